@@ -7,6 +7,7 @@ fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let api_token = std::env::var("KINTONE_API_TOKEN").expect("KINTONE_API_TOKEN is not set");
 
     let client = KintoneClient::new(&base_url, Auth::api_token(api_token));
+    #[allow(clippy::needless_borrows_for_generic_args)]
     let resp = kintone::v1::record::get_records(5)
         .fields(&["test_field_1", "test_field_2"])
         .send(&client)?;
@@ -7,11 +7,12 @@
 //!
 //! - [`KintoneClient`] - The main HTTP client for making API requests
 //! - [`KintoneClientBuilder`] - Builder for configuring the client with custom options
-//! - [`Auth`] - Authentication methods (API token or username/password)
+//! - [`Auth`] - Authentication methods (API token, username/password, or OAuth)
+//! - [`RawRequest`] - Calls endpoints that have no dedicated function in this crate
 //!
 //! ## Authentication
 //!
-//! The client supports two authentication methods:
+//! The client supports three authentication methods:
 //!
 //! ### API Token Authentication
 //!
@@ -36,6 +37,16 @@
 //! );
 //! ```
 //!
+//! ### OAuth Authentication
+//! ```rust
+//! use kintone::client::{Auth, KintoneClient};
+//!
+//! let client = KintoneClient::new(
+//!     "https://your-domain.cybozu.com",
+//!     Auth::bearer("your-access-token".to_owned())
+//! );
+//! ```
+//!
 //! ### Client Certificate Authentication (Mutual TLS)
 //!
 //! For Kintone's "Secure Access" feature:
@@ -182,18 +193,21 @@
 //!     .build();
 //! ```
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::io::Cursor;
 use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as BASE64;
 use rand::RngCore as _;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use ureq::tls::{Certificate, ClientCert, PrivateKey, TlsConfig};
+use ureq::tls::{Certificate, ClientCert, PemItem, PrivateKey, RootCerts, TlsConfig};
 
-use crate::error::ApiError;
+use crate::error::{ApiError, ConnectionError};
 use crate::middleware;
 
 /// The main HTTP client for communicating with Kintone's REST API.
@@ -205,6 +219,19 @@ use crate::middleware;
 /// The client is designed to be reused across multiple API calls and is thread-safe.
 /// For advanced configuration like middleware support, use [`KintoneClientBuilder`].
 ///
+/// # Thread Safety
+///
+/// `KintoneClient` is `Send` and `Sync`. Share one client between threads by reference or in
+/// an [`Arc`]; requests sent concurrently draw on the same connection pool. Every middleware
+/// layer and custom handler must be `Send + Sync` as well, which the builder enforces.
+///
+/// # Connection Lifetime
+///
+/// The client keeps idle connections open for reuse, for at most
+/// [`idle_connection_timeout`](KintoneClientBuilder::idle_connection_timeout). Dropping the
+/// client, or calling [`shutdown`](KintoneClient::shutdown), closes them right away unless the
+/// pool is shared with other clients through [`KintoneClientBuilder::with_shared_agent`].
+///
 /// # Examples
 ///
 /// ```rust
@@ -218,9 +245,14 @@ use crate::middleware;
 /// ```
 pub struct KintoneClient {
     base_url: url::Url,
+    base_path: String,
     auth: Auth,
     guest_space_id: Option<u64>,
+    max_response_bytes: u64,
+    locale: Option<String>,
+    agent: HttpAgent,
     handler: Box<dyn middleware::Handler>,
+    app_codes: Mutex<HashMap<String, u64>>,
 }
 
 impl KintoneClient {
@@ -284,28 +316,351 @@ impl KintoneClient {
         let base_url = url::Url::parse(base_url).unwrap();
         KintoneClientBuilder {
             base_url,
+            base_path: "/k".to_owned(),
             auth,
             user_agent: None,
             guest_space_id: None,
             client_cert: None,
+            root_certs: Vec::new(),
+            accept_invalid_certs: false,
+            max_idle_connections: None,
+            max_idle_connections_per_host: None,
+            idle_connection_timeout: None,
+            compression: true,
+            shared_agent: None,
+            max_response_bytes: None,
+            locale: None,
+            custom_handler: None,
             layer: middleware::NoLayer,
         }
     }
 
+    /// Returns the HTTP agent used by this client.
+    ///
+    /// The returned agent can be passed to [`KintoneClientBuilder::with_shared_agent`] so that
+    /// several clients (e.g. one per tenant) share a single connection pool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let first = KintoneClient::new(
+    ///     "https://tenant-a.cybozu.com",
+    ///     Auth::api_token("token-a".to_owned())
+    /// );
+    /// let second = KintoneClient::builder(
+    ///         "https://tenant-b.cybozu.com",
+    ///         Auth::api_token("token-b".to_owned())
+    ///     )
+    ///     .with_shared_agent(first.http_agent())
+    ///     .build();
+    /// ```
+    pub fn http_agent(&self) -> HttpAgent {
+        self.agent.clone()
+    }
+
+    /// Returns `true` if the client was constructed with [`Auth::ApiToken`].
+    ///
+    /// Credentials from [`Auth::Provider`] are not inspected, since calling the provider
+    /// may have side effects such as refreshing a token.
+    pub(crate) fn uses_api_token(&self) -> bool {
+        matches!(self.auth, Auth::ApiToken { .. })
+    }
+
     pub(crate) fn run(
         &self,
         req: http::Request<middleware::RequestBody>,
     ) -> Result<http::Response<middleware::ResponseBody>, ApiError> {
         self.handler.handle(req)
     }
+
+    /// Starts building a request to an API endpoint that this crate does not wrap.
+    ///
+    /// The request goes through the same authentication, guest space prefix, and middleware
+    /// as every other API call. `api_path` is the path after `/k`, such as `/v1/app.json`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// let resp: serde_json::Value = client
+    ///     .request(http::Method::GET, "/v1/app/acl.json")
+    ///     .query("app", 123)
+    ///     .send()?;
+    /// println!("{resp}");
+    ///
+    /// let resp: serde_json::Value = client
+    ///     .request(http::Method::PUT, "/v1/preview/app/settings.json")
+    ///     .json_body(serde_json::json!({"app": 123, "name": "New name"}))
+    ///     .send()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn request(&self, method: http::Method, api_path: impl Into<String>) -> RawRequest<'_> {
+        RawRequest {
+            client: self,
+            builder: RequestBuilder::new(method, api_path),
+            body: None,
+        }
+    }
+
+    /// Closes the client and the idle connections in its pool.
+    ///
+    /// This is the same as dropping the client, spelled out for services that create and
+    /// discard clients over time (for example one per tenant) and want the cleanup to be
+    /// visible. Since requests borrow the client, none can be in flight when it is shut down.
+    /// If the pool is shared through [`HttpAgent`], its connections stay open until the
+    /// last client and agent handle using it are dropped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let client = KintoneClient::new(
+    ///     "https://your-domain.cybozu.com",
+    ///     Auth::api_token("your-api-token".to_owned())
+    /// );
+    /// // ... use the client ...
+    /// client.shutdown();
+    /// ```
+    pub fn shutdown(self) {
+        drop(self);
+    }
+
+    /// Verifies that Kintone is reachable and accepts the client's credentials.
+    ///
+    /// This sends a single cheap authenticated request (a [`get_apps`](crate::v1::app::get_apps)
+    /// call limited to one app) and is meant for validating the configuration at startup, not
+    /// for use on every request.
+    ///
+    /// # Errors
+    /// Returns [`ConnectionError::Unreachable`] if the server cannot be reached or does not
+    /// respond like Kintone, and [`ConnectionError::AuthenticationFailed`] if the credentials
+    /// are rejected.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// use kintone::error::ConnectionError;
+    ///
+    /// match client.check_connection() {
+    ///     Ok(()) => {}
+    ///     Err(ConnectionError::AuthenticationFailed(e)) => panic!("check the credentials: {e}"),
+    ///     Err(e) => panic!("cannot use kintone: {e}"),
+    /// }
+    /// ```
+    pub fn check_connection(&self) -> Result<(), ConnectionError> {
+        crate::v1::app::get_apps().limit(1).send(self)?;
+        Ok(())
+    }
+
+    /// Returns the ID of the app with the given app code.
+    ///
+    /// The first lookup of a code sends a [`get_apps`](crate::v1::app::get_apps) request.
+    /// The result is cached in the client, and later lookups of the same code are answered
+    /// from the cache. App codes are assumed not to change during the lifetime of the client;
+    /// call [`KintoneClient::clear_app_code_cache`] if they might have.
+    ///
+    /// # Errors
+    /// Returns [`ApiError::AppCodeNotFound`] if no app has the given code.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// let app_id = client.resolve_app_code("PROJECT")?;
+    /// let response = kintone::v1::record::get_records(app_id).send(&client)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resolve_app_code(&self, code: &str) -> Result<u64, ApiError> {
+        if let Some(&app_id) = self.app_codes.lock().unwrap().get(code) {
+            return Ok(app_id);
+        }
+        self.prewarm_app_codes([code])?;
+        self.app_codes
+            .lock()
+            .unwrap()
+            .get(code)
+            .copied()
+            .ok_or_else(|| ApiError::AppCodeNotFound {
+                code: code.to_owned(),
+            })
+    }
+
+    /// Resolves several app codes at once and stores them in the cache.
+    ///
+    /// Codes that are already cached are not requested again. Codes that do not match any app
+    /// are ignored here; [`KintoneClient::resolve_app_code`] reports them when they are used.
+    pub fn prewarm_app_codes<I, T>(&self, codes: I) -> Result<(), ApiError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let missing: Vec<String> = {
+            let cache = self.app_codes.lock().unwrap();
+            codes
+                .into_iter()
+                .map(Into::into)
+                .filter(|code| !cache.contains_key(code))
+                .collect()
+        };
+        for chunk in missing.chunks(crate::v1::app::MAX_APPS_PER_REQUEST) {
+            let resp = crate::v1::app::get_apps().codes(chunk.iter().cloned()).send(self)?;
+            let mut cache = self.app_codes.lock().unwrap();
+            for app in resp.apps {
+                cache.insert(app.code, app.app_id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Forgets all app codes resolved by [`KintoneClient::resolve_app_code`].
+    pub fn clear_app_code_cache(&self) {
+        self.app_codes.lock().unwrap().clear();
+    }
+}
+
+/// A request to an arbitrary Kintone API endpoint, created by [`KintoneClient::request`].
+#[must_use]
+pub struct RawRequest<'a> {
+    client: &'a KintoneClient,
+    builder: RequestBuilder,
+    body: Option<serde_json::Value>,
+}
+
+impl RawRequest<'_> {
+    /// Sets a query parameter, replacing any value set before for the same key.
+    pub fn query<V: ToString>(mut self, key: &str, value: V) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Sets an array query parameter, encoded as `key[0]=...&key[1]=...`, replacing any values
+    /// set before.
+    pub fn query_array<V: ToString>(mut self, key: &str, values: &[V]) -> Self {
+        self.builder = self.builder.query_array(key, values);
+        self
+    }
+
+    /// Adds a request header.
+    ///
+    /// Headers added here are sent in addition to those the client adds itself. A
+    /// `User-Agent` header replaces the client's default; prefer
+    /// [`user_agent`](Self::user_agent), which also replaces one set before.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+
+    /// Sets the `User-Agent` header of this request, overriding the one configured with
+    /// [`KintoneClientBuilder::user_agent`].
+    ///
+    /// This lets individual operations be told apart in server-side logs, for example a batch
+    /// import and an interactive dashboard sharing one client.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// let resp: serde_json::Value = client
+    ///     .request(http::Method::GET, "/v1/records.json")
+    ///     .query("app", 123)
+    ///     .user_agent("MyApp/1.0 (import-job)")
+    ///     .send()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.builder = self.builder.replace_header("user-agent", user_agent);
+        self
+    }
+
+    /// Sets the JSON request body.
+    pub fn json_body(mut self, body: serde_json::Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self) -> String {
+        self.builder.debug_url(self.client)
+    }
+
+    /// Sends the request and deserializes the JSON response.
+    pub fn send<Resp: DeserializeOwned>(self) -> Result<Resp, ApiError> {
+        self.send_with_metadata().map(|(resp, _)| resp)
+    }
+
+    /// Sends the request like [`send`](Self::send), and also returns metadata about the
+    /// response, such as the request id Kintone assigned to it.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// let (resp, meta) = client
+    ///     .request(http::Method::GET, "/v1/app.json")
+    ///     .query("id", 123)
+    ///     .send_with_metadata::<serde_json::Value>()?;
+    /// log::info!("request id: {:?}", meta.request_id);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn send_with_metadata<Resp: DeserializeOwned>(
+        self,
+    ) -> Result<(Resp, ResponseMetadata), ApiError> {
+        let body = self.body.map(|b| serde_json::to_vec_pretty(&b)).transpose()?;
+        let resp = self.builder.execute(self.client, body)?;
+        let metadata = ResponseMetadata {
+            status: resp.status().as_u16(),
+            request_id: crate::error::request_id_header(resp.headers()),
+        };
+        let value = resp.into_body().read_json_with_limit(self.client.max_response_bytes)?;
+        Ok((value, metadata))
+    }
+}
+
+/// Information about a successful response, returned by
+/// [`RawRequest::send_with_metadata`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponseMetadata {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The value of the `X-Cybozu-Request-Id` response header, if present.
+    ///
+    /// Cybozu support can use this id to find the request in their logs. Failed requests
+    /// carry the same id in [`ApiError::request_id`].
+    pub request_id: Option<String>,
+}
+
+/// Returns a builder for a client of `https://example.cybozu.com` with API token
+/// authentication, for tests.
+#[cfg(test)]
+pub(crate) fn test_client_builder() -> KintoneClientBuilder<middleware::NoLayer> {
+    KintoneClient::builder("https://example.cybozu.com", Auth::api_token("t".to_owned()))
+}
+
+/// Returns a client that sends its requests to `mock`, for tests.
+#[cfg(test)]
+pub(crate) fn mock_client(mock: &middleware::MockHandler) -> KintoneClient {
+    test_client_builder().with_handler(mock.clone()).build()
 }
 
+/// A handle to an HTTP agent and its connection pool.
+///
+/// Cloning an `HttpAgent` is cheap; all clones share the same pool. Obtain one from an existing
+/// client with [`KintoneClient::http_agent`] and pass it to
+/// [`KintoneClientBuilder::with_shared_agent`].
+#[derive(Debug, Clone)]
+pub struct HttpAgent(ureq::Agent);
+
 /// Internal HTTP request handler that implements the actual HTTP communication.
 ///
 /// This is an internal implementation detail and should not be used directly.
 /// Use [`KintoneClient`] or [`KintoneClientBuilder`] instead.
 pub struct RequestHandler {
     http_client: ureq::Agent,
+    custom_handler: Option<Arc<dyn middleware::Handler>>,
 }
 
 impl middleware::Handler for RequestHandler {
@@ -313,6 +668,13 @@ impl middleware::Handler for RequestHandler {
         &self,
         req: http::Request<middleware::RequestBody>,
     ) -> Result<http::Response<middleware::ResponseBody>, ApiError> {
+        if let Some(custom_handler) = &self.custom_handler {
+            let resp = custom_handler.handle(req)?;
+            if resp.status().as_u16() >= 400 {
+                return Err(ApiError::from(resp.map(|body| body.into_ureq_body())));
+            }
+            return Ok(resp);
+        }
         let req = req.map(|body| body.into_ureq_body());
         let resp = self.http_client.run(req)?;
         if resp.status().as_u16() >= 400 {
@@ -335,6 +697,32 @@ impl middleware::Handler for RequestHandler {
 /// * `L` - The middleware layer type. This is used to ensure type safety when building
 ///   the middleware stack.
 ///
+/// # Building Several Clients
+///
+/// The builder is `Clone` as long as its layers are, which holds for every layer in
+/// [`middleware`]. A factory can keep one configured builder and clone it for each client,
+/// for example one per tenant. Functions and handlers held by the builder, such as
+/// [`RetryLayer::with_on_retry`](middleware::RetryLayer::with_on_retry) callbacks or a handler
+/// set with [`with_handler`](Self::with_handler), are shared by the clones, not copied. A custom
+/// layer that cannot implement `Clone` has to be added to each clone separately instead.
+///
+/// ```rust
+/// use kintone::client::{Auth, KintoneClient};
+/// use kintone::middleware;
+///
+/// let template = KintoneClient::builder(
+///         "https://tenant-a.cybozu.com",
+///         Auth::api_token("token-a".to_owned())
+///     )
+///     .layer(middleware::RetryLayer::new())
+///     .layer(middleware::LoggingLayer::new());
+/// let tenant_a = template.clone().build();
+/// let tenant_b = template
+///     .base_url("https://tenant-b.cybozu.com")
+///     .auth(Auth::api_token("token-b".to_owned()))
+///     .build();
+/// ```
+///
 /// # Examples
 ///
 /// ```rust
@@ -356,12 +744,24 @@ impl middleware::Handler for RequestHandler {
 ///     .layer(middleware::LoggingLayer::new())
 ///     .build();
 /// ```
+#[derive(Clone)]
 pub struct KintoneClientBuilder<L> {
     base_url: url::Url,
+    base_path: String,
     auth: Auth,
     user_agent: Option<String>,
     guest_space_id: Option<u64>,
     client_cert: Option<ClientCert>,
+    root_certs: Vec<Certificate<'static>>,
+    accept_invalid_certs: bool,
+    max_idle_connections: Option<usize>,
+    max_idle_connections_per_host: Option<usize>,
+    idle_connection_timeout: Option<Duration>,
+    compression: bool,
+    shared_agent: Option<HttpAgent>,
+    max_response_bytes: Option<u64>,
+    locale: Option<String>,
+    custom_handler: Option<Arc<dyn middleware::Handler>>,
     layer: L,
 }
 
@@ -441,10 +841,21 @@ impl<L> KintoneClientBuilder<L> {
         let layer_stack = middleware::Stack::new(self.layer, new_layer);
         KintoneClientBuilder {
             base_url: self.base_url,
+            base_path: self.base_path,
             auth: self.auth,
             user_agent: self.user_agent,
             guest_space_id: self.guest_space_id,
             client_cert: self.client_cert,
+            root_certs: self.root_certs,
+            accept_invalid_certs: self.accept_invalid_certs,
+            max_idle_connections: self.max_idle_connections,
+            max_idle_connections_per_host: self.max_idle_connections_per_host,
+            idle_connection_timeout: self.idle_connection_timeout,
+            compression: self.compression,
+            shared_agent: self.shared_agent,
+            max_response_bytes: self.max_response_bytes,
+            locale: self.locale,
+            custom_handler: self.custom_handler,
             layer: layer_stack,
         }
     }
@@ -481,12 +892,67 @@ impl<L> KintoneClientBuilder<L> {
         self
     }
 
+    /// Sets the path prefix under which the Kintone REST API is served.
+    ///
+    /// cybozu.com serves the API under `/k`, which is the default. Self-hosted installations
+    /// behind a reverse proxy may use a different prefix. When a guest space is configured,
+    /// the guest space path is nested under this prefix (e.g. `/prefix/guest/123`).
+    ///
+    /// # Arguments
+    ///
+    /// * `base_path` - The path prefix, e.g. `"/kintone/k"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let client = KintoneClient::builder(
+    ///         "https://kintone.example.internal",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .base_path("/kintone/k")
+    ///     .build();
+    /// ```
+    pub fn base_path(mut self, base_path: impl Into<String>) -> Self {
+        let base_path = base_path.into();
+        let base_path = base_path.trim_end_matches('/');
+        self.base_path = if base_path.starts_with('/') || base_path.is_empty() {
+            base_path.to_owned()
+        } else {
+            format!("/{base_path}")
+        };
+        self
+    }
+
+    /// Replaces the base URL given to [`KintoneClient::builder`].
+    ///
+    /// This is mainly useful together with cloning the builder, to create clients with the
+    /// same configuration for different Kintone environments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_url` is not a valid URL, like [`KintoneClient::builder`].
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = url::Url::parse(base_url).unwrap();
+        self
+    }
+
+    /// Replaces the authentication given to [`KintoneClient::builder`].
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
     /// Sets a custom User-Agent header for HTTP requests.
     ///
     /// The User-Agent header identifies your application to the Kintone server.
     /// This can be useful for debugging, analytics, or server-side logging.
     /// If not specified, defaults to "kintone-rs".
     ///
+    /// A `User-Agent` set on an individual request with [`RawRequest::user_agent`] takes
+    /// precedence over this value.
+    ///
     /// # Arguments
     ///
     /// * `user_agent` - The User-Agent string to use for requests
@@ -508,6 +974,75 @@ impl<L> KintoneClientBuilder<L> {
         self
     }
 
+    /// Sets the language of localized text returned by Kintone.
+    ///
+    /// The value is sent as the `Accept-Language` header on every request. Kintone uses it to
+    /// choose the language of error messages, and of names and labels (field labels, view names,
+    /// status names, etc.) in app settings when the user's language setting follows the browser.
+    /// Record values are not affected. If not specified, no `Accept-Language` header is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - A language tag such as `"ja"`, `"en"`, or `"zh"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let client = KintoneClient::builder(
+    ///         "https://your-domain.cybozu.com",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .locale("en")
+    ///     .build();
+    /// ```
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Replaces the HTTP transport with a custom handler.
+    ///
+    /// Requests are passed to `handler` instead of being sent over the network. Middleware
+    /// layers are still applied on top of it, and responses with a status code of 400 or above
+    /// are converted into [`ApiError`] as usual. This is mainly intended for unit-testing
+    /// application code with [`middleware::MockHandler`].
+    ///
+    /// It can also be used to send requests with another HTTP client. With the `reqwest`
+    /// feature, `middleware::ReqwestHandler` sends them with reqwest; other clients can be
+    /// plugged in the same way by wrapping their response bodies with
+    /// [`middleware::ResponseBody::from_reader`]. The connection settings of this builder, such
+    /// as TLS certificates and connection pool limits, only apply to the built-in transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The handler that receives the requests
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    /// use kintone::middleware::MockHandler;
+    ///
+    /// let mock = MockHandler::new()
+    ///     .with_response(http::Method::GET, "/k/v1/record.json", 200, r#"{"record":{}}"#);
+    /// let client = KintoneClient::builder(
+    ///         "https://your-domain.cybozu.com",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .with_handler(mock.clone())
+    ///     .build();
+    ///
+    /// let response = kintone::v1::record::get_record(1, 2).send(&client)?;
+    /// assert_eq!(mock.requests()[0].uri, "https://your-domain.cybozu.com/k/v1/record.json?app=1&id=2");
+    /// # Ok::<(), kintone::error::ApiError>(())
+    /// ```
+    pub fn with_handler(mut self, handler: impl middleware::Handler) -> Self {
+        self.custom_handler = Some(Arc::new(handler));
+        self
+    }
+
     /// Sets a client certificate for mutual TLS authentication.
     ///
     /// This method configures the client to use a client certificate for authentication,
@@ -571,74 +1106,347 @@ impl<L> KintoneClientBuilder<L> {
         self.client_cert = Some(ClientCert::new_with_certs(&[cert], key));
         Ok(self)
     }
-}
 
-impl<L> KintoneClientBuilder<L>
-where
-    L: middleware::Layer<RequestHandler>,
-{
-    /// Builds the final [`KintoneClient`] instance with all configured options.
+    /// Trusts the root certificates in the given PEM data when verifying the server.
     ///
-    /// This method consumes the builder and creates a ready-to-use Kintone client
-    /// with all the specified configuration including middleware layers, authentication,
-    /// guest space settings, and client certificates.
+    /// This is needed when Kintone is reached through a TLS-intercepting proxy, or through a
+    /// gateway whose certificate is issued by an internal CA. The PEM data may contain several
+    /// certificates, and this method may be called more than once.
     ///
-    /// # Returns
+    /// **Note:** once a root certificate is added, *only* the added certificates are trusted and
+    /// the bundled Mozilla root store is no longer used. If the client must also reach servers
+    /// with publicly issued certificates, include their roots in the PEM data as well.
     ///
-    /// A configured [`KintoneClient`] instance ready for making API requests.
+    /// # Arguments
+    ///
+    /// * `pem` - One or more root certificates in PEM format (as bytes)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data cannot be parsed or contains no certificate.
     ///
     /// # Examples
     ///
-    /// ```rust
-    /// use std::time::Duration;
+    /// ```no_run
     /// use kintone::client::{Auth, KintoneClient};
-    /// use kintone::middleware;
+    ///
+    /// let ca_pem = std::fs::read("corporate-root-ca.pem")?;
     ///
     /// let client = KintoneClient::builder(
     ///         "https://your-domain.cybozu.com",
     ///         Auth::api_token("your-api-token".to_owned())
     ///     )
-    ///     .user_agent("MyApp/1.0")
-    ///     .layer(middleware::LoggingLayer::new())
+    ///     .add_root_certificate(&ca_pem)?
     ///     .build();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn build(self) -> KintoneClient {
-        let user_agent = self.user_agent.unwrap_or_else(|| "kintone-rs".to_owned());
-        let http_client: ureq::Agent = ureq::Agent::config_builder()
-            .user_agent(&user_agent)
-            .http_status_as_error(false)
-            .tls_config(TlsConfig::builder().client_cert(self.client_cert).build())
-            .build()
-            .into();
-
-        let handler = self.layer.layer(RequestHandler { http_client });
-
-        KintoneClient {
-            base_url: self.base_url,
-            auth: self.auth,
-            guest_space_id: self.guest_space_id,
-            handler: Box::new(handler),
+    pub fn add_root_certificate(mut self, pem: &[u8]) -> Result<Self, std::io::Error> {
+        let mut found = false;
+        for item in ureq::tls::parse_pem(pem) {
+            if let PemItem::Certificate(cert) = item.map_err(|e| e.into_io())? {
+                self.root_certs.push(cert);
+                found = true;
+            }
+        }
+        if !found {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "no certificate found in PEM data",
+            ));
         }
+        Ok(self)
     }
-}
 
-/// Authentication configuration for Kintone API access.
-///
-/// Kintone supports two primary authentication methods:
-/// - API Token authentication
-/// - Username/Password authentication
-///
-/// **Note**: If your domain requires Basic authentication, use [`crate::middleware::BasicAuthLayer`]
-/// middleware in addition to your Kintone authentication. BasicAuthLayer adds the necessary
-/// `Authorization: Basic` headers for domain-level authentication, while the Auth configuration handles
-/// Kintone's own authentication mechanism.
-///
-/// # Examples
-///
-/// ```rust
-/// use kintone::client::Auth;
-///
-/// // API token authentication
+    /// Disables verification of the server's TLS certificate.
+    ///
+    /// **Dangerous:** with this enabled, the client accepts any certificate, including expired,
+    /// self-signed, or forged ones, so anyone able to intercept the connection can read and
+    /// modify the traffic, including credentials. Prefer
+    /// [`add_root_certificate`](Self::add_root_certificate); use this only for local testing.
+    ///
+    /// # Arguments
+    ///
+    /// * `accept` - Whether to accept invalid certificates (default: `false`)
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept in the connection pool.
+    ///
+    /// Connections are reused across requests (HTTP keep-alive). This limit applies to
+    /// the pool as a whole, across all hosts. If not specified, defaults to 10.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of idle connections to keep
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let client = KintoneClient::builder(
+    ///         "https://your-domain.cybozu.com",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .max_idle_connections(20)
+    ///     .build();
+    /// ```
+    pub fn max_idle_connections(mut self, max: usize) -> Self {
+        self.max_idle_connections = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept per host.
+    ///
+    /// Since a client usually talks to a single Kintone domain, this is the effective
+    /// limit for most applications. Raise it if you make many concurrent requests from
+    /// multiple threads. If not specified, defaults to 3.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of idle connections to keep for each host
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let client = KintoneClient::builder(
+    ///         "https://your-domain.cybozu.com",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .max_idle_connections_per_host(8)
+    ///     .build();
+    /// ```
+    pub fn max_idle_connections_per_host(mut self, max: usize) -> Self {
+        self.max_idle_connections_per_host = Some(max);
+        self
+    }
+
+    /// Sets how long an idle connection is kept in the pool before being closed.
+    ///
+    /// Long-running services that make steady traffic may benefit from a longer timeout
+    /// to avoid repeated TLS handshakes, while services that keep many rarely used clients
+    /// can shorten it so that sockets do not linger. If not specified, defaults to 15 seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The maximum age of an idle connection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let client = KintoneClient::builder(
+    ///         "https://your-domain.cybozu.com",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .idle_connection_timeout(Duration::from_secs(60))
+    ///     .build();
+    /// ```
+    pub fn idle_connection_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_connection_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to ask the server for compressed responses.
+    ///
+    /// When enabled (the default), requests carry `Accept-Encoding: gzip` and responses are
+    /// decompressed transparently, which greatly reduces the transfer size of large
+    /// `get_records` pages. Only the transfer encoding is affected: a downloaded file that is
+    /// itself compressed, such as a `.gz` attachment, is returned as stored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let client = KintoneClient::builder(
+    ///         "https://your-domain.cybozu.com",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .compression(false)
+    ///     .build();
+    /// ```
+    pub fn compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Uses an existing HTTP agent instead of creating a new one.
+    ///
+    /// This is useful for multi-tenant services that create one client per domain or per
+    /// credential: all clients built with the same agent share one connection pool, so the
+    /// number of open connections stays bounded.
+    ///
+    /// The agent carries its own HTTP settings. When a shared agent is used, the
+    /// [`user_agent`](Self::user_agent), [`compression`](Self::compression), TLS, and connection
+    /// pool settings of this builder are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `agent` - The agent to share, obtained from [`KintoneClient::http_agent`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let base = KintoneClient::builder(
+    ///         "https://tenant-a.cybozu.com",
+    ///         Auth::api_token("token-a".to_owned())
+    ///     )
+    ///     .max_idle_connections(50)
+    ///     .build();
+    ///
+    /// let other = KintoneClient::builder(
+    ///         "https://tenant-b.cybozu.com",
+    ///         Auth::password("username".to_owned(), "password".to_owned())
+    ///     )
+    ///     .with_shared_agent(base.http_agent())
+    ///     .build();
+    /// ```
+    pub fn with_shared_agent(mut self, agent: HttpAgent) -> Self {
+        self.shared_agent = Some(agent);
+        self
+    }
+
+    /// Sets the maximum size of a JSON response body.
+    ///
+    /// Responses larger than this are rejected with [`ApiError::ResponseTooLarge`] instead of
+    /// being read into memory. File downloads are streamed and are not subject to this limit.
+    /// If not specified, defaults to 10 MiB.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of bytes to read from a JSON response body
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::{Auth, KintoneClient};
+    ///
+    /// let client = KintoneClient::builder(
+    ///         "https://your-domain.cybozu.com",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .max_response_bytes(1024 * 1024)
+    ///     .build();
+    /// ```
+    pub fn max_response_bytes(mut self, max: usize) -> Self {
+        self.max_response_bytes = Some(max as u64);
+        self
+    }
+}
+
+impl<L> KintoneClientBuilder<L>
+where
+    L: middleware::Layer<RequestHandler>,
+{
+    /// Builds the final [`KintoneClient`] instance with all configured options.
+    ///
+    /// This method consumes the builder and creates a ready-to-use Kintone client
+    /// with all the specified configuration including middleware layers, authentication,
+    /// guest space settings, and client certificates.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`KintoneClient`] instance ready for making API requests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use kintone::client::{Auth, KintoneClient};
+    /// use kintone::middleware;
+    ///
+    /// let client = KintoneClient::builder(
+    ///         "https://your-domain.cybozu.com",
+    ///         Auth::api_token("your-api-token".to_owned())
+    ///     )
+    ///     .user_agent("MyApp/1.0")
+    ///     .layer(middleware::LoggingLayer::new())
+    ///     .build();
+    /// ```
+    pub fn build(self) -> KintoneClient {
+        let agent = match self.shared_agent {
+            Some(agent) => agent,
+            None => {
+                let user_agent = self.user_agent.unwrap_or_else(|| "kintone-rs".to_owned());
+                let mut tls_config = TlsConfig::builder()
+                    .client_cert(self.client_cert)
+                    .disable_verification(self.accept_invalid_certs);
+                if !self.root_certs.is_empty() {
+                    tls_config = tls_config.root_certs(RootCerts::new_with_certs(&self.root_certs));
+                }
+                let tls_config = tls_config.build();
+                let mut config = ureq::Agent::config_builder()
+                    .user_agent(&user_agent)
+                    .http_status_as_error(false)
+                    .tls_config(tls_config);
+                if let Some(max) = self.max_idle_connections {
+                    config = config.max_idle_connections(max);
+                }
+                if let Some(max) = self.max_idle_connections_per_host {
+                    config = config.max_idle_connections_per_host(max);
+                }
+                if let Some(timeout) = self.idle_connection_timeout {
+                    config = config.max_idle_age(timeout);
+                }
+                if !self.compression {
+                    config = config.accept_encoding(ureq::config::AutoHeaderValue::None);
+                }
+                HttpAgent(config.build().into())
+            }
+        };
+        let http_client = agent.0.clone();
+
+        let handler = self.layer.layer(RequestHandler {
+            http_client,
+            custom_handler: self.custom_handler,
+        });
+
+        KintoneClient {
+            base_url: self.base_url,
+            base_path: self.base_path,
+            auth: self.auth,
+            guest_space_id: self.guest_space_id,
+            max_response_bytes: self
+                .max_response_bytes
+                .unwrap_or(middleware::ResponseBody::MAX_JSON_SIZE),
+            locale: self.locale,
+            agent,
+            handler: Box::new(handler),
+            app_codes: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+/// Authentication configuration for Kintone API access.
+///
+/// Kintone supports the following authentication methods:
+/// - API Token authentication
+/// - Username/Password authentication
+/// - OAuth access token (Bearer) authentication
+///
+/// For credentials that expire or are rotated, use [`Auth::provider`] to resolve
+/// the credentials on every request.
+///
+/// **Note**: If your domain requires Basic authentication, use [`crate::middleware::BasicAuthLayer`]
+/// middleware in addition to your Kintone authentication. BasicAuthLayer adds the necessary
+/// `Authorization: Basic` headers for domain-level authentication, while the Auth configuration handles
+/// Kintone's own authentication mechanism.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::client::Auth;
+///
+/// // API token authentication
 /// let auth = Auth::api_token("your-api-token".to_owned());
 ///
 /// // Multiple API tokens (for accessing multiple apps)
@@ -649,13 +1457,22 @@ where
 ///
 /// // Username/password authentication
 /// let auth = Auth::password("username".to_owned(), "password".to_owned());
+///
+/// // OAuth access token authentication
+/// let auth = Auth::bearer("your-access-token".to_owned());
 /// ```
 #[derive(Clone)]
+#[non_exhaustive]
 pub enum Auth {
     Password { username: String, password: String },
     ApiToken { tokens: Vec<String> },
+    Bearer { token: String },
+    Provider { provider: Arc<CredentialProviderFn> },
 }
 
+/// A function that returns the current credentials. See [`Auth::provider`].
+pub type CredentialProviderFn = dyn Fn() -> Auth + Send + Sync;
+
 impl Auth {
     /// Creates password-based authentication configuration.
     ///
@@ -722,6 +1539,58 @@ impl Auth {
     pub fn api_tokens(tokens: Vec<String>) -> Self {
         Self::ApiToken { tokens }
     }
+
+    /// Creates OAuth access token authentication configuration.
+    ///
+    /// The token is sent in the `Authorization: Bearer ...` header. Since this uses
+    /// the `Authorization` header, it cannot be combined with
+    /// [`crate::middleware::BasicAuthLayer`].
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The OAuth access token
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::client::Auth;
+    ///
+    /// let auth = Auth::bearer("your-access-token".to_owned());
+    /// ```
+    pub fn bearer(token: String) -> Self {
+        Self::Bearer { token }
+    }
+
+    /// Creates authentication configuration that obtains credentials from a provider.
+    ///
+    /// The provider is called for every request, so a long-lived client always uses the
+    /// latest credentials. This is useful when tokens expire (e.g. OAuth access tokens)
+    /// or are rotated by a secrets manager. The provider is responsible for caching and
+    /// refreshing the credentials; it should be cheap to call.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - A function returning the credentials to use for the next request
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, RwLock};
+    /// use kintone::client::Auth;
+    ///
+    /// let token = Arc::new(RwLock::new("initial-token".to_owned()));
+    ///
+    /// let current = Arc::clone(&token);
+    /// let auth = Auth::provider(move || Auth::bearer(current.read().unwrap().clone()));
+    ///
+    /// // Later, when the token is refreshed:
+    /// *token.write().unwrap() = "refreshed-token".to_owned();
+    /// ```
+    pub fn provider(provider: impl Fn() -> Auth + Send + Sync + 'static) -> Self {
+        Self::Provider {
+            provider: Arc::new(provider),
+        }
+    }
 }
 
 impl Debug for Auth {
@@ -735,13 +1604,15 @@ impl Debug for Auth {
             Auth::ApiToken { .. } => {
                 f.debug_struct("ApiToken").field("tokens", &"<hidden>").finish()
             }
+            Auth::Bearer { .. } => f.debug_struct("Bearer").field("token", &"<hidden>").finish(),
+            Auth::Provider { .. } => f.debug_struct("Provider").finish_non_exhaustive(),
         }
     }
 }
 
 pub(crate) struct RequestBuilder {
     method: http::Method,
-    api_path: String,               // DO NOT include "/k" prefix
+    api_path: String,               // DO NOT include the base path ("/k")
     headers: Vec<(String, String)>, // keys and values are NOT encoded
     query: Vec<(String, String)>,   // keys and values are NOT encoded
 }
@@ -756,12 +1627,24 @@ impl RequestBuilder {
         }
     }
 
+    /// Replaces the API path, keeping the query parameters and headers set so far.
+    pub fn api_path(mut self, api_path: impl Into<String>) -> Self {
+        self.api_path = api_path.into();
+        self
+    }
+
+    /// Sets the query parameter `key`, replacing any value set before.
     pub fn query<V: ToString>(mut self, key: &str, value: V) -> Self {
+        self.query.retain(|(k, _)| k != key);
         self.query.push((key.to_owned(), value.to_string()));
         self
     }
 
+    /// Sets the array query parameter `key[0]`, `key[1]`, ..., replacing any values set before.
     pub fn query_array<V: ToString>(mut self, key: &str, values: &[V]) -> Self {
+        self.query.retain(|(k, _)| {
+            !(k.strip_prefix(key)).is_some_and(|rest| rest.starts_with('[') && rest.ends_with(']'))
+        });
         for (i, v) in values.iter().enumerate() {
             let name = format!("{key}[{i}]");
             self.query.push((name, v.to_string()));
@@ -769,10 +1652,52 @@ impl RequestBuilder {
         self
     }
 
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Sets a header, removing any value added before for the same (case-insensitive) name.
+    pub fn replace_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.retain(|(k, _)| !k.eq_ignore_ascii_case(key));
+        self.header(key, value)
+    }
+
+    /// Builds the URL including the base path, the guest space prefix, and query parameters.
+    /// Useful for logging and tests; the request body and headers are not included.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        make_url(client, &self.api_path, self.query.iter().cloned()).into()
+    }
+
     pub fn call<Resp: DeserializeOwned>(self, client: &KintoneClient) -> Result<Resp, ApiError> {
         let req = make_request(client, self.method, &self.api_path, self.headers, self.query)?;
         let resp = client.run(req)?;
-        resp.into_body().read_json()
+        resp.into_body().read_json_with_limit(client.max_response_bytes)
+    }
+
+    /// Sends the request with an optional JSON body and returns the response unread.
+    pub fn execute(
+        mut self,
+        client: &KintoneClient,
+        json_body: Option<Vec<u8>>,
+    ) -> Result<http::Response<middleware::ResponseBody>, ApiError> {
+        let Some(json_body) = json_body else {
+            let req = make_request(client, self.method, &self.api_path, self.headers, self.query)?;
+            return client.run(req);
+        };
+        self.headers.push(("content-type".to_owned(), "application/json".to_owned()));
+        let req = make_request(client, self.method, &self.api_path, self.headers, self.query)?
+            .map(|_| middleware::RequestBody::from_bytes(json_body));
+        client.run(req)
+    }
+
+    /// Sends the request and returns the response body without reading it.
+    pub fn call_for_body(
+        self,
+        client: &KintoneClient,
+    ) -> Result<middleware::ResponseBody, ApiError> {
+        let req = make_request(client, self.method, &self.api_path, self.headers, self.query)?;
+        Ok(client.run(req)?.into_body())
     }
 
     pub fn send<Body: Serialize, Resp: DeserializeOwned>(
@@ -785,7 +1710,7 @@ impl RequestBuilder {
         let req = make_request(client, self.method, &self.api_path, self.headers, self.query)?
             .map(|_| body);
         let resp = client.run(req)?;
-        resp.into_body().read_json()
+        resp.into_body().read_json_with_limit(client.max_response_bytes)
     }
 }
 
@@ -812,7 +1737,7 @@ impl RequestBuilder {
 /// ```
 pub(crate) struct UploadRequest {
     method: http::Method,
-    api_path: String, // DO NOT include "/k" prefix
+    api_path: String, // DO NOT include the base path ("/k")
     name: String,
     filename: String,
 }
@@ -832,9 +1757,52 @@ impl UploadRequest {
         }
     }
 
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        make_url(client, &self.api_path, []).into()
+    }
+
     const CONTROLS_AND_QUOTES: &percent_encoding::AsciiSet =
         &percent_encoding::CONTROLS.add(b'\'').add(b'"').add(b'\\');
 
+    // Characters allowed unencoded in an RFC 5987 `ext-value` (`attr-char`).
+    const NON_ATTR_CHAR: &percent_encoding::AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+        .remove(b'!')
+        .remove(b'#')
+        .remove(b'$')
+        .remove(b'&')
+        .remove(b'+')
+        .remove(b'-')
+        .remove(b'.')
+        .remove(b'^')
+        .remove(b'_')
+        .remove(b'`')
+        .remove(b'|')
+        .remove(b'~');
+
+    /// Formats the filename parameters of the `Content-Disposition` header.
+    ///
+    /// The quoted `filename` carries the name as raw UTF-8, which is what browsers send and
+    /// what Kintone reads. Names that are not plain ASCII also get an RFC 5987
+    /// `filename*=UTF-8''...` parameter so that strict parsers recover them too.
+    fn filename_params(filename: &str) -> String {
+        let quoted: String = filename
+            .chars()
+            .filter(|c| !c.is_control())
+            .flat_map(|c| match c {
+                '"' | '\\' => vec!['\\', c],
+                c => vec![c],
+            })
+            .collect();
+        if filename.is_ascii() {
+            format!("filename=\"{quoted}\"")
+        } else {
+            format!(
+                "filename=\"{quoted}\"; filename*=UTF-8''{}",
+                percent_encoding::utf8_percent_encode(filename, Self::NON_ATTR_CHAR)
+            )
+        }
+    }
+
     pub fn send<Resp: DeserializeOwned>(
         self,
         client: &KintoneClient,
@@ -854,14 +1822,11 @@ impl UploadRequest {
 
         let header = format!(
             "--{boundary}\r\n\
-             Content-Disposition: form-data; name=\"{}\"; filename*=utf8''{}\r\n\
+             Content-Disposition: form-data; name=\"{}\"; {}\r\n\
              {inner_content_type_header}\
              \r\n",
             percent_encoding::utf8_percent_encode(&self.name, Self::CONTROLS_AND_QUOTES),
-            percent_encoding::utf8_percent_encode(
-                &self.filename,
-                percent_encoding::NON_ALPHANUMERIC
-            ),
+            Self::filename_params(&self.filename),
         );
         let footer = format!("\r\n--{boundary}--\r\n");
 
@@ -873,7 +1838,7 @@ impl UploadRequest {
         let req = make_request(client, self.method, &self.api_path, headers, vec![])?.map(|_| body);
 
         let resp = client.run(req)?;
-        resp.into_body().read_json()
+        resp.into_body().read_json_with_limit(client.max_response_bytes)
     }
 }
 
@@ -887,7 +1852,7 @@ impl UploadRequest {
 /// Use the [`crate::v1::file::download`] function instead.
 pub(crate) struct DownloadRequest {
     method: http::Method,
-    api_path: String,             // DO NOT include "/k" prefix
+    api_path: String,             // DO NOT include the base path ("/k")
     query: Vec<(String, String)>, // keys and values are NOT encoded
 }
 
@@ -926,6 +1891,10 @@ impl DownloadRequest {
         self
     }
 
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        make_url(client, &self.api_path, self.query.iter().cloned()).into()
+    }
+
     fn get_content_type<B>(resp: &http::Response<B>) -> Option<mime::Mime> {
         let content_type = resp.headers().get(http::header::CONTENT_TYPE)?;
         let content_type = content_type.to_str().ok()?;
@@ -944,43 +1913,487 @@ impl DownloadRequest {
     }
 }
 
-fn make_request(
-    client: &KintoneClient,
-    method: http::Method,
-    api_path: &str,
-    headers: impl IntoIterator<Item = (String, String)>,
-    query: impl IntoIterator<Item = (String, String)>,
-) -> Result<http::Request<middleware::RequestBody>, http::Error> {
-    // Add headers for auth
-    let auth_headers = match client.auth {
-        Auth::Password {
-            ref username,
-            ref password,
-        } => {
+fn auth_header(auth: &Auth) -> (String, String) {
+    match auth {
+        Auth::Password { username, password } => {
             let body = format!("{username}:{password}");
             let header_value = BASE64.encode(body);
-            [("x-cybozu-authorization".to_owned(), header_value)]
+            ("x-cybozu-authorization".to_owned(), header_value)
         }
-        Auth::ApiToken { ref tokens } => [("x-cybozu-api-token".to_owned(), tokens.join(","))],
-    };
+        Auth::ApiToken { tokens } => ("x-cybozu-api-token".to_owned(), tokens.join(",")),
+        Auth::Bearer { token } => ("authorization".to_owned(), format!("Bearer {token}")),
+        Auth::Provider { provider } => auth_header(&provider()),
+    }
+}
 
-    // Construct URL
+fn make_url(
+    client: &KintoneClient,
+    api_path: &str,
+    query: impl IntoIterator<Item = (String, String)>,
+) -> url::Url {
     let mut u = client.base_url.clone();
-    let mut path = if let Some(guest_space_id) = client.guest_space_id {
-        format!("/k/guest/{guest_space_id}")
-    } else {
-        "/k".to_owned()
-    };
+    let mut path = client.base_path.clone();
+    if let Some(guest_space_id) = client.guest_space_id {
+        path += &format!("/guest/{guest_space_id}");
+    }
     path += api_path;
     u.set_path(&path);
     for (key, value) in query {
         u.query_pairs_mut().append_pair(&key, &value);
     }
+    u
+}
+
+fn make_request(
+    client: &KintoneClient,
+    method: http::Method,
+    api_path: &str,
+    headers: impl IntoIterator<Item = (String, String)>,
+    query: impl IntoIterator<Item = (String, String)>,
+) -> Result<http::Request<middleware::RequestBody>, http::Error> {
+    // Add headers for auth
+    let auth_headers = [auth_header(&client.auth)];
+    let locale_header = client
+        .locale
+        .as_ref()
+        .map(|locale| ("accept-language".to_owned(), locale.clone()));
+
+    let u = make_url(client, api_path, query);
 
     let mut req = http::Request::builder().method(method).uri(u.as_str());
-    let all_headers = headers.into_iter().chain(auth_headers);
+    let all_headers = headers.into_iter().chain(auth_headers).chain(locale_header);
     for (key, value) in all_headers {
         req = req.header(&key, &value);
     }
     req.body(middleware::RequestBody::void())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_uri(client: &KintoneClient) -> String {
+        let req = make_request(
+            client,
+            http::Method::GET,
+            "/v1/record.json",
+            [],
+            [("app".to_owned(), "1".to_owned())],
+        )
+        .unwrap();
+        req.uri().to_string()
+    }
+
+    #[test]
+    fn default_base_path() {
+        let auth = Auth::api_token("token".to_owned());
+        let client = KintoneClient::builder("https://example.cybozu.com", auth.clone()).build();
+        assert_eq!(request_uri(&client), "https://example.cybozu.com/k/v1/record.json?app=1");
+
+        let client = KintoneClient::builder("https://example.cybozu.com", auth)
+            .guest_space_id(5)
+            .build();
+        assert_eq!(
+            request_uri(&client),
+            "https://example.cybozu.com/k/guest/5/v1/record.json?app=1"
+        );
+    }
+
+    #[test]
+    fn locale_header() {
+        let auth = Auth::api_token("token".to_owned());
+        let make = |client: &KintoneClient| {
+            make_request(client, http::Method::GET, "/v1/app.json", [], []).unwrap()
+        };
+
+        let client = KintoneClient::builder("https://example.cybozu.com", auth.clone()).build();
+        assert!(!make(&client).headers().contains_key(http::header::ACCEPT_LANGUAGE));
+
+        let client =
+            KintoneClient::builder("https://example.cybozu.com", auth).locale("en").build();
+        assert_eq!(make(&client).headers()[http::header::ACCEPT_LANGUAGE], "en");
+    }
+
+    #[test]
+    fn with_handler_converts_error_responses() {
+        let mock = middleware::MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/record.json",
+                404,
+                r#"{"code":"GAIA_RE01","id":"abc","message":"not found"}"#,
+            )
+            .with_response(http::Method::GET, "/k/v1/record.json", 200, r#"{"record":{}}"#);
+        let client = mock_client(&mock);
+
+        let err = crate::v1::record::get_record(1, 2).send(&client).unwrap_err();
+        assert!(matches!(err, ApiError::Kintone(e) if e.code == "GAIA_RE01"));
+        assert!(crate::v1::record::get_record(1, 2).send(&client).is_ok());
+        assert!(crate::v1::record::get_record(1, 2).send(&client).is_ok());
+
+        let err = crate::v1::app::get_apps().send(&client).unwrap_err();
+        assert!(matches!(err, ApiError::Http(e) if e.status == 404));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].headers["x-cybozu-api-token"], "t");
+    }
+
+    #[test]
+    fn app_code_cache() {
+        let apps = r#"{"apps":[{"appId":"42","code":"PROJECT","name":"Projects","description":"",
+            "spaceId":null,"threadId":null,"createdAt":"2024-01-01T00:00:00Z",
+            "creator":{"code":"alice","name":"Alice"},"modifiedAt":"2024-01-01T00:00:00Z",
+            "modifier":{"code":"alice","name":"Alice"}}]}"#;
+        let mock = middleware::MockHandler::new().with_response(
+            http::Method::GET,
+            "/k/v1/apps.json",
+            200,
+            apps,
+        );
+        let client = mock_client(&mock);
+
+        client.prewarm_app_codes(["PROJECT", "MISSING"]).unwrap();
+        assert_eq!(mock.requests().len(), 1);
+        assert_eq!(client.resolve_app_code("PROJECT").unwrap(), 42);
+        assert_eq!(mock.requests().len(), 1);
+
+        let err = client.resolve_app_code("MISSING").unwrap_err();
+        assert!(matches!(&err, ApiError::AppCodeNotFound { code } if code == "MISSING"), "{err:?}");
+        assert_eq!(mock.requests().len(), 2);
+
+        client.clear_app_code_cache();
+        assert_eq!(client.resolve_app_code("PROJECT").unwrap(), 42);
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[test]
+    fn transport_errors_are_classified() {
+        use crate::error::TransportErrorKind;
+
+        let refused = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+        let err = ApiError::from(ureq::Error::Io(refused));
+        assert!(
+            matches!(&err, ApiError::Transport(e) if e.kind == TransportErrorKind::ConnectionRefused),
+            "{err:?}"
+        );
+        let err = ApiError::from(ureq::Error::ConnectionFailed);
+        assert!(
+            matches!(&err, ApiError::Transport(e) if e.kind == TransportErrorKind::ConnectionFailed)
+        );
+        let err = ApiError::from(ureq::Error::HostNotFound);
+        assert!(matches!(&err, ApiError::Transport(e) if e.kind == TransportErrorKind::Dns));
+        let err = ApiError::from(ureq::Error::Tls("bad certificate"));
+        assert!(matches!(&err, ApiError::Transport(e) if e.kind == TransportErrorKind::Tls));
+        assert_eq!(err.to_string(), "transport error: TLS error: bad certificate");
+    }
+
+    #[test]
+    fn raw_request() {
+        let mock = middleware::MockHandler::new()
+            .with_response(http::Method::GET, "/k/guest/3/v1/app/acl.json", 200, r#"{"rights":[]}"#)
+            .with_response(
+                http::Method::PUT,
+                "/k/guest/3/v1/preview/app/settings.json",
+                200,
+                r#"{"revision":"2"}"#,
+            );
+        let client = test_client_builder().guest_space_id(3).with_handler(mock.clone()).build();
+
+        let req = client.request(http::Method::GET, "/v1/app/acl.json").query("app", 1);
+        assert_eq!(req.debug_url(), "https://example.cybozu.com/k/guest/3/v1/app/acl.json?app=1");
+        let resp: serde_json::Value = req.header("x-custom", "yes").send().unwrap();
+        assert_eq!(resp["rights"], serde_json::json!([]));
+
+        let resp: serde_json::Value = client
+            .request(http::Method::PUT, "/v1/preview/app/settings.json")
+            .json_body(serde_json::json!({"app": 1, "name": "New"}))
+            .send()
+            .unwrap();
+        assert_eq!(resp["revision"], "2");
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].headers["x-custom"], "yes");
+        assert_eq!(requests[0].headers["x-cybozu-api-token"], "t");
+        assert_eq!(requests[1].body_json()["name"], "New");
+    }
+
+    #[test]
+    fn compression_is_negotiated_by_default() {
+        use ureq::config::AutoHeaderValue;
+
+        let client =
+            KintoneClient::new("https://example.cybozu.com", Auth::api_token("t".to_owned()));
+        let accept_encoding = client.http_agent().0.config().accept_encoding().clone();
+        assert!(matches!(accept_encoding, AutoHeaderValue::Default), "{accept_encoding:?}");
+
+        let client = test_client_builder().compression(false).build();
+        let accept_encoding = client.http_agent().0.config().accept_encoding().clone();
+        assert!(matches!(accept_encoding, AutoHeaderValue::None), "{accept_encoding:?}");
+    }
+
+    #[test]
+    fn custom_root_certificates() {
+        let auth = Auth::api_token("t".to_owned());
+        let pem = include_str!("testdata/root_ca.pem");
+        let bundle = format!("{pem}{pem}");
+        let client = KintoneClient::builder("https://example.cybozu.com", auth.clone())
+            .add_root_certificate(bundle.as_bytes())
+            .unwrap()
+            .danger_accept_invalid_certs(true)
+            .build();
+        let tls = client.http_agent().0.config().tls_config().clone();
+        assert!(matches!(tls.root_certs(), RootCerts::Specific(certs) if certs.len() == 2));
+        assert!(tls.disable_verification());
+
+        let default = KintoneClient::new("https://example.cybozu.com", auth.clone());
+        let tls = default.http_agent().0.config().tls_config().clone();
+        assert!(matches!(tls.root_certs(), RootCerts::WebPki));
+        assert!(!tls.disable_verification());
+
+        let err = KintoneClient::builder("https://example.cybozu.com", auth)
+            .add_root_certificate(b"not a certificate")
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn auth_failures_are_typed() {
+        let mock = middleware::MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/record.json",
+                401,
+                r#"{"code":"CB_WA01","id":"abc","message":"Password authentication failed."}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records.json",
+                403,
+                r#"{"code":"GAIA_NO01","id":"def","message":"Using this API token, you cannot run the specified API."}"#,
+            );
+        let client = mock_client(&mock);
+
+        let err = crate::v1::record::get_record(1, 2).send(&client).unwrap_err();
+        assert!(matches!(err, ApiError::Unauthorized { .. }), "{err:?}");
+
+        let err = crate::v1::record::get_records(1).send(&client).unwrap_err();
+        match err {
+            ApiError::Forbidden {
+                required_permission,
+                ..
+            } => assert_eq!(
+                required_permission.as_deref(),
+                Some("an API token with permission for this API")
+            ),
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn check_connection_reports_auth_failure() {
+        let mock = middleware::MockHandler::new()
+            .with_response(http::Method::GET, "/k/v1/apps.json", 200, r#"{"apps":[]}"#)
+            .with_response(
+                http::Method::GET,
+                "/k/v1/apps.json",
+                401,
+                r#"{"code":"CB_WA01","id":"abc","message":"Password authentication failed."}"#,
+            );
+        let client = mock_client(&mock);
+
+        client.check_connection().unwrap();
+        let err = client.check_connection().unwrap_err();
+        assert!(matches!(err, ConnectionError::AuthenticationFailed(_)), "{err:?}");
+        assert!(mock.requests()[0].uri.ends_with("/k/v1/apps.json?limit=1"));
+    }
+
+    #[test]
+    fn check_connection_reports_non_kintone_page_as_unreachable() {
+        struct NotKintone;
+        impl middleware::Handler for NotKintone {
+            fn handle(
+                &self,
+                _req: http::Request<middleware::RequestBody>,
+            ) -> Result<http::Response<middleware::ResponseBody>, ApiError> {
+                Ok(http::Response::builder()
+                    .status(404)
+                    .body(middleware::ResponseBody::from_reader(
+                        Cursor::new("<html>Not Found</html>"),
+                        Some("text/html"),
+                    ))
+                    .unwrap())
+            }
+        }
+        let client = test_client_builder().with_handler(NotKintone).build();
+
+        let err = client.check_connection().unwrap_err();
+        assert!(matches!(err, ConnectionError::Unreachable(ApiError::Http(_))), "{err:?}");
+    }
+
+    #[test]
+    fn upload_encodes_utf8_filename() {
+        let mock = middleware::MockHandler::new()
+            .with_response(http::Method::POST, "/k/v1/file.json", 200, r#"{"fileKey":"k1"}"#)
+            .with_response(http::Method::POST, "/k/v1/file.json", 200, r#"{"fileKey":"k2"}"#);
+        let client = mock_client(&mock);
+
+        crate::v1::file::upload("見積書 (1).pdf")
+            .send(&client, Cursor::new(b"%PDF".to_vec()))
+            .unwrap();
+        crate::v1::file::upload("a\"b.txt")
+            .send(&client, Cursor::new(b"x".to_vec()))
+            .unwrap();
+
+        let requests = mock.requests();
+        let body = String::from_utf8(requests[0].body.clone()).unwrap();
+        assert!(
+            body.contains(
+                "Content-Disposition: form-data; name=\"file\"; filename=\"見積書 (1).pdf\"; \
+                 filename*=UTF-8''%E8%A6%8B%E7%A9%8D%E6%9B%B8%20%281%29.pdf\r\n"
+            ),
+            "{body}"
+        );
+        let body = String::from_utf8(requests[1].body.clone()).unwrap();
+        assert!(
+            body.contains(
+                "Content-Disposition: form-data; name=\"file\"; filename=\"a\\\"b.txt\"\r\n"
+            ),
+            "{body}"
+        );
+    }
+
+    #[test]
+    fn cloned_builder_shares_layers() {
+        let attempts = Arc::new(Mutex::new(0));
+        let counter = Arc::clone(&attempts);
+        let mock = middleware::MockHandler::new();
+        let template =
+            KintoneClient::builder("https://a.cybozu.com", Auth::api_token("a".to_owned()))
+                .with_handler(mock.clone())
+                .layer(
+                    middleware::RetryLayer::new()
+                        .with_max_attempts(2)
+                        .with_backoff(middleware::FixedBackoff(Duration::ZERO))
+                        .with_on_retry(Box::new(move |_, _, _| *counter.lock().unwrap() += 1)),
+                );
+        let a = template.clone().build();
+        let b = template.base_url("https://b.cybozu.com").build();
+
+        for client in [&a, &b] {
+            let err = crate::v1::record::get_record(1, 2).send(client).unwrap_err();
+            assert!(matches!(err, ApiError::Retried { attempts: 2, .. }), "{err:?}");
+        }
+        assert_eq!(*attempts.lock().unwrap(), 2);
+        let hosts: Vec<String> = mock.requests().into_iter().map(|r| r.uri).collect();
+        assert!(hosts[0].starts_with("https://a.cybozu.com/"), "{hosts:?}");
+        assert!(hosts[2].starts_with("https://b.cybozu.com/"), "{hosts:?}");
+    }
+
+    #[test]
+    fn raw_request_user_agent_override() {
+        let mock = middleware::MockHandler::new().with_response(
+            http::Method::GET,
+            "/k/v1/records.json",
+            200,
+            r#"{"records":[]}"#,
+        );
+        let client =
+            test_client_builder().user_agent("MyApp/1.0").with_handler(mock.clone()).build();
+
+        let _: serde_json::Value = client
+            .request(http::Method::GET, "/v1/records.json")
+            .header("User-Agent", "first")
+            .user_agent("MyApp/1.0 (import-job)")
+            .send()
+            .unwrap();
+        let requests = mock.requests();
+        let agents: Vec<_> = requests[0].headers.get_all(http::header::USER_AGENT).iter().collect();
+        assert_eq!(agents, ["MyApp/1.0 (import-job)"]);
+    }
+
+    #[test]
+    fn request_id_on_success_and_error() {
+        struct Tagged;
+        impl middleware::Handler for Tagged {
+            fn handle(
+                &self,
+                req: http::Request<middleware::RequestBody>,
+            ) -> Result<http::Response<middleware::ResponseBody>, ApiError> {
+                let (status, body, mime) = if req.uri().path() == "/k/v1/app.json" {
+                    (200, r#"{"appId":"1"}"#, "application/json")
+                } else {
+                    (502, "Bad Gateway", "text/plain")
+                };
+                Ok(http::Response::builder()
+                    .status(status)
+                    .header("X-Cybozu-Request-Id", "req-42")
+                    .body(middleware::ResponseBody::from_reader(
+                        std::io::Cursor::new(body),
+                        Some(mime),
+                    ))
+                    .unwrap())
+            }
+        }
+        let client = test_client_builder().with_handler(Tagged).build();
+
+        let (resp, meta) = client
+            .request(http::Method::GET, "/v1/app.json")
+            .send_with_metadata::<serde_json::Value>()
+            .unwrap();
+        assert_eq!(resp["appId"], "1");
+        assert_eq!(meta.status, 200);
+        assert_eq!(meta.request_id.as_deref(), Some("req-42"));
+
+        let err = client
+            .request(http::Method::GET, "/v1/records.json")
+            .send::<serde_json::Value>()
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Http(ref e) if e.status == 502), "{err:?}");
+        assert_eq!(err.request_id(), Some("req-42"));
+    }
+
+    #[test]
+    fn client_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<KintoneClient>();
+
+        let client = test_client_builder().build();
+        let agent = client.http_agent();
+        client.shutdown();
+        // The shared pool outlives the client that created it.
+        let other = test_client_builder().with_shared_agent(agent).build();
+        other.shutdown();
+    }
+
+    #[test]
+    fn debug_url() {
+        let auth = Auth::api_token("token".to_owned());
+        let client = KintoneClient::builder("https://example.cybozu.com", auth)
+            .guest_space_id(5)
+            .build();
+        let url = crate::v1::record::get_record(1, 2).debug_url(&client);
+        assert_eq!(url, "https://example.cybozu.com/k/guest/5/v1/record.json?app=1&id=2");
+        let url = crate::v1::file::download("abc").debug_url(&client);
+        assert_eq!(url, "https://example.cybozu.com/k/guest/5/v1/file.json?fileKey=abc");
+    }
+
+    #[test]
+    fn custom_base_path() {
+        let auth = Auth::api_token("token".to_owned());
+        let client = KintoneClient::builder("https://example.internal", auth.clone())
+            .base_path("/kintone/k/")
+            .build();
+        assert_eq!(request_uri(&client), "https://example.internal/kintone/k/v1/record.json?app=1");
+
+        let client = KintoneClient::builder("https://example.internal", auth)
+            .base_path("kintone")
+            .guest_space_id(5)
+            .build();
+        assert_eq!(
+            request_uri(&client),
+            "https://example.internal/kintone/guest/5/v1/record.json?app=1"
+        );
+    }
+}
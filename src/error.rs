@@ -4,6 +4,8 @@
 //! All API operations return `Result<T, ApiError>` where errors can be categorized
 //! into I/O errors or HTTP-specific errors.
 
+use std::collections::BTreeMap;
+
 use serde::Deserialize;
 
 /// HTTP-specific error containing status code and response body.
@@ -15,11 +17,83 @@ use serde::Deserialize;
 /// # Fields
 /// * `status` - The HTTP status code (e.g., 404, 500)
 /// * `body` - The response body as a string, which may contain error details from Kintone
+/// * `request_id` - The value of the `X-Cybozu-Request-Id` response header, if present
+///
+/// Construct one with [`HttpError::new`]; more fields may be added in the future.
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("status={status}, body={body:?}")]
+#[non_exhaustive]
 pub struct HttpError {
     pub status: u16,
     pub body: String,
+    pub request_id: Option<String>,
+}
+
+impl HttpError {
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        HttpError {
+            status,
+            body: body.into(),
+            request_id: None,
+        }
+    }
+
+    /// Sets the request id reported in the `X-Cybozu-Request-Id` response header.
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+}
+
+/// A failure in the network layer, before or while receiving a response.
+///
+/// The [`kind`](TransportError::kind) tells what went wrong in a way that can be matched on,
+/// and the source error carries the details. For example, a misconfigured client certificate
+/// usually shows up as [`TransportErrorKind::Tls`].
+#[derive(Debug, thiserror::Error)]
+#[error("{kind}: {source}")]
+pub struct TransportError {
+    pub kind: TransportErrorKind,
+    source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl TransportError {
+    pub fn new(
+        kind: TransportErrorKind,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        TransportError {
+            kind,
+            source: source.into(),
+        }
+    }
+}
+
+/// The category of a [`TransportError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum TransportErrorKind {
+    /// The host name could not be resolved
+    #[error("DNS lookup failed")]
+    Dns,
+    /// The TLS handshake failed or a certificate could not be used
+    #[error("TLS error")]
+    Tls,
+    /// The server refused the connection
+    #[error("connection refused")]
+    ConnectionRefused,
+    /// The connection could not be established for another reason
+    #[error("connection failed")]
+    ConnectionFailed,
+    /// The request timed out
+    #[error("timed out")]
+    Timeout,
+    /// An I/O error occurred while sending the request or reading the response
+    #[error("i/o error")]
+    Io,
+    /// Any other failure in the HTTP client
+    #[error("http client error")]
+    Other,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -29,6 +103,177 @@ pub struct KintoneError {
     pub code: String,
     pub id: String,
     pub message: String,
+    /// Validation messages keyed by the path of the offending value,
+    /// e.g. `"records[2].price.value"`. Empty unless Kintone reported field errors.
+    pub errors: BTreeMap<String, Vec<String>>,
+}
+
+impl KintoneError {
+    /// Returns the error code as a [`KintoneErrorCode`], for matching without string literals.
+    pub fn error_code(&self) -> KintoneErrorCode {
+        KintoneErrorCode::from(self.code.as_str())
+    }
+
+    /// Returns the per-field validation errors reported by Kintone, one entry per message.
+    ///
+    /// For bulk operations such as [`add_records`](crate::v1::record::add_records), the
+    /// `record_index` tells which record in the request was rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use kintone::error::KintoneError;
+    ///
+    /// let error = KintoneError {
+    ///     status: 400,
+    ///     code: "CB_VA01".to_owned(),
+    ///     id: "abc".to_owned(),
+    ///     message: "Missing or invalid input.".to_owned(),
+    ///     errors: BTreeMap::from([(
+    ///         "records[2].price.value".to_owned(),
+    ///         vec!["Only numbers are allowed.".to_owned()],
+    ///     )]),
+    /// };
+    /// let field_errors = error.field_errors();
+    /// assert_eq!(field_errors[0].record_index, Some(2));
+    /// assert_eq!(field_errors[0].field_code, "price");
+    /// assert_eq!(field_errors[0].message, "Only numbers are allowed.");
+    /// ```
+    pub fn field_errors(&self) -> Vec<BulkFieldError> {
+        let mut field_errors = Vec::new();
+        for (path, messages) in &self.errors {
+            let (record_index, field_path) = split_record_index(path);
+            let field_code = field_path.split('.').next().unwrap_or_default();
+            for message in messages {
+                field_errors.push(BulkFieldError {
+                    record_index,
+                    field_code: field_code.to_owned(),
+                    path: path.clone(),
+                    message: message.clone(),
+                    code: self.code.clone(),
+                });
+            }
+        }
+        // Paths sort as strings ("records[10]" < "records[2]"), so order by index explicitly.
+        field_errors.sort_by_key(|e| e.record_index);
+        field_errors
+    }
+}
+
+/// A validation error for a single field, extracted by [`KintoneError::field_errors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkFieldError {
+    /// The index of the record in a bulk request, or `None` for single-record requests
+    pub record_index: Option<usize>,
+    /// The code of the top-level field that has the error
+    pub field_code: String,
+    /// The full path of the value as reported by Kintone, e.g. `records[0].table.value[1].value.qty.value`
+    pub path: String,
+    /// The error message
+    pub message: String,
+    /// The error code of the response, e.g. `CB_VA01`
+    pub code: String,
+}
+
+/// Splits `records[N].rest` into `(Some(N), "rest")` and `record.rest` into `(None, "rest")`.
+fn split_record_index(path: &str) -> (Option<usize>, &str) {
+    if let Some(rest) = path.strip_prefix("records[")
+        && let Some((index, rest)) = rest.split_once("].")
+        && let Ok(index) = index.parse()
+    {
+        return (Some(index), rest);
+    }
+    (None, path.strip_prefix("record.").unwrap_or(path))
+}
+
+/// A Kintone error code, such as `GAIA_RE01`, as returned in [`KintoneError::code`].
+///
+/// Commonly handled codes have their own variant; any other code is kept as
+/// [`Unknown`](KintoneErrorCode::Unknown). Converting from a string and back with
+/// [`as_str`](KintoneErrorCode::as_str) always yields the original code.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::error::KintoneErrorCode;
+///
+/// assert_eq!(KintoneErrorCode::from("GAIA_DA02"), KintoneErrorCode::DatabaseLock);
+/// assert_eq!(KintoneErrorCode::DatabaseLock.as_str(), "GAIA_DA02");
+/// assert_eq!(KintoneErrorCode::from("GAIA_XX99"), KintoneErrorCode::Unknown("GAIA_XX99".to_owned()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KintoneErrorCode {
+    /// `GAIA_DA02`: the database is locked by another operation. Retrying later may succeed.
+    DatabaseLock,
+    /// `GAIA_CO02`: the given revision does not match the latest revision of the record.
+    RevisionConflict,
+    /// `GAIA_RE01`: the specified record does not exist.
+    RecordNotFound,
+    /// `GAIA_AP01`: the specified app does not exist.
+    AppNotFound,
+    /// `GAIA_NO01`: the API token has no permission for the API.
+    ApiTokenNoPermission,
+    /// `CB_NO02`: the user has no permission for the operation.
+    NoPermission,
+    /// `CB_VA01`: the request contains missing or invalid values.
+    InvalidInput,
+    /// `CB_IL02`: the request is malformed.
+    IllegalRequest,
+    /// `CB_WA01`: password authentication failed.
+    PasswordAuthFailed,
+    /// Any other error code.
+    Unknown(String),
+}
+
+/// The codes with their own [`KintoneErrorCode`] variant.
+const ERROR_CODES: &[(KintoneErrorCode, &str)] = &[
+    (KintoneErrorCode::DatabaseLock, "GAIA_DA02"),
+    (KintoneErrorCode::RevisionConflict, "GAIA_CO02"),
+    (KintoneErrorCode::RecordNotFound, "GAIA_RE01"),
+    (KintoneErrorCode::AppNotFound, "GAIA_AP01"),
+    (KintoneErrorCode::ApiTokenNoPermission, "GAIA_NO01"),
+    (KintoneErrorCode::NoPermission, "CB_NO02"),
+    (KintoneErrorCode::InvalidInput, "CB_VA01"),
+    (KintoneErrorCode::IllegalRequest, "CB_IL02"),
+    (KintoneErrorCode::PasswordAuthFailed, "CB_WA01"),
+];
+
+impl KintoneErrorCode {
+    /// Returns the code as Kintone writes it, e.g. `"GAIA_DA02"`.
+    pub fn as_str(&self) -> &str {
+        if let Self::Unknown(code) = self {
+            return code;
+        }
+        ERROR_CODES
+            .iter()
+            .find(|(variant, _)| variant == self)
+            .map(|(_, code)| *code)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the error is temporary, so that sending the same request again may
+    /// succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::DatabaseLock)
+    }
+}
+
+impl From<&str> for KintoneErrorCode {
+    fn from(code: &str) -> Self {
+        ERROR_CODES
+            .iter()
+            .find(|(_, c)| *c == code)
+            .map(|(variant, _)| variant.clone())
+            .unwrap_or_else(|| Self::Unknown(code.to_owned()))
+    }
+}
+
+impl std::fmt::Display for KintoneErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 #[derive(Deserialize)]
@@ -36,6 +281,14 @@ struct KintoneErrorJson {
     pub code: String,
     pub id: String,
     pub message: String,
+    #[serde(default)]
+    pub errors: BTreeMap<String, KintoneErrorMessages>,
+}
+
+#[derive(Deserialize)]
+struct KintoneErrorMessages {
+    #[serde(default)]
+    messages: Vec<String>,
 }
 
 /// The main error type for all Kintone API operations.
@@ -45,14 +298,34 @@ struct KintoneErrorJson {
 /// connection problems) and HTTP errors (API-specific error responses).
 ///
 /// # Variants
-/// * `Io` - I/O related errors such as network connectivity issues
+/// * `Io` - I/O related errors that do not come from the network layer
+/// * `Transport` - Network failures such as DNS resolution, TLS handshake, or connection errors
 /// * `Http` - HTTP-specific errors with status codes and response bodies
+/// * `Kintone` - An error response returned by Kintone
+/// * `RevisionConflict` - The record was modified by someone else since the revision given
+///   in the request (Kintone error code `GAIA_CO02`)
+/// * `Unauthorized` - Authentication failed (HTTP 401)
+/// * `Forbidden` - The credentials lack the permission for the operation (HTTP 403)
+/// * `InvalidRequest` - The request was rejected client-side before being sent,
+///   e.g. because it exceeds an API limit
+/// * `AppCodeNotFound` - No app has the app code the request was made with
+/// * `AuthMethodNotSupported` - The operation cannot be performed with the client's
+///   authentication method, e.g. adding an app with an API token
+/// * `ResponseTooLarge` - The response body exceeded the configured maximum size
+/// * `UnexpectedContentType` - The server returned something other than JSON,
+///   e.g. an HTML maintenance page
+/// * `Retried` - The request kept failing until [`RetryLayer`] used up all attempts
+///
+/// [`RetryLayer`]: crate::middleware::RetryLayer
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum ApiError {
     #[error("i/o error: {0}")]
     Io(#[from] std::io::Error),
 
+    #[error("transport error: {0}")]
+    Transport(#[from] TransportError),
+
     #[error("http error: {0}")]
     Http(#[from] HttpError),
 
@@ -61,11 +334,131 @@ pub enum ApiError {
 
     #[error("kintone error: {0}")]
     Kintone(#[from] KintoneError),
+
+    #[error("revision conflict: {0}")]
+    RevisionConflict(KintoneError),
+
+    #[error("unauthorized: {message}")]
+    Unauthorized {
+        message: String,
+        /// The id Kintone assigned to the failed request, when known.
+        request_id: Option<String>,
+    },
+
+    #[error("forbidden: {message}{}", required_permission.as_ref().map(|p| format!(" (requires {p})")).unwrap_or_default())]
+    Forbidden {
+        message: String,
+        /// The permission or authentication method the operation requires, when known.
+        required_permission: Option<String>,
+        /// The id Kintone assigned to the failed request, when known.
+        request_id: Option<String>,
+    },
+
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+
+    #[error("no app found with code {code:?}")]
+    AppCodeNotFound { code: String },
+
+    #[error(
+        "{operation} cannot be performed with API token authentication; use password authentication"
+    )]
+    AuthMethodNotSupported {
+        /// A short description of the rejected operation, such as `"adding an app"`.
+        operation: String,
+    },
+
+    #[error("response body exceeds the limit of {limit} bytes")]
+    ResponseTooLarge { limit: u64 },
+
+    #[error("expected a JSON response but got {content_type:?}: {snippet:?}")]
+    UnexpectedContentType {
+        content_type: String,
+        /// The beginning of the response body, for diagnostics.
+        snippet: String,
+    },
+
+    #[error("gave up after {attempts} attempts: {last}")]
+    Retried {
+        attempts: usize,
+        #[source]
+        last: Box<ApiError>,
+    },
+}
+
+impl ApiError {
+    /// Returns the error of the final attempt, looking through [`ApiError::Retried`].
+    ///
+    /// This is convenient for matching on the underlying cause regardless of whether the
+    /// request was retried.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::error::{ApiError, HttpError};
+    ///
+    /// let err = ApiError::Retried {
+    ///     attempts: 5,
+    ///     last: Box::new(ApiError::Http(HttpError::new(503, ""))),
+    /// };
+    /// assert!(matches!(err.final_error(), ApiError::Http(e) if e.status == 503));
+    /// ```
+    pub fn final_error(&self) -> &ApiError {
+        match self {
+            ApiError::Retried { last, .. } => last.final_error(),
+            other => other,
+        }
+    }
+
+    /// Returns the id Kintone assigned to the failed request, if the server reported one.
+    ///
+    /// Cybozu support asks for this id when investigating a failure, so it is worth including
+    /// in logs. It is taken from the `id` of a Kintone error response, or from the
+    /// `X-Cybozu-Request-Id` response header when the body does not carry one. Retried
+    /// requests report the id of the final attempt.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::error::{ApiError, HttpError};
+    ///
+    /// let err = ApiError::Http(HttpError::new(502, "").with_request_id("f3b4c2a1"));
+    /// assert_eq!(err.request_id(), Some("f3b4c2a1"));
+    /// ```
+    pub fn request_id(&self) -> Option<&str> {
+        match self.final_error() {
+            ApiError::Kintone(e) | ApiError::RevisionConflict(e) => {
+                (!e.id.is_empty()).then_some(e.id.as_str())
+            }
+            ApiError::Http(e) => e.request_id.as_deref(),
+            ApiError::Unauthorized { request_id, .. } | ApiError::Forbidden { request_id, .. } => {
+                request_id.as_deref()
+            }
+            _ => None,
+        }
+    }
 }
 
 impl From<ureq::Error> for ApiError {
     fn from(err: ureq::Error) -> Self {
-        Self::Io(err.into_io())
+        let kind = match &err {
+            ureq::Error::BodyExceedsLimit(limit) => {
+                return ApiError::ResponseTooLarge { limit: *limit };
+            }
+            ureq::Error::HostNotFound => TransportErrorKind::Dns,
+            ureq::Error::Tls(_) | ureq::Error::Pem(_) | ureq::Error::Rustls(_) => {
+                TransportErrorKind::Tls
+            }
+            ureq::Error::ConnectionFailed => TransportErrorKind::ConnectionFailed,
+            ureq::Error::Timeout(_) => TransportErrorKind::Timeout,
+            ureq::Error::Io(e) => match e.kind() {
+                std::io::ErrorKind::ConnectionRefused => TransportErrorKind::ConnectionRefused,
+                std::io::ErrorKind::TimedOut => TransportErrorKind::Timeout,
+                _ => TransportErrorKind::Io,
+            },
+            _ => TransportErrorKind::Other,
+        };
+        ApiError::Transport(TransportError::new(kind, err))
     }
 }
 
@@ -92,11 +485,28 @@ impl From<http::Response<ureq::Body>> for ApiError {
     fn from(mut response: http::Response<ureq::Body>) -> ApiError {
         const MAX_JSON_SIZE: u64 = 10 * 1024 * 1024;
 
+        let status = response.status().as_u16();
+        let header_request_id = request_id_header(response.headers());
         if !is_json_response(&response) {
-            let status = response.status().as_u16();
-            return match response.body_mut().read_to_string() {
-                Ok(body) => ApiError::Http(HttpError { status, body }),
-                Err(e) => ApiError::Io(e.into_io()),
+            let body = match response.body_mut().read_to_string() {
+                Ok(body) => body,
+                Err(e) => return e.into(),
+            };
+            return match status {
+                401 => ApiError::Unauthorized {
+                    message: body,
+                    request_id: header_request_id,
+                },
+                403 => ApiError::Forbidden {
+                    message: body,
+                    required_permission: None,
+                    request_id: header_request_id,
+                },
+                _ => ApiError::Http(HttpError {
+                    status,
+                    body,
+                    request_id: header_request_id,
+                }),
             };
         };
         // If the response is JSON, attempt to parse it as KintoneError.
@@ -105,14 +515,136 @@ impl From<http::Response<ureq::Body>> for ApiError {
             Err(e) => return e.into(),
         };
         match serde_json::from_slice::<KintoneErrorJson>(&body) {
-            Ok(error_json) => KintoneError {
-                status: response.status().as_u16(),
-                code: error_json.code,
-                id: error_json.id,
-                message: error_json.message,
+            Ok(error_json) => {
+                let id = if error_json.id.is_empty() {
+                    header_request_id.unwrap_or_default()
+                } else {
+                    error_json.id
+                };
+                let error = KintoneError {
+                    status,
+                    code: error_json.code,
+                    id,
+                    message: error_json.message,
+                    errors: error_json
+                        .errors
+                        .into_iter()
+                        .map(|(path, messages)| (path, messages.messages))
+                        .collect(),
+                };
+                match status {
+                    401 => ApiError::Unauthorized {
+                        request_id: (!error.id.is_empty()).then_some(error.id),
+                        message: error.message,
+                    },
+                    403 => ApiError::Forbidden {
+                        required_permission: required_permission(&error.error_code())
+                            .map(str::to_owned),
+                        request_id: (!error.id.is_empty()).then_some(error.id),
+                        message: error.message,
+                    },
+                    _ if error.error_code() == KintoneErrorCode::RevisionConflict => {
+                        ApiError::RevisionConflict(error)
+                    }
+                    _ => ApiError::Kintone(error),
+                }
             }
-            .into(),
             Err(e) => e.into(),
         }
     }
 }
+
+/// The response header carrying the id Kintone assigns to each request.
+pub(crate) const REQUEST_ID_HEADER: &str = "x-cybozu-request-id";
+
+/// Reads the request id from the response headers, if present.
+pub(crate) fn request_id_header(headers: &http::HeaderMap) -> Option<String> {
+    headers.get(REQUEST_ID_HEADER).and_then(|v| v.to_str().ok()).map(str::to_owned)
+}
+
+/// Describes what is missing for the Kintone error codes that are returned with HTTP 403.
+fn required_permission(code: &KintoneErrorCode) -> Option<&'static str> {
+    match code {
+        KintoneErrorCode::ApiTokenNoPermission => Some("an API token with permission for this API"),
+        _ => None,
+    }
+}
+
+/// An error from waiting for an app deployment to finish.
+///
+/// Returned by [`wait_for_deploy`](crate::v1::app::settings::wait_for_deploy) and
+/// [`DeployStatusPoller::wait`](crate::v1::app::settings::DeployStatusPoller::wait).
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum DeployError {
+    /// The deployment of `app_id` did not succeed.
+    ///
+    /// When several apps are deployed together and one of them fails, Kintone cancels the
+    /// others; `app_id` names the app that failed rather than one that was cancelled.
+    /// Kintone does not report why a deployment failed, so `reason` is only set when it can
+    /// be told from the statuses, e.g. when the deployment was cancelled.
+    #[error("deployment of app {app_id} failed{}", reason.as_ref().map(|r| format!(": {r}")).unwrap_or_default())]
+    Failed { app_id: u64, reason: Option<String> },
+
+    /// The deployment status could not be retrieved.
+    #[error(transparent)]
+    Api(#[from] ApiError),
+}
+
+/// An error from [`KintoneClient::check_connection`](crate::client::KintoneClient::check_connection).
+///
+/// The variants separate the failures that usually call for different fixes: an unreachable
+/// server points at the base URL or the network, while rejected credentials point at the
+/// authentication settings.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ConnectionError {
+    /// Kintone could not be reached, or something other than Kintone answered, such as a web
+    /// server returning an HTML error page because the base URL is wrong.
+    #[error("cannot reach kintone: {0}")]
+    Unreachable(ApiError),
+
+    /// Kintone was reached but rejected the credentials.
+    #[error("kintone rejected the credentials: {0}")]
+    AuthenticationFailed(ApiError),
+
+    /// Any other failure of the probe request.
+    #[error(transparent)]
+    Api(ApiError),
+}
+
+impl From<ApiError> for ConnectionError {
+    fn from(err: ApiError) -> Self {
+        match err.final_error() {
+            ApiError::Io(_)
+            | ApiError::Transport(_)
+            | ApiError::Http(_)
+            | ApiError::UnexpectedContentType { .. } => ConnectionError::Unreachable(err),
+            ApiError::Unauthorized { .. } | ApiError::Forbidden { .. } => {
+                ConnectionError::AuthenticationFailed(err)
+            }
+            ApiError::Kintone(e) if e.error_code() == KintoneErrorCode::PasswordAuthFailed => {
+                ConnectionError::AuthenticationFailed(err)
+            }
+            _ => ConnectionError::Api(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_code_mapping_round_trips() {
+        for (variant, code) in ERROR_CODES {
+            assert_eq!(&KintoneErrorCode::from(*code), variant);
+            assert_eq!(variant.as_str(), *code);
+        }
+        let unknown = KintoneErrorCode::from("GAIA_ZZ01");
+        assert_eq!(unknown, KintoneErrorCode::Unknown("GAIA_ZZ01".to_owned()));
+        assert_eq!(unknown.to_string(), "GAIA_ZZ01");
+        assert!(KintoneErrorCode::DatabaseLock.is_retryable());
+        assert!(!KintoneErrorCode::InvalidInput.is_retryable());
+    }
+}
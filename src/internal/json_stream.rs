@@ -0,0 +1,203 @@
+use std::io::{self, BufRead, BufReader, Read};
+use std::marker::PhantomData;
+
+use serde::de::{DeserializeOwned, Error as _};
+
+use crate::error::ApiError;
+use crate::middleware::body_read_error;
+
+/// Reads the elements of an array stored under `key` of a top-level JSON object one at a time.
+///
+/// Only the bytes of the current element are held in memory. Other members of the object
+/// that come before the array are skipped without being parsed, and anything after the
+/// array is not read at all.
+pub(crate) struct JsonArrayStream<R, T> {
+    reader: BufReader<R>,
+    key: &'static str,
+    state: State,
+    _marker: PhantomData<fn() -> T>,
+}
+
+enum State {
+    Start,
+    First,
+    Rest,
+    Done,
+}
+
+impl<R: Read, T: DeserializeOwned> JsonArrayStream<R, T> {
+    pub fn new(reader: R, key: &'static str) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            key,
+            state: State::Start,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Advances to the first byte after the `[` that opens the array.
+    fn seek_array(&mut self) -> Result<(), ApiError> {
+        self.expect(b'{')?;
+        let mut buf = Vec::new();
+        loop {
+            match self.skip_whitespace()? {
+                Some(b'"') => {}
+                _ => return Err(syntax(format!("no `{}` array in the response", self.key))),
+            }
+            buf.clear();
+            self.read_value(&mut buf)?;
+            let key: String = serde_json::from_slice(&buf)?;
+            self.expect(b':')?;
+            if key == self.key {
+                return self.expect(b'[');
+            }
+            buf.clear();
+            self.read_value(&mut buf)?;
+            if self.skip_whitespace()? == Some(b',') {
+                self.reader.consume(1);
+            }
+        }
+    }
+
+    /// Reads the next element, or returns `None` at the end of the array.
+    fn read_element(&mut self) -> Result<Option<T>, ApiError> {
+        if let State::Start = self.state {
+            self.seek_array()?;
+            self.state = State::First;
+        }
+        if self.skip_whitespace()? == Some(b']') {
+            return Ok(None);
+        }
+        if let State::Rest = self.state {
+            self.expect(b',')?;
+        }
+        self.state = State::Rest;
+        let mut buf = Vec::new();
+        self.read_value(&mut buf)?;
+        Ok(Some(serde_json::from_slice(&buf)?))
+    }
+
+    /// Appends the bytes of the next JSON value to `out` without interpreting them.
+    fn read_value(&mut self, out: &mut Vec<u8>) -> Result<(), ApiError> {
+        match self.skip_whitespace()? {
+            None => Err(unexpected_end()),
+            Some(b'"') => {
+                self.bump(out)?;
+                self.read_string_tail(out)
+            }
+            Some(b'{' | b'[') => {
+                let mut depth = 0usize;
+                loop {
+                    match self.bump(out)? {
+                        b'"' => self.read_string_tail(out)?,
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Ok(());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Some(_) => {
+                while let Some(b) = self.peek()? {
+                    if matches!(b, b',' | b']' | b'}') || is_whitespace(b) {
+                        break;
+                    }
+                    self.bump(out)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Reads the rest of a string whose opening quote has already been read.
+    fn read_string_tail(&mut self, out: &mut Vec<u8>) -> Result<(), ApiError> {
+        loop {
+            match self.bump(out)? {
+                b'"' => return Ok(()),
+                b'\\' => {
+                    self.bump(out)?;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), ApiError> {
+        match self.skip_whitespace()? {
+            Some(b) if b == expected => {
+                self.reader.consume(1);
+                Ok(())
+            }
+            Some(b) => Err(syntax(format!(
+                "expected `{}` but found `{}`",
+                expected as char,
+                b.escape_ascii()
+            ))),
+            None => Err(unexpected_end()),
+        }
+    }
+
+    /// Skips whitespace and returns the next byte without consuming it.
+    fn skip_whitespace(&mut self) -> Result<Option<u8>, ApiError> {
+        loop {
+            match self.peek()? {
+                Some(b) if is_whitespace(b) => self.reader.consume(1),
+                other => return Ok(other),
+            }
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, ApiError> {
+        let buf = self.reader.fill_buf().map_err(read_error)?;
+        Ok(buf.first().copied())
+    }
+
+    /// Consumes the next byte, appending it to `out`.
+    fn bump(&mut self, out: &mut Vec<u8>) -> Result<u8, ApiError> {
+        let b = self.peek()?.ok_or_else(unexpected_end)?;
+        self.reader.consume(1);
+        out.push(b);
+        Ok(b)
+    }
+}
+
+impl<R: Read, T: DeserializeOwned> Iterator for JsonArrayStream<R, T> {
+    type Item = Result<T, ApiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let State::Done = self.state {
+            return None;
+        }
+        match self.read_element() {
+            Ok(Some(element)) => Some(Ok(element)),
+            Ok(None) => {
+                self.state = State::Done;
+                None
+            }
+            Err(e) => {
+                self.state = State::Done;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+fn is_whitespace(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+fn read_error(e: io::Error) -> ApiError {
+    body_read_error(e, u64::MAX)
+}
+
+fn syntax(msg: String) -> ApiError {
+    ApiError::Json(serde_json::Error::custom(msg))
+}
+
+fn unexpected_end() -> ApiError {
+    syntax("unexpected end of the response body".to_owned())
+}
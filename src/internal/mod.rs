@@ -1 +1,3 @@
+pub(crate) mod json_stream;
 pub(crate) mod serde_helper;
+pub(crate) mod validation;
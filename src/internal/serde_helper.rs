@@ -23,34 +23,62 @@ pub(crate) mod stringified {
     }
 }
 
-pub(crate) mod stringified_or_empty {
-    use std::fmt::Display;
-    use std::str::FromStr;
-
+/// Stringified decimal where an empty string represents `None`.
+///
+/// Decimals are written in plain notation, because `BigDecimal`'s `Display` switches to
+/// exponential notation (e.g. `1E-30`) for very small or very large values.
+///
+/// Values with digit grouping, such as `"1,234,567.5"`, are accepted when reading. Only
+/// commas placed every three digits before the decimal point count as grouping, so a value
+/// like `"1,5"` is still rejected rather than being misread as `15`.
+pub(crate) mod decimal_or_empty {
+    use bigdecimal::BigDecimal;
     use serde::Deserialize;
 
-    // cf. https://stackoverflow.com/questions/75527167/serde-deserialize-string-into-u64
-    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<BigDecimal>, D::Error>
     where
         D: serde::Deserializer<'de>,
-        T: FromStr,
-        <T as FromStr>::Err: Display,
     {
         let s: String = String::deserialize(deserializer)?;
         if s.is_empty() {
             return Ok(None);
         }
-        let v = s.parse::<T>().map_err(serde::de::Error::custom)?;
-        Ok(Some(v))
+        let v = match ungrouped(&s) {
+            Some(plain) => plain.parse(),
+            None => s.parse(),
+        };
+        Ok(Some(v.map_err(serde::de::Error::custom)?))
     }
 
-    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    /// Removes the thousands separators from `s`, or returns `None` if it is not a number
+    /// grouped by commas.
+    fn ungrouped(s: &str) -> Option<String> {
+        let unsigned = s.strip_prefix('-').unwrap_or(s);
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+        let mut groups = integer.split(',');
+        let first = groups.next()?;
+        let rest: Vec<&str> = groups.collect();
+        let is_digits = |g: &str| g.bytes().all(|b| b.is_ascii_digit());
+        if rest.is_empty()
+            || !(1..=3).contains(&first.len())
+            || !is_digits(first)
+            || !rest.iter().all(|g| g.len() == 3 && is_digits(g))
+            || !fraction.is_none_or(|f| !f.is_empty() && is_digits(f))
+        {
+            return None;
+        }
+        Some(s.replace(',', ""))
+    }
+
+    pub fn serialize<S>(value: &Option<BigDecimal>, serializer: S) -> Result<S::Ok, S::Error>
     where
-        T: Display,
         S: serde::Serializer,
     {
         match value {
-            Some(v) => serializer.serialize_str(&v.to_string()),
+            Some(v) => serializer.serialize_str(&v.to_plain_string()),
             None => serializer.serialize_str(""),
         }
     }
@@ -86,3 +114,47 @@ pub(crate) mod option_stringified {
         }
     }
 }
+
+pub(crate) mod empty_as_none {
+    use serde::Deserialize;
+    use serde::de::IntoDeserializer;
+
+    // Kintone represents a blank value as either `null` or `""` depending on the field type.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let opt_s: Option<String> = Option::deserialize(deserializer)?;
+        match opt_s {
+            Some(s) if !s.is_empty() => T::deserialize(s.into_deserializer()).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    // Writes a blank value as `""`, which clears the field when sent to Kintone.
+    pub fn serialize<T, S>(v: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: serde::Serialize,
+        S: serde::Serializer,
+    {
+        match v {
+            Some(value) => value.serialize(serializer),
+            None => serializer.serialize_str(""),
+        }
+    }
+}
+
+pub(crate) mod null_as_default {
+    use serde::Deserialize;
+
+    // Fields that only make sense with some app feature enabled, such as the workflow status,
+    // may be `null` when the feature is off.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: Deserialize<'de> + Default,
+    {
+        Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+    }
+}
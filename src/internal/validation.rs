@@ -0,0 +1,22 @@
+use crate::error::ApiError;
+
+/// Returns an error if `len` items exceed the API limit `max` for `name`.
+pub(crate) fn check_len(name: &str, len: usize, max: usize) -> Result<(), ApiError> {
+    if len > max {
+        return Err(ApiError::InvalidRequest(format!(
+            "too many {name}: {len} given, but at most {max} are allowed per request"
+        )));
+    }
+    Ok(())
+}
+
+/// Returns an error if `value` is longer than `max` characters.
+pub(crate) fn check_text_len(name: &str, value: &str, max: usize) -> Result<(), ApiError> {
+    let len = value.chars().count();
+    if len > max {
+        return Err(ApiError::InvalidRequest(format!(
+            "{name} is too long: {value:?} has {len} characters, but at most {max} are allowed"
+        )));
+    }
+    Ok(())
+}
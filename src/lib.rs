@@ -4,6 +4,9 @@
 //!
 //! **WARNING**: This library is under development and is likely to undergo incompatible changes in the future.
 //!
+//! Response types are marked `#[non_exhaustive]` so that fields Kintone adds to its responses can be
+//! exposed without breaking changes. Read their fields, but do not construct them yourself.
+//!
 //! ## Usage
 //!
 //! This library provides a fluent API for interacting with Kintone REST APIs using method chaining. All API functions return request builders that can be configured with additional parameters and then sent to the Kintone server.
@@ -52,13 +55,13 @@
 //! The library currently supports the following Kintone REST API endpoints:
 //!
 //! - [`v1::record`]: Record management APIs
-//!     - [`v1::record::get_record`], [`v1::record::get_records`], [`v1::record::add_record`], [`v1::record::add_records`], [`v1::record::update_record`], [`v1::record::update_records`], [`v1::record::delete_records`], [`v1::record::bulk_request`], [`v1::record::update_assignees`], [`v1::record::update_status`], [`v1::record::get_comments`], [`v1::record::add_comment`], [`v1::record::delete_comment`], [`v1::record::create_cursor`], [`v1::record::get_records_by_cursor`], [`v1::record::delete_cursor`]
+//!     - [`v1::record::get_record`], [`v1::record::get_records`], [`v1::record::add_record`], [`v1::record::add_records`], [`v1::record::update_record`], [`v1::record::update_records`], [`v1::record::upsert_record`], [`v1::record::upsert_records`], [`v1::record::delete_records`], [`v1::record::bulk_request`], [`v1::record::update_assignees`], [`v1::record::update_status`], [`v1::record::update_statuses`], [`v1::record::get_comments`], [`v1::record::add_comment`], [`v1::record::delete_comment`], [`v1::record::create_cursor`], [`v1::record::get_records_by_cursor`], [`v1::record::delete_cursor`]
 //! - [`v1::file`]: File management APIs
 //!     - [`v1::file::upload`], [`v1::file::download`]
 //! - [`v1::space`]: Space management APIs
 //!     - [`v1::space::add_space`], [`v1::space::delete_space`], [`v1::space::add_thread`], [`v1::space::add_thread_comment`]
 //! - [`v1::app`]: App management APIs
-//!     - [`v1::app::add_app`], [`v1::app::settings::deploy_app`], [`v1::app::settings::get_app_deploy_status`], [`v1::app::form::add_form_field`]
+//!     - [`v1::app::add_app`], [`v1::app::settings::deploy_app`], [`v1::app::settings::get_app_deploy_status`], [`v1::app::settings::get_app_settings`], [`v1::app::settings::update_app_settings`], [`v1::app::settings::get_app_customize`], [`v1::app::settings::update_app_customize`], [`v1::app::form::get_form_fields`], [`v1::app::form::add_form_field`], [`v1::app::form::get_form_layout`], [`v1::app::form::update_form_layout`], [`v1::app::notification::get_general_notifications`], [`v1::app::notification::update_general_notifications`], [`v1::app::notification::get_per_record_notifications`], [`v1::app::notification::update_per_record_notifications`], [`v1::app::notification::get_reminder_notifications`], [`v1::app::notification::update_reminder_notifications`]
 //!
 //! ### Builder Pattern and Method Chaining
 //!
@@ -37,23 +37,37 @@
 //!
 //! ## Built-in Middleware
 //!
-//! - [`RetryLayer`] - Automatically retries failed requests with exponential backoff
+//! - [`RetryLayer`] - Automatically retries failed requests with a configurable [`BackoffStrategy`]
 //! - [`LoggingLayer`] - Logs request and response information for debugging
 //! - [`BasicAuthLayer`] - Adds HTTP Basic authentication headers to requests
+//! - [`CacheLayer`] - Caches GET responses and revalidates them with ETags
+//! - [`TapLayer`] - Passes the raw request and response bodies to a callback, for auditing
+//!
+//! ## Transports
+//!
+//! - `ReqwestHandler` - Sends requests with reqwest instead of ureq; requires the `reqwest`
+//!   feature and is installed with
+//!   [`KintoneClientBuilder::with_handler`](crate::client::KintoneClientBuilder::with_handler)
+//!
+//! ## Testing
+//!
+//! - [`MockHandler`] - Serves canned responses and records requests, for use with
+//!   [`KintoneClientBuilder::with_handler`](crate::client::KintoneClientBuilder::with_handler)
 
 use std::{
-    borrow::Borrow,
-    io::{Cursor, Read},
-    sync::Arc,
+    collections::HashMap,
+    io::{BufReader, Cursor, Read},
+    sync::{Arc, Mutex},
+    time::Instant,
 };
 
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as BASE64;
 use http::Request;
-use log::info;
+use log::{debug, info};
 use serde::de::DeserializeOwned;
 
-use crate::error::ApiError;
+use crate::error::{ApiError, KintoneErrorCode, TransportError, TransportErrorKind};
 
 /// Represents the body of an HTTP request in the middleware system.
 ///
@@ -95,6 +109,15 @@ impl RequestBody {
         RequestBody(RequestBodyInner::Reader(Box::new(reader)))
     }
 
+    /// Returns the body as bytes, or `None` if it is a streaming body.
+    pub(crate) fn as_bytes(&self) -> Option<&[u8]> {
+        match &self.0 {
+            RequestBodyInner::Void => Some(&[]),
+            RequestBodyInner::Bytes(p) => Some(p),
+            RequestBodyInner::Reader(_) => None,
+        }
+    }
+
     pub fn try_clone(&self) -> Option<Self> {
         match &self.0 {
             RequestBodyInner::Void => Some(RequestBody(RequestBodyInner::Void)),
@@ -141,22 +164,122 @@ enum RequestBodyInner {
 pub struct ResponseBody(ureq::Body);
 
 impl ResponseBody {
-    const MAX_JSON_SIZE: u64 = 10 * 1024 * 1024;
+    pub(crate) const MAX_JSON_SIZE: u64 = 10 * 1024 * 1024;
 
     pub(crate) fn from_ureq_body(body: ureq::Body) -> Self {
         ResponseBody(body)
     }
 
+    /// Creates a response body from bytes held in memory.
+    ///
+    /// This is useful for middleware that produces responses without contacting the server,
+    /// such as [`CacheLayer`].
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        ResponseBody(ureq::Body::builder().data(bytes))
+    }
+
+    /// Creates a response body that streams from `reader`.
+    ///
+    /// This is meant for handlers that replace the HTTP transport, such as one built on
+    /// another HTTP client (see [`KintoneClientBuilder::with_handler`]). Pass the response's
+    /// MIME type, e.g. `"application/json"`, if it is known, so that non-JSON responses are
+    /// reported as [`ApiError::UnexpectedContentType`] as they are with the default transport.
+    ///
+    /// [`KintoneClientBuilder::with_handler`]: crate::client::KintoneClientBuilder::with_handler
+    pub fn from_reader(reader: impl Read + Send + Sync + 'static, mime_type: Option<&str>) -> Self {
+        let mut builder = ureq::Body::builder();
+        if let Some(mime_type) = mime_type {
+            builder = builder.mime_type(mime_type);
+        }
+        ResponseBody(builder.reader(reader))
+    }
+
     pub fn into_reader(self) -> impl Read + 'static {
         self.0.into_reader()
     }
 
+    pub(crate) fn into_ureq_body(self) -> ureq::Body {
+        self.0
+    }
+
+    /// Deserializes the body as JSON.
+    ///
+    /// The body is parsed directly from the response stream instead of being buffered
+    /// into memory first, which keeps peak memory low for large responses such as
+    /// cursor pages of 500 records.
     pub fn read_json<D: DeserializeOwned>(&mut self) -> Result<D, ApiError> {
-        let body = self.0.with_config().limit(Self::MAX_JSON_SIZE).read_to_vec()?;
-        serde_json::from_slice(&body).map_err(|e| e.into())
+        self.read_json_with_limit(Self::MAX_JSON_SIZE)
+    }
+
+    /// Deserializes the body as JSON, reading at most `limit` bytes.
+    ///
+    /// Returns [`ApiError::ResponseTooLarge`] if the body is longer than `limit`, and
+    /// [`ApiError::UnexpectedContentType`] if the response declares a non-JSON content type.
+    pub fn read_json_with_limit<D: DeserializeOwned>(&mut self, limit: u64) -> Result<D, ApiError> {
+        if let Some(content_type) = self.0.mime_type()
+            && !is_json_mime_type(content_type)
+        {
+            let content_type = content_type.to_owned();
+            return Err(self.unexpected_content_type(content_type));
+        }
+        let reader = self.0.with_config().limit(limit).reader();
+        serde_json::from_reader(BufReader::new(reader)).map_err(|e| {
+            if !e.is_io() {
+                return ApiError::Json(e);
+            }
+            body_read_error(std::io::Error::from(e), limit)
+        })
+    }
+
+    /// Returns a reader over the body after checking that it declares a JSON content type.
+    ///
+    /// Unlike [`read_json_with_limit`](Self::read_json_with_limit), no size limit is applied,
+    /// since the caller consumes the body incrementally.
+    pub(crate) fn into_json_reader(mut self) -> Result<impl Read + Send + 'static, ApiError> {
+        if let Some(content_type) = self.0.mime_type()
+            && !is_json_mime_type(content_type)
+        {
+            let content_type = content_type.to_owned();
+            return Err(self.unexpected_content_type(content_type));
+        }
+        Ok(self.0.into_reader())
+    }
+
+    fn unexpected_content_type(&mut self, content_type: String) -> ApiError {
+        const SNIPPET_SIZE: u64 = 256;
+
+        let mut buf = Vec::new();
+        let reader = self.0.with_config().limit(SNIPPET_SIZE).reader();
+        // Reading the snippet is best-effort; the content type alone is enough to report.
+        let _ = reader.take(SNIPPET_SIZE).read_to_end(&mut buf);
+        ApiError::UnexpectedContentType {
+            content_type,
+            snippet: String::from_utf8_lossy(&buf).trim().to_owned(),
+        }
     }
 }
 
+/// Converts an error from reading a response body, read with at most `limit` bytes, to an
+/// [`ApiError`].
+pub(crate) fn body_read_error(e: std::io::Error, limit: u64) -> ApiError {
+    let is_ureq_error = e.get_ref().is_some_and(|inner| inner.is::<ureq::Error>());
+    if !is_ureq_error {
+        return TransportError::new(TransportErrorKind::Io, e).into();
+    }
+    match e.into_inner().map(|inner| inner.downcast::<ureq::Error>()) {
+        Some(Ok(inner)) => match *inner {
+            ureq::Error::BodyExceedsLimit(_) => ApiError::ResponseTooLarge { limit },
+            inner => ApiError::from(inner),
+        },
+        _ => unreachable!("checked to be a ureq::Error above"),
+    }
+}
+
+fn is_json_mime_type(mime_type: &str) -> bool {
+    let mime_type = mime_type.trim().to_ascii_lowercase();
+    mime_type == "application/json" || mime_type.ends_with("+json")
+}
+
 //-----------------------------------------------------------------------------
 
 /// Core trait for handling HTTP requests in the middleware system.
@@ -260,6 +383,104 @@ pub type ShouldRetryFn = dyn Fn(&http::Request<()>, Result<&http::Response<Respo
     + Sync
     + 'static;
 
+/// Type alias for a function that is called before each retry.
+///
+/// This function receives the number of the attempt that just failed (starting at 1),
+/// the response (or `ApiError`) of that attempt, and the delay before the next attempt.
+/// It is useful for emitting metrics or custom logs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kintone::middleware::OnRetryFn;
+/// let on_retry: Box<OnRetryFn> = Box::new(|attempt, resp_or_err, next_delay| {
+///     match resp_or_err {
+///         Ok(resp) => eprintln!("attempt {attempt} returned {}, retrying in {next_delay:?}", resp.status()),
+///         Err(err) => eprintln!("attempt {attempt} failed: {err}, retrying in {next_delay:?}"),
+///     }
+/// });
+/// ```
+pub type OnRetryFn = dyn Fn(usize, Result<&http::Response<ResponseBody>, &ApiError>, std::time::Duration)
+    + Send
+    + Sync
+    + 'static;
+
+/// Computes the delay to wait before retrying a request in [`RetryLayer`].
+///
+/// A fresh copy of the strategy is used for every request sent through the layer, so
+/// implementations may keep per-request state in `self`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use kintone::middleware::{BackoffStrategy, RetryLayer};
+///
+/// // Waits 1s, 2s, 3s, ...
+/// #[derive(Clone)]
+/// struct Linear;
+///
+/// impl BackoffStrategy for Linear {
+///     fn next_delay(&mut self, attempt: usize) -> Duration {
+///         Duration::from_secs(attempt as u64)
+///     }
+/// }
+///
+/// let retry_layer = RetryLayer::new().with_backoff(Linear);
+/// ```
+pub trait BackoffStrategy {
+    /// Returns how long to wait after the `attempt`-th attempt (starting from 1) has failed.
+    fn next_delay(&mut self, attempt: usize) -> std::time::Duration;
+}
+
+/// Waits `initial`, `2 * initial`, `4 * initial`, ..., up to `max`.
+///
+/// This is the default strategy of [`RetryLayer`].
+#[derive(Debug, Clone)]
+pub struct ExponentialBackoff {
+    pub initial: std::time::Duration,
+    pub max: std::time::Duration,
+}
+
+impl BackoffStrategy for ExponentialBackoff {
+    fn next_delay(&mut self, attempt: usize) -> std::time::Duration {
+        let shift = u32::try_from(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        let factor = 1u32.checked_shl(shift).unwrap_or(u32::MAX);
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// Waits the same amount of time before every retry.
+#[derive(Debug, Clone)]
+pub struct FixedBackoff(pub std::time::Duration);
+
+impl BackoffStrategy for FixedBackoff {
+    fn next_delay(&mut self, _attempt: usize) -> std::time::Duration {
+        self.0
+    }
+}
+
+/// Waits `unit` multiplied by the Fibonacci sequence (1, 1, 2, 3, 5, ...), up to `max`.
+///
+/// The delay grows more slowly than with [`ExponentialBackoff`].
+#[derive(Debug, Clone)]
+pub struct FibonacciBackoff {
+    pub unit: std::time::Duration,
+    pub max: std::time::Duration,
+}
+
+impl BackoffStrategy for FibonacciBackoff {
+    fn next_delay(&mut self, attempt: usize) -> std::time::Duration {
+        let (mut a, mut b) = (0u32, 1u32);
+        for _ in 0..attempt {
+            (a, b) = (b, a.saturating_add(b));
+        }
+        self.unit.saturating_mul(a).min(self.max)
+    }
+}
+
+type BackoffFactory = dyn Fn() -> Box<dyn BackoffStrategy> + Send + Sync + 'static;
+
 /// Middleware layer that automatically retries failed requests with exponential backoff.
 ///
 /// This layer is particularly useful for handling transient errors like database locks
@@ -271,7 +492,13 @@ pub type ShouldRetryFn = dyn Fn(&http::Request<()>, Result<&http::Response<Respo
 /// - Requests are retried up to `max_attempts` times
 /// - Delay between retries starts at `initial_delay` and doubles after each attempt
 /// - Delay is capped at `max_delay` to prevent excessively long waits
+/// - A different [`BackoffStrategy`] can be set with [`RetryLayer::with_backoff`]
 /// - Only requests with cloneable bodies can be retried (streaming requests are not retried)
+/// - If the last attempt still fails with a retryable error, the error is wrapped in
+///   [`ApiError::Retried`]; errors that are not retried are returned as is
+/// - By default, error responses from Kintone are retried unless retrying cannot help:
+///   malformed requests ([`IllegalRequest`](crate::error::KintoneErrorCode::IllegalRequest)),
+///   revision conflicts, and authentication or permission errors are never retried
 ///
 /// # Examples
 ///
@@ -284,34 +511,45 @@ pub type ShouldRetryFn = dyn Fn(&http::Request<()>, Result<&http::Response<Respo
 ///     .with_max_attempts(5)
 ///     .with_initial_delay(Duration::from_millis(500))
 ///     .with_max_delay(Duration::from_secs(30));
+///
+/// // Retry every 2 seconds
+/// use kintone::middleware::FixedBackoff;
+/// let retry_layer = RetryLayer::new().with_backoff(FixedBackoff(Duration::from_secs(2)));
 /// ```
 /// RetryLayer controls automatic retry logic for failed requests.
 ///
 /// Use builder-style methods to configure retry policy.
+///
+/// Cloning a `RetryLayer` is cheap. The clones share the functions given to
+/// [`with_should_retry`](Self::with_should_retry) and [`with_on_retry`](Self::with_on_retry)
+/// rather than copying them, so any state those functions capture is shared as well.
+#[derive(Clone)]
 pub struct RetryLayer {
     max_attempts: usize,
     initial_delay: std::time::Duration,
     max_delay: std::time::Duration,
-    should_retry: Box<ShouldRetryFn>,
+    backoff: Option<Arc<BackoffFactory>>,
+    should_retry: Arc<ShouldRetryFn>,
+    on_retry: Option<Arc<OnRetryFn>>,
 }
 
 impl RetryLayer {
-    const NONRETRYABLE_CODES: &[&str] = &[
-        "CB_IL02", // "不正なリクエストです。"
-    ];
+    const NONRETRYABLE_CODES: &[KintoneErrorCode] = &[KintoneErrorCode::IllegalRequest];
 
     pub const DEFAULT_MAX_ATTEMPTS: usize = 5;
     pub const DEFAULT_INITIAL_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
     pub const DEFAULT_MAX_DELAY: std::time::Duration = std::time::Duration::from_secs(8);
     pub const DEFAULT_SHOULD_RETRY_FN: &ShouldRetryFn = &|_, resp_or_err| match resp_or_err {
         Ok(resp) => !resp.status().is_success(),
-        Err(err) => {
-            if let ApiError::Kintone(kintone_err) = err {
-                !Self::NONRETRYABLE_CODES.contains(&kintone_err.code.borrow())
-            } else {
-                true
+        Err(err) => match err {
+            ApiError::Kintone(kintone_err) => {
+                !Self::NONRETRYABLE_CODES.contains(&kintone_err.error_code())
             }
-        }
+            ApiError::RevisionConflict(_)
+            | ApiError::Unauthorized { .. }
+            | ApiError::Forbidden { .. } => false,
+            _ => true,
+        },
     };
 
     /// Creates a new RetryLayer with default settings.
@@ -320,7 +558,9 @@ impl RetryLayer {
             max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
             initial_delay: Self::DEFAULT_INITIAL_DELAY,
             max_delay: Self::DEFAULT_MAX_DELAY,
-            should_retry: Box::new(Self::DEFAULT_SHOULD_RETRY_FN),
+            backoff: None,
+            should_retry: Arc::new(Self::DEFAULT_SHOULD_RETRY_FN),
+            on_retry: None,
         }
     }
 
@@ -347,9 +587,37 @@ impl RetryLayer {
         self
     }
 
+    /// Sets the strategy that decides the delay between retries.
+    ///
+    /// This replaces the default exponential backoff, so `initial_delay` and `max_delay`
+    /// are no longer used.
+    pub fn with_backoff<B>(mut self, backoff: B) -> Self
+    where
+        B: BackoffStrategy + Clone + Send + Sync + 'static,
+    {
+        self.backoff = Some(Arc::new(move || Box::new(backoff.clone())));
+        self
+    }
+
+    fn new_backoff(&self) -> Box<dyn BackoffStrategy> {
+        match &self.backoff {
+            Some(factory) => factory(),
+            None => Box::new(ExponentialBackoff {
+                initial: self.initial_delay,
+                max: self.max_delay,
+            }),
+        }
+    }
+
     /// Sets the retry decision function.
     pub fn with_should_retry(mut self, should_retry: Box<ShouldRetryFn>) -> Self {
-        self.should_retry = should_retry;
+        self.should_retry = Arc::from(should_retry);
+        self
+    }
+
+    /// Sets a callback that is invoked before each retry.
+    pub fn with_on_retry(mut self, on_retry: Box<OnRetryFn>) -> Self {
+        self.on_retry = Some(Arc::from(on_retry));
         self
     }
 }
@@ -391,7 +659,7 @@ impl<Inner: Handler> Handler for RetryHandler<Inner> {
         let (parts, body) = req.into_parts();
 
         let mut attempts = 1;
-        let mut delay = self.layer.initial_delay;
+        let mut backoff = self.layer.new_backoff();
 
         loop {
             let Some(body_cloned) = body.try_clone() else {
@@ -412,23 +680,35 @@ impl<Inner: Handler> Handler for RetryHandler<Inner> {
                     if !retry_ok {
                         return Ok(resp);
                     }
-                    // do retry
+                    let delay = backoff.next_delay(attempts);
+                    if let Some(on_retry) = &self.layer.on_retry {
+                        on_retry(attempts, Ok(&resp), delay);
+                    }
+                    std::thread::sleep(delay);
                 }
                 Err(e) => {
-                    if attempts >= self.layer.max_attempts {
-                        return Err(e);
-                    }
                     let req_nobody = http::Request::from_parts(parts.clone(), ());
                     let retry_ok = (self.layer.should_retry)(&req_nobody, Err(&e));
                     if !retry_ok {
                         return Err(e);
                     }
-                    // do retry
+                    if attempts >= self.layer.max_attempts {
+                        if attempts == 1 {
+                            return Err(e);
+                        }
+                        return Err(ApiError::Retried {
+                            attempts,
+                            last: Box::new(e),
+                        });
+                    }
+                    let delay = backoff.next_delay(attempts);
+                    if let Some(on_retry) = &self.layer.on_retry {
+                        on_retry(attempts, Err(&e), delay);
+                    }
+                    std::thread::sleep(delay);
                 }
             }
 
-            std::thread::sleep(delay);
-            delay = std::cmp::min(delay * 2, self.layer.max_delay);
             attempts += 1;
         }
     }
@@ -442,14 +722,19 @@ impl<Inner: Handler> Handler for RetryHandler<Inner> {
 /// HTTP request and response.
 ///
 /// - Uses the [`log`](https://docs.rs/log/latest/log/) crate for logging output.
-/// - All logs are emitted at the `info` level.
-/// - You can use any logger compatible with the `log` crate (e.g., `env_logger`, `tracing`, etc.).
+/// - You can use any logger compatible with the `log` crate (e.g., `env_logger`, `tracing`, etc.),
+///   and control the verbosity through its configuration.
 ///
 /// # Logged Information
 ///
-/// - Request: HTTP method and URL
-/// - Request body (if available)
-/// - Response: HTTP status code or error details
+/// - At `debug` level, before the request is sent: HTTP method and URL, and the request body
+///   (if available)
+/// - At `info` level, once the call has finished: HTTP method, URL, and the HTTP status code
+///   or error details, with the time the call took
+///
+/// Besides the message, each record carries structured key-values: `method` and `url`, plus
+/// `status` and `duration_ms` or `error` for the finished call. Loggers with key-value support
+/// (such as `env_logger` with its `kv` feature, or the `tracing-log` bridge) can pick them up.
 ///
 /// # Examples
 ///
@@ -459,6 +744,7 @@ impl<Inner: Handler> Handler for RetryHandler<Inner> {
 /// env_logger::init();
 /// let logging_layer = LoggingLayer::new();
 /// ```
+#[derive(Clone)]
 pub struct LoggingLayer {
     log_target: String,
     enabled: bool,
@@ -545,19 +831,36 @@ impl<Inner: Handler> Handler for LoggingHandler<Inner> {
             return self.inner.handle(req);
         }
 
-        info!(target: &self.log_target, "Request: method={}, url={:?}", req.method(), req.uri());
-        if let Some(body) = req.body().try_clone() {
+        let target = self.log_target.as_str();
+        let method = req.method().clone();
+        let url = req.uri().clone();
+        debug!(target: target, method = method.as_str(), url:% = url; "Request: method={method}, url={url:?}");
+        if log::log_enabled!(target: target, log::Level::Debug)
+            && let Some(body) = req.body().try_clone()
+        {
             let mut buf = String::new();
-            if body.into_reader().read_to_string(&mut buf).is_ok() {
-                info!(target: &self.log_target, "Request body:\n{buf}");
+            if body.into_reader().read_to_string(&mut buf).is_ok() && !buf.is_empty() {
+                debug!(target: target, method = method.as_str(), url:% = url; "Request body:\n{buf}");
             }
         }
+
+        let start = Instant::now();
         let result = self.inner.handle(req);
+        let duration_ms = start.elapsed().as_millis() as u64;
         match &result {
             Ok(resp) => {
-                info!(target: &self.log_target, "Response: status={}", resp.status().as_u16());
+                let status = resp.status().as_u16();
+                info!(
+                    target: target,
+                    method = method.as_str(), url:% = url, status, duration_ms;
+                    "Response: method={method}, url={url:?}, status={status}, duration={duration_ms}ms"
+                );
             }
-            Err(e) => info!(target: &self.log_target, "Response: error={e}"),
+            Err(e) => info!(
+                target: target,
+                method = method.as_str(), url:% = url, error:% = e, duration_ms;
+                "Response: method={method}, url={url:?}, error={e}, duration={duration_ms}ms"
+            ),
         }
         result
     }
@@ -565,6 +868,156 @@ impl<Inner: Handler> Handler for LoggingHandler<Inner> {
 
 //-----------------------------------------------------------------------------
 
+/// Type alias for the callback of a [`TapLayer`].
+///
+/// The callback receives the request and the response (or `ApiError`) of every call that
+/// passes through the layer. The request body is `None` when it is a streaming body, such as
+/// a file upload, which cannot be read without consuming it. The response body is `None`
+/// unless buffering was enabled with [`TapLayer::with_response_bodies`].
+///
+/// Error responses from Kintone reach the callback as [`ApiError`]s, which carry the error
+/// body themselves.
+pub type TapFn = dyn Fn(&http::Request<Option<&[u8]>>, Result<&http::Response<Option<&[u8]>>, &ApiError>)
+    + Send
+    + Sync
+    + 'static;
+
+/// Middleware layer that passes the raw bytes of each request and response to a callback.
+///
+/// This is meant for audit and compliance logging, where exactly what was exchanged with
+/// Kintone has to be recorded. Unlike [`LoggingLayer`], nothing is formatted or written by
+/// the layer itself.
+///
+/// # Memory Usage
+///
+/// Response bodies are streamed by default, so passing them to the callback requires
+/// buffering each one completely in memory. This is why response bodies are opt-in. Only
+/// JSON responses are buffered, up to the default response size limit of the client; the
+/// bodies of file downloads and other non-JSON responses are passed on unread, and the
+/// callback receives `None` for them. If a JSON body cannot be read, the callback receives
+/// the error instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::client::{Auth, KintoneClient};
+/// use kintone::middleware::TapLayer;
+///
+/// let audit = TapLayer::new(|req, resp_or_err| {
+///     let sent = req.body().map(String::from_utf8_lossy);
+///     match resp_or_err {
+///         Ok(resp) => eprintln!("{} {} {sent:?} -> {}", req.method(), req.uri(), resp.status()),
+///         Err(err) => eprintln!("{} {} {sent:?} -> {err}", req.method(), req.uri()),
+///     }
+/// })
+/// .with_response_bodies(true);
+///
+/// let client = KintoneClient::builder(
+///         "https://your-domain.cybozu.com",
+///         Auth::api_token("your-api-token".to_owned())
+///     )
+///     .layer(audit)
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct TapLayer {
+    tap: Arc<TapFn>,
+    response_bodies: bool,
+}
+
+impl TapLayer {
+    /// Creates a new TapLayer that calls `tap` for every request.
+    pub fn new(
+        tap: impl Fn(&http::Request<Option<&[u8]>>, Result<&http::Response<Option<&[u8]>>, &ApiError>)
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        TapLayer {
+            tap: Arc::new(tap),
+            response_bodies: false,
+        }
+    }
+
+    /// Enables or disables buffering response bodies to pass them to the callback.
+    /// (builder style)
+    ///
+    /// Disabled by default.
+    pub fn with_response_bodies(mut self, enabled: bool) -> Self {
+        self.response_bodies = enabled;
+        self
+    }
+}
+
+impl<Inner: Handler> Layer<Inner> for TapLayer {
+    type Outer = TapHandler<Inner>;
+    fn layer(self, inner: Inner) -> Self::Outer {
+        TapHandler { inner, layer: self }
+    }
+}
+
+/// Handler implementation that wraps another handler with a [`TapLayer`] callback.
+///
+/// This is an internal implementation detail and should not be used directly.
+pub struct TapHandler<Inner> {
+    inner: Inner,
+    layer: TapLayer,
+}
+
+impl<Inner: Handler> Handler for TapHandler<Inner> {
+    fn handle(
+        &self,
+        req: http::Request<RequestBody>,
+    ) -> Result<http::Response<ResponseBody>, ApiError> {
+        let (parts, body) = req.into_parts();
+        let tapped_parts = parts.clone();
+        let sent = body.try_clone();
+        let result = self.inner.handle(http::Request::from_parts(parts, body));
+
+        let sent_bytes = sent.as_ref().and_then(RequestBody::as_bytes);
+        let tapped_req = http::Request::from_parts(tapped_parts, sent_bytes);
+        match result {
+            Ok(resp)
+                if self.layer.response_bodies
+                    && resp.body().0.mime_type().is_none_or(is_json_mime_type) =>
+            {
+                let (parts, body) = resp.into_parts();
+                let mime_type = body.0.mime_type().map(str::to_owned);
+                let limit = ResponseBody::MAX_JSON_SIZE;
+                let received = match body.0.into_with_config().limit(limit).read_to_vec() {
+                    Ok(received) => received,
+                    Err(e) => {
+                        let e = ApiError::from(e);
+                        (self.layer.tap)(&tapped_req, Err(&e));
+                        return Err(e);
+                    }
+                };
+                let tapped_resp = http::Response::from_parts(parts, Some(received.as_slice()));
+                (self.layer.tap)(&tapped_req, Ok(&tapped_resp));
+
+                let (parts, _) = tapped_resp.into_parts();
+                let mut body = ureq::Body::builder();
+                if let Some(mime_type) = mime_type {
+                    body = body.mime_type(mime_type);
+                }
+                let body = ResponseBody(body.data(received));
+                Ok(http::Response::from_parts(parts, body))
+            }
+            Ok(resp) => {
+                let (parts, body) = resp.into_parts();
+                let tapped_resp = http::Response::from_parts(parts, None);
+                (self.layer.tap)(&tapped_req, Ok(&tapped_resp));
+                let (parts, _) = tapped_resp.into_parts();
+                Ok(http::Response::from_parts(parts, body))
+            }
+            Err(e) => {
+                (self.layer.tap)(&tapped_req, Err(&e));
+                Err(e)
+            }
+        }
+    }
+}
+
 /// Middleware layer that adds HTTP Basic authentication headers to requests.
 ///
 /// This layer automatically adds the `Authorization` header with Basic authentication
@@ -601,6 +1054,7 @@ impl<Inner: Handler> Handler for LoggingHandler<Inner> {
 ///     .layer(middleware::LoggingLayer::new())
 ///     .build();
 /// ```
+#[derive(Clone)]
 pub struct BasicAuthLayer {
     credentials: Option<(String, String)>,
 }
@@ -708,6 +1162,488 @@ impl<Inner: Handler> Handler for BasicAuthHandler<Inner> {
 
 //-----------------------------------------------------------------------------
 
+/// Middleware layer that caches GET responses and revalidates them with ETags.
+///
+/// When a cached response exists for a URL, the request is sent with an `If-None-Match`
+/// header. If the server answers `304 Not Modified`, the cached body is returned instead,
+/// which saves transferring and parsing large settings such as form fields or views.
+///
+/// Caching is opt-in: only requests whose path matches one of the patterns registered with
+/// [`CacheLayer::with_path`] are cached. Responses without an `ETag` header are never cached,
+/// since they cannot be revalidated.
+///
+/// # Memory Usage
+///
+/// Response bodies are streamed by default, so caching requires buffering the whole body in
+/// memory. The cache keeps at most `capacity` responses and evicts the least recently used one
+/// when it is full.
+///
+/// The cache is keyed by URL only. Do not share one client between users who may see different
+/// data for the same URL.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::client::{Auth, KintoneClient};
+/// use kintone::middleware::CacheLayer;
+///
+/// let client = KintoneClient::builder(
+///         "https://your-domain.cybozu.com",
+///         Auth::api_token("your-api-token".to_owned())
+///     )
+///     .layer(CacheLayer::new(100)
+///         .with_path("*/v1/app/form/fields.json")
+///         .with_path("*/v1/app/views.json")
+///     )
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct CacheLayer {
+    capacity: usize,
+    patterns: Vec<String>,
+}
+
+impl CacheLayer {
+    /// Creates a new CacheLayer that holds at most `capacity` responses.
+    ///
+    /// No paths are cached until patterns are added with [`CacheLayer::with_path`].
+    pub fn new(capacity: usize) -> Self {
+        CacheLayer {
+            capacity,
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Enables caching for request paths that match the given pattern. (builder style)
+    ///
+    /// The pattern is matched against the whole path of the request URL, excluding the query
+    /// string. `*` matches any sequence of characters, including `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::middleware::CacheLayer;
+    ///
+    /// // Cache all app settings, in guest spaces too
+    /// let cache_layer = CacheLayer::new(100).with_path("*/v1/app/*");
+    /// ```
+    pub fn with_path(mut self, pattern: impl Into<String>) -> Self {
+        self.patterns.push(pattern.into());
+        self
+    }
+}
+
+impl<Inner: Handler> Layer<Inner> for CacheLayer {
+    type Outer = CacheHandler<Inner>;
+    fn layer(self, inner: Inner) -> Self::Outer {
+        CacheHandler {
+            inner,
+            capacity: self.capacity,
+            patterns: self.patterns,
+            cache: Mutex::new(Cache::default()),
+        }
+    }
+}
+
+/// Handler implementation that wraps another handler with response caching.
+///
+/// This handler implements the actual caching behavior for the [`CacheLayer`].
+///
+/// This is an internal implementation detail and should not be used directly.
+pub struct CacheHandler<Inner> {
+    inner: Inner,
+    capacity: usize,
+    patterns: Vec<String>,
+    cache: Mutex<Cache>,
+}
+
+#[derive(Default)]
+struct Cache {
+    entries: HashMap<String, CacheEntry>,
+    clock: u64,
+}
+
+struct CacheEntry {
+    etag: http::HeaderValue,
+    headers: http::HeaderMap,
+    body: Arc<[u8]>,
+    last_used: u64,
+}
+
+impl Cache {
+    fn get(&mut self, key: &str) -> Option<&CacheEntry> {
+        self.clock += 1;
+        let entry = self.entries.get_mut(key)?;
+        entry.last_used = self.clock;
+        Some(entry)
+    }
+
+    fn insert(&mut self, key: String, mut entry: CacheEntry, capacity: usize) {
+        self.clock += 1;
+        entry.last_used = self.clock;
+        if !self.entries.contains_key(&key) && self.entries.len() >= capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, entry);
+    }
+}
+
+impl<Inner> CacheHandler<Inner> {
+    fn is_cacheable(&self, req: &http::Request<RequestBody>) -> bool {
+        self.capacity > 0
+            && req.method() == http::Method::GET
+            && !req.headers().contains_key(http::header::IF_NONE_MATCH)
+            && self.patterns.iter().any(|p| glob_match(p, req.uri().path()))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Cache> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<Inner: Handler> Handler for CacheHandler<Inner> {
+    fn handle(
+        &self,
+        mut req: http::Request<RequestBody>,
+    ) -> Result<http::Response<ResponseBody>, ApiError> {
+        if !self.is_cacheable(&req) {
+            return self.inner.handle(req);
+        }
+
+        let key = req.uri().to_string();
+        let cached_etag = self.lock().get(&key).map(|entry| entry.etag.clone());
+        if let Some(etag) = &cached_etag {
+            req.headers_mut().insert(http::header::IF_NONE_MATCH, etag.clone());
+        }
+
+        let resp = self.inner.handle(req)?;
+
+        if resp.status() == http::StatusCode::NOT_MODIFIED && cached_etag.is_some() {
+            if let Some(entry) = self.lock().get(&key) {
+                let mut cached = http::Response::new(ResponseBody::from_bytes(entry.body.to_vec()));
+                *cached.headers_mut() = entry.headers.clone();
+                return Ok(cached);
+            }
+            return Ok(resp);
+        }
+
+        let Some(etag) = resp.headers().get(http::header::ETAG).cloned() else {
+            return Ok(resp);
+        };
+        if resp.status() != http::StatusCode::OK {
+            return Ok(resp);
+        }
+
+        let (parts, body) = resp.into_parts();
+        let body: Arc<[u8]> = body
+            .0
+            .into_with_config()
+            .limit(ResponseBody::MAX_JSON_SIZE)
+            .read_to_vec()?
+            .into();
+        let entry = CacheEntry {
+            etag,
+            headers: parts.headers.clone(),
+            body: Arc::clone(&body),
+            last_used: 0,
+        };
+        self.lock().insert(key, entry, self.capacity);
+        Ok(http::Response::from_parts(parts, ResponseBody::from_bytes(body.to_vec())))
+    }
+}
+
+/// Matches `text` against a pattern where `*` matches any sequence of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or("");
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No `*` in the pattern
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+//-----------------------------------------------------------------------------
+
+/// A handler that serves canned responses instead of contacting Kintone.
+///
+/// `MockHandler` lets you unit-test code that uses [`KintoneClient`] without a real
+/// Kintone environment. Register responses per method and path, inject the handler with
+/// [`KintoneClientBuilder::with_handler`], and inspect the captured requests afterwards.
+///
+/// Clones of a `MockHandler` share their responses and captured requests, so keep a clone
+/// to make assertions after passing one to the client builder.
+///
+/// - The path is matched against the request path without the query string
+///   (e.g. `/k/v1/record.json`).
+/// - If several responses are registered for the same method and path, they are returned
+///   in order, and the last one is repeated.
+/// - Requests without a registered response get a `404 Not Found` response, which results
+///   in an [`ApiError::Http`].
+///
+/// [`KintoneClient`]: crate::client::KintoneClient
+/// [`KintoneClientBuilder::with_handler`]: crate::client::KintoneClientBuilder::with_handler
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::client::{Auth, KintoneClient};
+/// use kintone::middleware::MockHandler;
+///
+/// let mock = MockHandler::new()
+///     .with_response(http::Method::POST, "/k/v1/record.json", 200, r#"{"id":"10","revision":"1"}"#);
+/// let client = KintoneClient::builder(
+///         "https://your-domain.cybozu.com",
+///         Auth::api_token("your-api-token".to_owned())
+///     )
+///     .with_handler(mock.clone())
+///     .build();
+///
+/// let response = kintone::v1::record::add_record(1).send(&client)?;
+/// assert_eq!(response.id, 10);
+///
+/// let requests = mock.requests();
+/// assert_eq!(requests[0].method, http::Method::POST);
+/// assert_eq!(requests[0].body_json()["app"], 1);
+/// # Ok::<(), kintone::error::ApiError>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct MockHandler {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    routes: Vec<MockRoute>,
+    requests: Vec<CapturedRequest>,
+}
+
+struct MockRoute {
+    method: http::Method,
+    path: String,
+    responses: std::collections::VecDeque<(u16, Vec<u8>)>,
+}
+
+/// A request received by a [`MockHandler`].
+#[derive(Debug, Clone)]
+pub struct CapturedRequest {
+    pub method: http::Method,
+    /// The full request URL, including the query string
+    pub uri: String,
+    pub headers: http::HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl CapturedRequest {
+    /// Parses the request body as JSON.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body is not valid JSON.
+    pub fn body_json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("request body is not JSON")
+    }
+}
+
+impl MockHandler {
+    /// Creates a new MockHandler with no registered responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a JSON response for the given method and path. (builder style)
+    pub fn with_response(
+        self,
+        method: http::Method,
+        path: impl Into<String>,
+        status: u16,
+        body: impl Into<Vec<u8>>,
+    ) -> Self {
+        {
+            let mut state = self.lock();
+            let path = path.into();
+            let response = (status, body.into());
+            match state.routes.iter_mut().find(|r| r.method == method && r.path == path) {
+                Some(route) => route.responses.push_back(response),
+                None => state.routes.push(MockRoute {
+                    method,
+                    path,
+                    responses: [response].into(),
+                }),
+            }
+        }
+        self
+    }
+
+    /// Returns the requests received so far, in order.
+    pub fn requests(&self) -> Vec<CapturedRequest> {
+        self.lock().requests.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Handler for MockHandler {
+    fn handle(
+        &self,
+        req: http::Request<RequestBody>,
+    ) -> Result<http::Response<ResponseBody>, ApiError> {
+        let (parts, body) = req.into_parts();
+        let mut body_bytes = Vec::new();
+        body.into_reader().read_to_end(&mut body_bytes)?;
+
+        let mut state = self.lock();
+        state.requests.push(CapturedRequest {
+            method: parts.method.clone(),
+            uri: parts.uri.to_string(),
+            headers: parts.headers.clone(),
+            body: body_bytes,
+        });
+
+        let path = parts.uri.path();
+        let route = state.routes.iter_mut().find(|r| r.method == parts.method && r.path == path);
+        let Some(route) = route else {
+            let message = format!("no mock response for {} {path}", parts.method);
+            return Ok(http::Response::builder()
+                .status(404)
+                .header(http::header::CONTENT_TYPE, "text/plain")
+                .body(ResponseBody::from_bytes(message))?);
+        };
+        let (status, body) = if route.responses.len() > 1 {
+            route.responses.pop_front().unwrap()
+        } else {
+            route.responses[0].clone()
+        };
+        Ok(http::Response::builder()
+            .status(status)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(ResponseBody::from_bytes(body))?)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// A handler that sends requests with the blocking client of
+/// [`reqwest`](https://docs.rs/reqwest) instead of the built-in transport.
+///
+/// This is available with the `reqwest` feature, for applications that already use reqwest.
+/// Pass it to [`KintoneClientBuilder::with_handler`]. Authentication, middleware layers, and
+/// the conversion of error responses into [`ApiError`] work as with the built-in transport.
+/// The connection settings of the builder, such as timeouts, TLS certificates, and connection
+/// pool limits, do not apply; configure them on the `reqwest::blocking::Client` given to
+/// [`ReqwestHandler::from_client`] instead.
+///
+/// Like any use of reqwest's blocking client, the handler must not be created or used from
+/// within an async runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kintone::client::{Auth, KintoneClient};
+/// use kintone::middleware::ReqwestHandler;
+///
+/// let http = reqwest::blocking::Client::builder()
+///     .timeout(std::time::Duration::from_secs(30))
+///     .build()?;
+/// let client = KintoneClient::builder(
+///         "https://your-domain.cybozu.com",
+///         Auth::api_token("your-api-token".to_owned())
+///     )
+///     .with_handler(ReqwestHandler::from_client(http))
+///     .build();
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`KintoneClientBuilder::with_handler`]: crate::client::KintoneClientBuilder::with_handler
+#[cfg(feature = "reqwest")]
+#[derive(Clone, Default)]
+pub struct ReqwestHandler {
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestHandler {
+    /// Creates a handler with a reqwest client in its default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a handler that sends requests with `client`.
+    pub fn from_client(client: reqwest::blocking::Client) -> Self {
+        ReqwestHandler { client }
+    }
+
+    fn to_reqwest_request(
+        &self,
+        req: http::Request<RequestBody>,
+    ) -> Result<reqwest::blocking::Request, ApiError> {
+        let (parts, body) = req.into_parts();
+        let builder =
+            self.client.request(parts.method, parts.uri.to_string()).headers(parts.headers);
+        let builder = match body.0 {
+            RequestBodyInner::Void => builder,
+            RequestBodyInner::Bytes(bytes) => builder.body(bytes.to_vec()),
+            RequestBodyInner::Reader(reader) => builder.body(reqwest::blocking::Body::new(reader)),
+        };
+        builder.build().map_err(reqwest_transport_error)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Handler for ReqwestHandler {
+    fn handle(
+        &self,
+        req: http::Request<RequestBody>,
+    ) -> Result<http::Response<ResponseBody>, ApiError> {
+        let req = self.to_reqwest_request(req)?;
+        let resp = self.client.execute(req).map_err(reqwest_transport_error)?;
+        // ResponseBody expects a bare MIME type, without parameters such as the charset
+        let mime_type = resp
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(';').next().unwrap_or(v).trim().to_owned());
+        let mut builder = http::Response::builder().status(resp.status()).version(resp.version());
+        if let Some(headers) = builder.headers_mut() {
+            *headers = resp.headers().clone();
+        }
+        Ok(builder.body(ResponseBody::from_reader(resp, mime_type.as_deref()))?)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+fn reqwest_transport_error(err: reqwest::Error) -> ApiError {
+    let kind = if err.is_timeout() {
+        TransportErrorKind::Timeout
+    } else if err.is_connect() {
+        TransportErrorKind::ConnectionFailed
+    } else {
+        TransportErrorKind::Other
+    };
+    ApiError::Transport(TransportError::new(kind, err))
+}
+
+//-----------------------------------------------------------------------------
+
 /// A no-op middleware layer that provides no additional functionality.
 ///
 /// This layer is used as the base case in the middleware stack. When applied,
@@ -715,6 +1651,7 @@ impl<Inner: Handler> Handler for BasicAuthHandler<Inner> {
 /// by the [`KintoneClient`] as the starting point for building middleware stacks.
 ///
 /// [`KintoneClient`]: crate::client::KintoneClient
+#[derive(Clone)]
 pub struct NoLayer;
 
 impl<Inner: Handler> Layer<Inner> for NoLayer {
@@ -744,6 +1681,7 @@ impl<Inner: Handler> Layer<Inner> for NoLayer {
 /// // This creates a stack: LoggingLayer -> RetryLayer -> Handler
 /// let stack = Stack::new(LoggingLayer::new(), RetryLayer::new(...));
 /// ```
+#[derive(Clone)]
 pub struct Stack<Head, Tail>(Head, Tail);
 
 impl<Head, Tail> Stack<Head, Tail> {
@@ -763,3 +1701,403 @@ where
         self.0.layer(self.1.layer(inner))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A handler that serves a fixed body with an ETag and honors `If-None-Match`.
+    struct EtagServer {
+        etag: &'static str,
+        body: &'static str,
+        requests: Mutex<Vec<Option<String>>>,
+    }
+
+    impl Handler for Arc<EtagServer> {
+        fn handle(
+            &self,
+            req: http::Request<RequestBody>,
+        ) -> Result<http::Response<ResponseBody>, ApiError> {
+            let if_none_match = req
+                .headers()
+                .get(http::header::IF_NONE_MATCH)
+                .map(|v| v.to_str().unwrap().to_owned());
+            self.requests.lock().unwrap().push(if_none_match.clone());
+            let builder = http::Response::builder().header(http::header::ETAG, self.etag);
+            if if_none_match.as_deref() == Some(self.etag) {
+                Ok(builder.status(304).body(ResponseBody::from_bytes(Vec::new()))?)
+            } else {
+                Ok(builder.status(200).body(ResponseBody::from_bytes(self.body))?)
+            }
+        }
+    }
+
+    fn get(uri: &str) -> http::Request<RequestBody> {
+        http::Request::get(uri).body(RequestBody::void()).unwrap()
+    }
+
+    fn read_body(resp: http::Response<ResponseBody>) -> String {
+        let mut body = String::new();
+        resp.into_body().into_reader().read_to_string(&mut body).unwrap();
+        body
+    }
+
+    #[test]
+    fn cache_layer_revalidates_with_etag() {
+        let server = Arc::new(EtagServer {
+            etag: "\"v1\"",
+            body: r#"{"properties":{}}"#,
+            requests: Mutex::new(Vec::new()),
+        });
+        let handler = CacheLayer::new(10)
+            .with_path("*/v1/app/form/fields.json")
+            .layer(Arc::clone(&server));
+
+        let uri = "https://example.com/k/v1/app/form/fields.json?app=1";
+        let first = handler.handle(get(uri)).unwrap();
+        assert_eq!(read_body(first), r#"{"properties":{}}"#);
+        let second = handler.handle(get(uri)).unwrap();
+        assert_eq!(second.status(), 200);
+        assert_eq!(read_body(second), r#"{"properties":{}}"#);
+
+        // Paths that do not match any pattern are not cached
+        handler.handle(get("https://example.com/k/v1/records.json?app=1")).unwrap();
+        handler.handle(get("https://example.com/k/v1/records.json?app=1")).unwrap();
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(*requests, vec![None, Some("\"v1\"".to_owned()), None, None]);
+    }
+
+    #[test]
+    fn cache_evicts_least_recently_used() {
+        let entry = || CacheEntry {
+            etag: http::HeaderValue::from_static("\"x\""),
+            headers: http::HeaderMap::new(),
+            body: Arc::from(Vec::new()),
+            last_used: 0,
+        };
+        let mut cache = Cache::default();
+        cache.insert("a".to_owned(), entry(), 2);
+        cache.insert("b".to_owned(), entry(), 2);
+        assert!(cache.get("a").is_some());
+        cache.insert("c".to_owned(), entry(), 2);
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn retry_layer_calls_on_retry() {
+        struct Flaky {
+            failures: Mutex<usize>,
+        }
+        impl Handler for Flaky {
+            fn handle(
+                &self,
+                _req: http::Request<RequestBody>,
+            ) -> Result<http::Response<ResponseBody>, ApiError> {
+                let mut failures = self.failures.lock().unwrap();
+                let status = if *failures > 0 { 503 } else { 200 };
+                *failures = failures.saturating_sub(1);
+                Ok(http::Response::builder().status(status).body(ResponseBody::from_bytes("{}"))?)
+            }
+        }
+
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let observed_cloned = Arc::clone(&observed);
+        let handler = RetryLayer::new()
+            .with_initial_delay(std::time::Duration::ZERO)
+            .with_on_retry(Box::new(move |attempt, resp_or_err, _| {
+                let status = resp_or_err.unwrap().status().as_u16();
+                observed_cloned.lock().unwrap().push((attempt, status));
+            }))
+            .layer(Flaky {
+                failures: Mutex::new(2),
+            });
+
+        let resp = handler.handle(get("https://example.com/k/v1/record.json")).unwrap();
+        assert_eq!(resp.status(), 200);
+        assert_eq!(*observed.lock().unwrap(), vec![(1, 503), (2, 503)]);
+    }
+
+    #[test]
+    fn retry_layer_reports_exhaustion() {
+        struct Failing;
+        impl Handler for Failing {
+            fn handle(
+                &self,
+                _req: http::Request<RequestBody>,
+            ) -> Result<http::Response<ResponseBody>, ApiError> {
+                Err(ApiError::Http(crate::error::HttpError::new(503, "")))
+            }
+        }
+
+        let handler = RetryLayer::new()
+            .with_max_attempts(3)
+            .with_initial_delay(std::time::Duration::ZERO)
+            .layer(Failing);
+        let Err(err) = handler.handle(get("https://example.com/k/v1/record.json")) else {
+            panic!("expected an error");
+        };
+        assert!(matches!(err, ApiError::Retried { attempts: 3, .. }), "{err:?}");
+        assert!(matches!(err.final_error(), ApiError::Http(e) if e.status == 503));
+
+        // Errors that are not retried are returned as is
+        let handler = RetryLayer::new().with_should_retry(Box::new(|_, _| false)).layer(Failing);
+        let Err(err) = handler.handle(get("https://example.com/k/v1/record.json")) else {
+            panic!("expected an error");
+        };
+        assert!(matches!(err, ApiError::Http(_)), "{err:?}");
+    }
+
+    #[test]
+    fn retry_layer_returns_non_retryable_final_error_as_is() {
+        struct Sequence(Mutex<Vec<u16>>);
+        impl Handler for Sequence {
+            fn handle(
+                &self,
+                _req: http::Request<RequestBody>,
+            ) -> Result<http::Response<ResponseBody>, ApiError> {
+                let status = self.0.lock().unwrap().remove(0);
+                Err(ApiError::Kintone(crate::error::KintoneError {
+                    status,
+                    code: if status == 400 { "CB_IL02" } else { "CB_IJ01" }.to_owned(),
+                    id: String::new(),
+                    message: String::new(),
+                    errors: Default::default(),
+                }))
+            }
+        }
+
+        // A 503 followed by a malformed-request error, which is never retryable
+        let handler = RetryLayer::new()
+            .with_max_attempts(2)
+            .with_initial_delay(std::time::Duration::ZERO)
+            .layer(Sequence(Mutex::new(vec![503, 400])));
+        let Err(err) = handler.handle(get("https://example.com/k/v1/record.json")) else {
+            panic!("expected an error");
+        };
+        assert!(matches!(&err, ApiError::Kintone(e) if e.status == 400), "{err:?}");
+
+        // A single attempt is not a retry
+        let handler = RetryLayer::new().with_max_attempts(1).layer(Sequence(Mutex::new(vec![503])));
+        let Err(err) = handler.handle(get("https://example.com/k/v1/record.json")) else {
+            panic!("expected an error");
+        };
+        assert!(matches!(&err, ApiError::Kintone(e) if e.status == 503), "{err:?}");
+    }
+
+    #[test]
+    fn retry_layer_skips_only_non_retryable_kintone_errors() {
+        struct Rejecting(u16, &'static str, Mutex<usize>);
+        impl Handler for Rejecting {
+            fn handle(
+                &self,
+                _req: http::Request<RequestBody>,
+            ) -> Result<http::Response<ResponseBody>, ApiError> {
+                *self.2.lock().unwrap() += 1;
+                Err(ApiError::Kintone(crate::error::KintoneError {
+                    status: self.0,
+                    code: self.1.to_owned(),
+                    id: String::new(),
+                    message: String::new(),
+                    errors: Default::default(),
+                }))
+            }
+        }
+
+        let cases = [
+            (400, "GAIA_DA02", 3),
+            (400, "GAIA_XX99", 3),
+            (429, "GAIA_XX99", 3),
+            (400, "CB_IL02", 1),
+        ];
+        for (status, code, expected_calls) in cases {
+            let inner = Rejecting(status, code, Mutex::new(0));
+            let handler = RetryLayer::new()
+                .with_max_attempts(3)
+                .with_initial_delay(std::time::Duration::ZERO)
+                .layer(inner);
+            let _ = handler.handle(get("https://example.com/k/v1/record.json"));
+            assert_eq!(*handler.inner.2.lock().unwrap(), expected_calls, "{status} {code}");
+        }
+    }
+
+    #[test]
+    fn tap_layer_sees_raw_bodies() {
+        type Tapped = (Option<Vec<u8>>, Option<Vec<u8>>);
+        let tapped: Arc<Mutex<Vec<Tapped>>> = Arc::default();
+        let sink = Arc::clone(&tapped);
+        let mock = MockHandler::new()
+            .with_response(http::Method::POST, "/k/v1/record.json", 200, r#"{"id":"1"}"#)
+            .with_response(http::Method::POST, "/k/v1/file.json", 200, r#"{"fileKey":"k"}"#);
+        let handler = TapLayer::new(move |req, resp_or_err| {
+            let received = resp_or_err.unwrap().body().map(<[u8]>::to_vec);
+            sink.lock().unwrap().push((req.body().map(<[u8]>::to_vec), received));
+        })
+        .with_response_bodies(true)
+        .layer(mock);
+
+        let req = http::Request::post("https://example.com/k/v1/record.json")
+            .body(RequestBody::from_bytes(br#"{"app":1}"#.to_vec()))
+            .unwrap();
+        assert_eq!(read_body(handler.handle(req).unwrap()), r#"{"id":"1"}"#);
+        let req = http::Request::post("https://example.com/k/v1/file.json")
+            .body(RequestBody::from_reader(Cursor::new(b"data".to_vec())))
+            .unwrap();
+        assert_eq!(read_body(handler.handle(req).unwrap()), r#"{"fileKey":"k"}"#);
+
+        let tapped = tapped.lock().unwrap();
+        assert_eq!(tapped[0].0.as_deref(), Some(&br#"{"app":1}"#[..]));
+        assert_eq!(tapped[0].1.as_deref(), Some(&br#"{"id":"1"}"#[..]));
+        assert_eq!(tapped[1].0, None);
+        assert_eq!(tapped[1].1.as_deref(), Some(&br#"{"fileKey":"k"}"#[..]));
+    }
+
+    #[test]
+    fn tap_layer_buffers_only_json_responses() {
+        struct Serving(&'static str);
+        impl Handler for Serving {
+            fn handle(
+                &self,
+                _req: http::Request<RequestBody>,
+            ) -> Result<http::Response<ResponseBody>, ApiError> {
+                struct Broken;
+                impl Read for Broken {
+                    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                        Err(std::io::Error::other("connection reset"))
+                    }
+                }
+                let body = match self.0 {
+                    "application/json" => ResponseBody::from_reader(Broken, Some(self.0)),
+                    mime_type => ResponseBody::from_reader(Cursor::new(b"%PDF"), Some(mime_type)),
+                };
+                Ok(http::Response::new(body))
+            }
+        }
+        type Tapped = Vec<Result<Option<Vec<u8>>, String>>;
+        let tapped: Arc<Mutex<Tapped>> = Arc::default();
+        let tap = |sink: Arc<Mutex<Vec<_>>>| {
+            TapLayer::new(move |_, resp_or_err| {
+                let received = resp_or_err
+                    .map(|resp| resp.body().map(<[u8]>::to_vec))
+                    .map_err(ToString::to_string);
+                sink.lock().unwrap().push(received);
+            })
+            .with_response_bodies(true)
+        };
+
+        let handler = tap(Arc::clone(&tapped)).layer(Serving("application/pdf"));
+        let resp = handler.handle(get("https://example.com/k/v1/file.json")).unwrap();
+        assert_eq!(read_body(resp), "%PDF");
+
+        let handler = tap(Arc::clone(&tapped)).layer(Serving("application/json"));
+        let Err(err) = handler.handle(get("https://example.com/k/v1/record.json")) else {
+            panic!("expected an error");
+        };
+        assert!(matches!(err, ApiError::Transport(_)), "{err:?}");
+
+        let tapped = tapped.lock().unwrap();
+        assert_eq!(tapped[0], Ok(None), "non-JSON bodies are not buffered");
+        assert!(matches!(&tapped[1], Err(msg) if msg.contains("connection reset")), "{tapped:?}");
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn reqwest_handler_converts_requests() {
+        let handler = ReqwestHandler::new();
+        let req = http::Request::post("https://example.com/k/v1/record.json?app=1")
+            .header("x-cybozu-api-token", "t")
+            .body(RequestBody::from_bytes(br#"{"app":1}"#.to_vec()))
+            .unwrap();
+        let req = handler.to_reqwest_request(req).unwrap();
+        assert_eq!(req.method(), http::Method::POST);
+        assert_eq!(req.url().as_str(), "https://example.com/k/v1/record.json?app=1");
+        assert_eq!(req.headers()["x-cybozu-api-token"], "t");
+        assert_eq!(req.body().and_then(|b| b.as_bytes()), Some(&br#"{"app":1}"#[..]));
+
+        let req = handler.to_reqwest_request(get("https://example.com/k/v1/app.json")).unwrap();
+        assert!(req.body().is_none());
+    }
+
+    #[test]
+    fn streaming_response_body_checks_mime_type() {
+        let body =
+            ResponseBody::from_reader(Cursor::new(br#"{"ok":true}"#), Some("application/json"));
+        let value: serde_json::Value =
+            body.into_json_reader().map(serde_json::from_reader).unwrap().unwrap();
+        assert_eq!(value["ok"], true);
+
+        let mut body = ResponseBody::from_reader(Cursor::new(b"<html>"), Some("text/html"));
+        let err = body.read_json::<serde_json::Value>().unwrap_err();
+        assert!(matches!(err, ApiError::UnexpectedContentType { .. }), "{err:?}");
+    }
+
+    #[test]
+    fn backoff_strategies() {
+        use std::time::Duration;
+
+        let secs = |b: &mut dyn BackoffStrategy| -> Vec<u64> {
+            (1..=6).map(|attempt| b.next_delay(attempt).as_secs()).collect()
+        };
+        let mut exponential = ExponentialBackoff {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(8),
+        };
+        assert_eq!(secs(&mut exponential), vec![1, 2, 4, 8, 8, 8]);
+        assert_eq!(exponential.next_delay(200), Duration::from_secs(8));
+
+        assert_eq!(secs(&mut FixedBackoff(Duration::from_secs(2))), vec![2; 6]);
+
+        let mut fibonacci = FibonacciBackoff {
+            unit: Duration::from_secs(1),
+            max: Duration::from_secs(6),
+        };
+        assert_eq!(secs(&mut fibonacci), vec![1, 1, 2, 3, 5, 6]);
+        assert_eq!(fibonacci.next_delay(200), Duration::from_secs(6));
+    }
+
+    #[test]
+    fn read_json_with_limit() {
+        let json = r#"{"records":[{"id":"1"},{"id":"2"}]}"#;
+        let value: serde_json::Value =
+            ResponseBody::from_bytes(json).read_json_with_limit(1024).unwrap();
+        assert_eq!(value["records"][1]["id"], "2");
+
+        let err = ResponseBody::from_bytes(json)
+            .read_json_with_limit::<serde_json::Value>(16)
+            .unwrap_err();
+        assert!(matches!(err, ApiError::ResponseTooLarge { limit: 16 }), "{err:?}");
+    }
+
+    #[test]
+    fn read_json_rejects_html() {
+        let html = "<!DOCTYPE html><html><body>Under maintenance</body></html>";
+        let mut body = ResponseBody(ureq::Body::builder().mime_type("text/html").data(html));
+        let err = body.read_json::<serde_json::Value>().unwrap_err();
+        match err {
+            ApiError::UnexpectedContentType {
+                content_type,
+                snippet,
+            } => {
+                assert_eq!(content_type, "text/html");
+                assert_eq!(snippet, html);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let json = ureq::Body::builder().mime_type("application/json").data(r#"{"ok":true}"#);
+        let value: serde_json::Value = ResponseBody(json).read_json().unwrap();
+        assert_eq!(value["ok"], true);
+    }
+
+    #[test]
+    fn glob_match_patterns() {
+        assert!(glob_match("/k/v1/app.json", "/k/v1/app.json"));
+        assert!(!glob_match("/k/v1/app.json", "/k/v1/apps.json"));
+        assert!(glob_match("*/v1/app/*", "/k/guest/1/v1/app/views.json"));
+        assert!(glob_match("*.json", "/k/v1/app.json"));
+        assert!(!glob_match("*/v1/app/*", "/k/v1/records.json"));
+        assert!(!glob_match("/k/*/a*a", "/k/x/a"));
+    }
+}
@@ -0,0 +1,106 @@
+//! # Kintone App Customization Models
+//!
+//! This module provides type definitions for JavaScript and CSS customization settings of
+//! Kintone apps.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use kintone::model::app::customize::{CustomizeResource, CustomizeResources};
+//!
+//! let desktop = CustomizeResources {
+//!     js: vec![
+//!         CustomizeResource::url("https://cdn.example.com/lib.js"),
+//!         CustomizeResource::file("uploaded-file-key"),
+//!     ],
+//!     css: vec![],
+//! };
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{FileBody, file_body};
+
+/// The range of users to which the customization is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CustomizeScope {
+    /// Applied to all users
+    All,
+    /// Applied only to app administrators
+    Admin,
+    /// Not applied
+    None,
+}
+
+/// JavaScript and CSS files applied to a Kintone app on a particular platform (desktop or mobile).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomizeResources {
+    /// JavaScript files, applied in order
+    pub js: Vec<CustomizeResource>,
+    /// CSS files, applied in order
+    pub css: Vec<CustomizeResource>,
+}
+
+/// A single JavaScript or CSS file used for app customization.
+///
+/// A resource is either loaded from an external URL or uploaded to Kintone. To use an uploaded
+/// file, first upload it with [`crate::v1::file::upload`] and pass the returned file key to
+/// [`CustomizeResource::file`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CustomizeResource {
+    /// A file loaded from an external URL
+    Url { url: String },
+    /// A file uploaded to Kintone
+    File { file: FileBody },
+}
+
+impl CustomizeResource {
+    /// Creates a resource that is loaded from the given URL.
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::Url { url: url.into() }
+    }
+
+    /// Creates a resource from the file key of an uploaded file.
+    pub fn file(file_key: impl Into<String>) -> Self {
+        Self::File {
+            file: file_body(file_key).build(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_and_serialize_resources() {
+        let json = r#"{
+            "js": [
+                {"type": "URL", "url": "https://cdn.example.com/lib.js"},
+                {
+                    "type": "FILE",
+                    "file": {
+                        "fileKey": "abc123",
+                        "name": "bundle.js",
+                        "contentType": "text/javascript",
+                        "size": "1024"
+                    }
+                }
+            ],
+            "css": []
+        }"#;
+        let resources: CustomizeResources = serde_json::from_str(json).unwrap();
+        assert_eq!(resources.js[0], CustomizeResource::url("https://cdn.example.com/lib.js"));
+        let CustomizeResource::File { file } = &resources.js[1] else {
+            panic!("expected a file resource");
+        };
+        assert_eq!(file.file_key, "abc123");
+        assert_eq!(file.size, Some(1024));
+
+        let serialized = serde_json::to_value(CustomizeResource::file("abc123")).unwrap();
+        assert_eq!(serialized, serde_json::json!({"type": "FILE", "file": {"fileKey": "abc123"}}));
+    }
+}
@@ -203,97 +203,165 @@ use crate::model::record::FieldType;
 /// assert_eq!(text_field.field_code(), "name");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Assoc)]
-#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(remote = "Self", tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
 #[func(pub const fn field_type(&self) -> FieldType)]
 #[func(pub fn field_code(&self) -> &str)]
+#[func(pub fn label(&self) -> &str)]
 #[non_exhaustive]
 pub enum FieldProperty {
     #[assoc(field_type = FieldType::Calc)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Calc(CalcFieldProperty),
     #[assoc(field_type = FieldType::SingleLineText)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     SingleLineText(SingleLineTextFieldProperty),
     #[assoc(field_type = FieldType::MultiLineText)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     MultiLineText(MultiLineTextFieldProperty),
     #[assoc(field_type = FieldType::RichText)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     RichText(RichTextFieldProperty),
     #[assoc(field_type = FieldType::Number)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Number(NumberFieldProperty),
     #[assoc(field_type = FieldType::Date)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Date(DateFieldProperty),
     #[assoc(field_type = FieldType::Time)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Time(TimeFieldProperty),
     #[assoc(field_type = FieldType::Datetime)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     DateTime(DateTimeFieldProperty),
     #[assoc(field_type = FieldType::RadioButton)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     RadioButton(RadioButtonFieldProperty),
     #[assoc(field_type = FieldType::CheckBox)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     CheckBox(CheckBoxFieldProperty),
     #[assoc(field_type = FieldType::MultiSelect)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     MultiSelect(MultiSelectFieldProperty),
     #[assoc(field_type = FieldType::DropDown)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     DropDown(DropDownFieldProperty),
     #[assoc(field_type = FieldType::File)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     File(FileFieldProperty),
     #[assoc(field_type = FieldType::Link)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Link(LinkFieldProperty),
     #[assoc(field_type = FieldType::UserSelect)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     UserSelect(UserSelectFieldProperty),
     #[assoc(field_type = FieldType::OrganizationSelect)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     OrganizationSelect(OrganizationSelectFieldProperty),
     #[assoc(field_type = FieldType::GroupSelect)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     GroupSelect(GroupSelectFieldProperty),
     #[assoc(field_type = FieldType::ReferenceTable)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     ReferenceTable(ReferenceTableFieldProperty),
     #[assoc(field_type = FieldType::Group)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Group(GroupFieldProperty),
     #[assoc(field_type = FieldType::Subtable)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Subtable(SubtableFieldProperty),
     #[assoc(field_type = FieldType::RecordNumber)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     RecordNumber(RecordNumberFieldProperty),
     #[assoc(field_type = FieldType::Category)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Category(CategoryFieldProperty),
     #[assoc(field_type = FieldType::Status)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Status(StatusFieldProperty),
     #[assoc(field_type = FieldType::StatusAssignee)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     StatusAssignee(StatusAssigneeFieldProperty),
     #[assoc(field_type = FieldType::CreatedTime)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     CreatedTime(CreatedTimeFieldProperty),
     #[assoc(field_type = FieldType::UpdatedTime)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     UpdatedTime(UpdatedTimeFieldProperty),
     #[assoc(field_type = FieldType::Creator)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Creator(CreatorFieldProperty),
     #[assoc(field_type = FieldType::Modifier)]
     #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
     Modifier(ModifierFieldProperty),
-    // Note: Lookup is handled separately in deserialization as it can be applied to various field types
-    // and is identified by the presence of a "lookup" property in the JSON
+    /// A lookup field. Its [`field_type`](FieldProperty::field_type) is the type of the
+    /// underlying field, such as [`FieldType::SingleLineText`] or [`FieldType::Number`].
+    ///
+    /// Kintone reports lookup fields with the underlying type and an additional `lookup`
+    /// property, which is how they are told apart when deserializing.
+    #[serde(skip)]
+    #[assoc(field_type = _0.field_type)]
+    #[assoc(field_code = &_0.code)]
+    #[assoc(label = &_0.label)]
+    Lookup(LookupFieldProperty),
+}
+
+impl Serialize for FieldProperty {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FieldProperty::Lookup(property) => property.serialize(serializer),
+            _ => FieldProperty::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldProperty {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let is_lookup = value.get("lookup").is_some_and(|lookup| !lookup.is_null());
+        if is_lookup {
+            LookupFieldProperty::deserialize(value)
+                .map(FieldProperty::Lookup)
+                .map_err(D::Error::custom)
+        } else {
+            FieldProperty::deserialize(value).map_err(D::Error::custom)
+        }
+    }
+}
+
+impl From<LookupFieldProperty> for FieldProperty {
+    fn from(property: LookupFieldProperty) -> Self {
+        FieldProperty::Lookup(property)
+    }
 }
 
 // Common types used across field properties
@@ -339,10 +407,11 @@ pub enum DisplayFormat {
 }
 
 /// Protocol type for link fields.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum LinkProtocol {
     /// Web URL (http/https)
+    #[default]
     Web,
     /// Phone call (tel:)
     Call,
@@ -350,12 +419,6 @@ pub enum LinkProtocol {
     Mail,
 }
 
-impl Default for LinkProtocol {
-    fn default() -> Self {
-        Self::Web
-    }
-}
-
 /// Represents an option in a choice field (radio button, checkbox, dropdown, multi-select).
 ///
 /// Each option has a display label and an index that determines its position
@@ -794,7 +857,10 @@ pub struct ReferenceTable {
 
 /// Information about a related app.
 ///
-/// Specifies which app to reference either by ID or by app code.
+/// Specifies which app to reference either by ID or by app code. Exactly one of the two
+/// should be set when adding a field; [`RelatedApp::by_id`] and [`RelatedApp::by_code`]
+/// build a value that does so. Kintone returns both when reading the form, in which case
+/// `code` is empty if the related app has no app code.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RelatedApp {
@@ -805,6 +871,29 @@ pub struct RelatedApp {
     pub code: Option<String>,
 }
 
+impl RelatedApp {
+    /// Refers to the related app by its ID.
+    pub fn by_id(app: u64) -> Self {
+        Self {
+            app: Some(app),
+            code: None,
+        }
+    }
+
+    /// Refers to the related app by its app code.
+    pub fn by_code(code: impl Into<String>) -> Self {
+        Self {
+            app: None,
+            code: Some(code.into()),
+        }
+    }
+
+    /// Returns `true` if neither an app ID nor a non-empty app code is set.
+    pub fn is_unspecified(&self) -> bool {
+        self.app.is_none() && self.code.as_deref().is_none_or(str::is_empty)
+    }
+}
+
 /// Condition that relates records between apps.
 ///
 /// Defines which fields are used to match records between the current app and the related app.
@@ -2551,19 +2640,15 @@ impl From<LinkFieldPropertyBuilder> for LinkFieldProperty {
 /// # Examples
 /// ```rust
 /// use kintone::model::app::field::user_select_field_property;
-/// use kintone::model::{Entity, EntityType};
+/// use kintone::model::Entity;
 ///
 /// let field = user_select_field_property("assignee")
 ///     .label("Assignee")
 ///     .required(true)
-///     .default_value(vec![
-///         Entity { code: "user1".to_string(), entity_type: EntityType::USER }
-///     ])
-///     .entities(vec![
-///         Entity { code: "user1".to_string(), entity_type: EntityType::USER },
-///         Entity { code: "user2".to_string(), entity_type: EntityType::USER },
-///     ])
+///     .default_users(["user1"])
+///     .add_users(["user1", "user2"])
 ///     .build();
+/// assert_eq!(field.entities, vec![Entity::user("user1"), Entity::user("user2")]);
 /// ```
 pub fn user_select_field_property(code: impl Into<String>) -> UserSelectFieldPropertyBuilder {
     UserSelectFieldPropertyBuilder {
@@ -2629,6 +2714,21 @@ impl UserSelectFieldPropertyBuilder {
         self
     }
 
+    /// Sets the default selected users by their codes.
+    pub fn default_users(mut self, codes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.property.default_value =
+            crate::model::entities_from_codes(crate::model::EntityType::USER, codes);
+        self
+    }
+
+    /// Adds users to the available entities by their codes.
+    pub fn add_users(mut self, codes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.property
+            .entities
+            .extend(crate::model::entities_from_codes(crate::model::EntityType::USER, codes));
+        self
+    }
+
     /// Builds the final [`UserSelectFieldProperty`].
     pub fn build(self) -> UserSelectFieldProperty {
         self.property
@@ -2649,18 +2749,13 @@ impl From<UserSelectFieldPropertyBuilder> for UserSelectFieldProperty {
 /// # Examples
 /// ```rust
 /// use kintone::model::app::field::organization_select_field_property;
-/// use kintone::model::{Entity, EntityType};
+/// use kintone::model::Entity;
 ///
 /// let field = organization_select_field_property("department")
 ///     .label("Department")
 ///     .required(true)
-///     .default_value(vec![
-///         Entity { code: "eng".to_string(), entity_type: EntityType::ORGANIZATION }
-///     ])
-///     .entities(vec![
-///         Entity { code: "eng".to_string(), entity_type: EntityType::ORGANIZATION },
-///         Entity { code: "sales".to_string(), entity_type: EntityType::ORGANIZATION },
-///     ])
+///     .default_value(vec![Entity::organization("eng")])
+///     .add_organizations(["eng", "sales"])
 ///     .build();
 /// ```
 pub fn organization_select_field_property(
@@ -2729,6 +2824,25 @@ impl OrganizationSelectFieldPropertyBuilder {
         self
     }
 
+    /// Sets the default selected organizations by their codes.
+    pub fn default_organizations(
+        mut self,
+        codes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.property.default_value =
+            crate::model::entities_from_codes(crate::model::EntityType::ORGANIZATION, codes);
+        self
+    }
+
+    /// Adds organizations to the available entities by their codes.
+    pub fn add_organizations(mut self, codes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.property.entities.extend(crate::model::entities_from_codes(
+            crate::model::EntityType::ORGANIZATION,
+            codes,
+        ));
+        self
+    }
+
     /// Builds the final [`OrganizationSelectFieldProperty`].
     pub fn build(self) -> OrganizationSelectFieldProperty {
         self.property
@@ -2749,18 +2863,12 @@ impl From<OrganizationSelectFieldPropertyBuilder> for OrganizationSelectFieldPro
 /// # Examples
 /// ```rust
 /// use kintone::model::app::field::group_select_field_property;
-/// use kintone::model::{Entity, EntityType};
 ///
 /// let field = group_select_field_property("team")
 ///     .label("Team")
 ///     .required(true)
-///     .default_value(vec![
-///         Entity { code: "backend".to_string(), entity_type: EntityType::GROUP }
-///     ])
-///     .entities(vec![
-///         Entity { code: "backend".to_string(), entity_type: EntityType::GROUP },
-///         Entity { code: "frontend".to_string(), entity_type: EntityType::GROUP },
-///     ])
+///     .default_groups(["backend"])
+///     .add_groups(["backend", "frontend"])
 ///     .build();
 /// ```
 pub fn group_select_field_property(code: impl Into<String>) -> GroupSelectFieldPropertyBuilder {
@@ -2827,6 +2935,21 @@ impl GroupSelectFieldPropertyBuilder {
         self
     }
 
+    /// Sets the default selected groups by their codes.
+    pub fn default_groups(mut self, codes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.property.default_value =
+            crate::model::entities_from_codes(crate::model::EntityType::GROUP, codes);
+        self
+    }
+
+    /// Adds groups to the available entities by their codes.
+    pub fn add_groups(mut self, codes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.property
+            .entities
+            .extend(crate::model::entities_from_codes(crate::model::EntityType::GROUP, codes));
+        self
+    }
+
     /// Builds the final [`GroupSelectFieldProperty`].
     pub fn build(self) -> GroupSelectFieldProperty {
         self.property
@@ -2849,10 +2972,7 @@ impl From<GroupSelectFieldPropertyBuilder> for GroupSelectFieldProperty {
 /// use kintone::model::app::field::{reference_table_field_property, ReferenceTable, RelatedApp, ReferenceCondition};
 ///
 /// let reference_table = ReferenceTable {
-///     related_app: RelatedApp {
-///         app: Some(123),
-///         code: None,
-///     },
+///     related_app: RelatedApp::by_id(123),
 ///     condition: ReferenceCondition {
 ///         field: "customer_id".to_string(),
 ///         related_field: "id".to_string(),
@@ -3497,3 +3617,58 @@ impl From<ModifierFieldPropertyBuilder> for ModifierFieldProperty {
         builder.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct FormFieldsResponse {
+        properties: BTreeMap<String, FieldProperty>,
+    }
+
+    #[test]
+    fn deserialize_lookup_fields() {
+        let json = include_str!("../../testdata/form_fields_lookup.json");
+        let properties = serde_json::from_str::<FormFieldsResponse>(json).unwrap().properties;
+
+        let FieldProperty::Lookup(customer) = &properties["customer"] else {
+            panic!("expected a lookup field: {:?}", properties["customer"]);
+        };
+        assert_eq!(properties["customer"].field_type(), FieldType::SingleLineText);
+        assert_eq!(properties["customer"].field_code(), "customer");
+        assert_eq!(customer.lookup.related_app.app, Some(12));
+        assert_eq!(customer.lookup.related_key_field, "name");
+        assert_eq!(customer.lookup.field_mappings[0].related_field, "phone");
+        assert!(matches!(properties["customer_phone"], FieldProperty::SingleLineText(_)));
+
+        let FieldProperty::Subtable(items) = &properties["items"] else {
+            panic!("expected a subtable");
+        };
+        let product_id = &items.fields["product_id"];
+        assert!(matches!(product_id, FieldProperty::Lookup(_)));
+        assert_eq!(product_id.field_type(), FieldType::Number);
+
+        for property in properties.values() {
+            let serialized = serde_json::to_value(property).unwrap();
+            let roundtrip: FieldProperty = serde_json::from_value(serialized).unwrap();
+            assert_eq!(&roundtrip, property);
+        }
+        let serialized = serde_json::to_value(&properties["customer"]).unwrap();
+        assert_eq!(serialized["type"], "SINGLE_LINE_TEXT");
+        assert_eq!(serialized["lookup"]["relatedKeyField"], "name");
+    }
+
+    #[test]
+    fn related_app_constructors() {
+        let by_id = serde_json::to_value(RelatedApp::by_id(12)).unwrap();
+        assert_eq!(by_id, serde_json::json!({"app": "12", "code": null}));
+        let by_code = serde_json::to_value(RelatedApp::by_code("CUSTOMERS")).unwrap();
+        assert_eq!(by_code, serde_json::json!({"app": null, "code": "CUSTOMERS"}));
+
+        assert!(!RelatedApp::by_id(12).is_unspecified());
+        assert!(!RelatedApp::by_code("CUSTOMERS").is_unspecified());
+        assert!(RelatedApp::by_code("").is_unspecified());
+        assert!(RelatedApp::default().is_unspecified());
+    }
+}
@@ -0,0 +1,189 @@
+//! # Kintone Form Layout Models
+//!
+//! This module provides type definitions for the layout of a Kintone app's form: how fields
+//! are arranged into rows, subtables, and groups, and the size of each element.
+//!
+//! The same layout is used on desktop and mobile; Kintone does not expose a separate mobile
+//! layout through the REST API.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use kintone::model::app::layout::{LayoutElement, LayoutRow, LayoutSize};
+//! use kintone::model::record::FieldType;
+//!
+//! let layout = vec![
+//!     LayoutRow::Row {
+//!         fields: vec![
+//!             LayoutElement::field(FieldType::SingleLineText, "title").with_width(300),
+//!             LayoutElement::field(FieldType::Date, "due_date"),
+//!         ],
+//!     },
+//!     LayoutRow::Group {
+//!         code: "details".to_owned(),
+//!         layout: vec![LayoutRow::Row {
+//!             fields: vec![LayoutElement::field(FieldType::MultiLineText, "description")],
+//!         }],
+//!     },
+//! ];
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::internal::serde_helper::option_stringified;
+use crate::model::record::FieldType;
+
+/// A top-level element of a form layout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LayoutRow {
+    /// A row of fields placed side by side
+    Row { fields: Vec<LayoutElement> },
+    /// A subtable and the fields it contains
+    Subtable {
+        code: String,
+        fields: Vec<LayoutElement>,
+    },
+    /// A field group and the rows it contains
+    Group {
+        code: String,
+        layout: Vec<LayoutRow>,
+    },
+}
+
+/// A field or decoration element placed in a row.
+///
+/// Which members are set depends on the element type:
+/// - Ordinary fields have a `code`
+/// - [`FieldType::Label`] elements have a `label` (HTML)
+/// - [`FieldType::Spacer`] elements may have an `element_id`
+/// - [`FieldType::Hr`] elements have neither
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutElement {
+    /// The type of the element
+    #[serde(rename = "type")]
+    pub field_type: FieldType,
+    /// The field code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The text of a label element
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// The element ID of a spacer element
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element_id: Option<String>,
+    /// The size of the element
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<LayoutSize>,
+}
+
+impl LayoutElement {
+    /// Creates an element for the field with the given type and code.
+    pub fn field(field_type: FieldType, code: impl Into<String>) -> Self {
+        Self {
+            field_type,
+            code: Some(code.into()),
+            label: None,
+            element_id: None,
+            size: None,
+        }
+    }
+
+    /// Creates a label element displaying the given HTML.
+    pub fn label(label: impl Into<String>) -> Self {
+        Self {
+            field_type: FieldType::Label,
+            code: None,
+            label: Some(label.into()),
+            element_id: None,
+            size: None,
+        }
+    }
+
+    /// Sets the width of the element in pixels. (builder style)
+    pub fn with_width(mut self, width: u64) -> Self {
+        self.size.get_or_insert_with(LayoutSize::default).width = Some(width);
+        self
+    }
+}
+
+/// The size of a layout element in pixels.
+///
+/// Unset members keep the size chosen by Kintone.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutSize {
+    /// The width of the element
+    #[serde(
+        default,
+        with = "option_stringified",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub width: Option<u64>,
+    /// The height of the element (spacers only)
+    #[serde(
+        default,
+        with = "option_stringified",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub height: Option<u64>,
+    /// The height of the input area (multi-line text and rich text only)
+    #[serde(
+        default,
+        with = "option_stringified",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub inner_height: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct LayoutResponse {
+        layout: Vec<LayoutRow>,
+    }
+
+    #[test]
+    fn deserialize_and_serialize_layout() {
+        let json = include_str!("../../testdata/form_layout.json");
+        let layout = serde_json::from_str::<LayoutResponse>(json).unwrap().layout;
+        assert_eq!(layout.len(), 3);
+
+        let LayoutRow::Row { fields } = &layout[0] else {
+            panic!("expected a row");
+        };
+        assert_eq!(
+            fields[0],
+            LayoutElement::field(FieldType::SingleLineText, "title").with_width(193)
+        );
+        assert_eq!(fields[1].label.as_deref(), Some("<b>Notes</b>"));
+        assert_eq!(fields[2].element_id.as_deref(), Some("space1"));
+        assert_eq!(fields[2].size.as_ref().unwrap().height, Some(40));
+
+        let LayoutRow::Subtable { code, fields } = &layout[1] else {
+            panic!("expected a subtable");
+        };
+        assert_eq!(code, "items");
+        assert_eq!(fields.len(), 2);
+
+        let LayoutRow::Group {
+            code,
+            layout: inner,
+        } = &layout[2]
+        else {
+            panic!("expected a group");
+        };
+        assert_eq!(code, "details");
+        let LayoutRow::Row { fields } = &inner[0] else {
+            panic!("expected a row in the group");
+        };
+        assert_eq!(fields[0].size.as_ref().unwrap().inner_height, Some(120));
+        assert_eq!(fields[1].field_type, FieldType::Hr);
+
+        let expected: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&layout).unwrap(), expected["layout"]);
+    }
+}
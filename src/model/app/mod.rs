@@ -6,6 +6,9 @@
 //! # Modules
 //!
 //! - [`field`] - Field property definitions and configurations for different field types
+//! - [`customize`] - JavaScript and CSS customization settings
+//! - [`layout`] - Form layout (rows, subtables, and groups)
+//! - [`notification`] - Notification settings
 //!
 //! # Examples
 //!
@@ -26,4 +29,7 @@
 //! println!("Field type: {:?}", field_property.field_type());
 //! ```
 
+pub mod customize;
 pub mod field;
+pub mod layout;
+pub mod notification;
@@ -0,0 +1,130 @@
+//! # Kintone App Notification Models
+//!
+//! This module provides type definitions for the notification settings of Kintone apps:
+//! general notifications, per-record notifications, and reminder notifications.
+//!
+//! Recipients are represented as [`Entity`] values, and record conditions are represented as
+//! query strings following Kintone's query syntax (an empty string matches all records).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use kintone::model::{Entity, EntityType};
+//! use kintone::model::app::notification::{NotificationTarget, PerRecordNotification};
+//!
+//! let notification = PerRecordNotification {
+//!     filter_cond: "priority in (\"High\")".to_owned(),
+//!     title: "High priority record".to_owned(),
+//!     targets: vec![NotificationTarget {
+//!         entity: Entity {
+//!             entity_type: EntityType::FIELD_ENTITY,
+//!             code: "assignee".to_owned(),
+//!         },
+//!         include_subs: false,
+//!     }],
+//! };
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::Entity;
+
+/// A recipient of the general notifications and the events that trigger them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneralNotification {
+    /// The recipient of the notifications
+    pub entity: Entity,
+    /// Whether to also notify members of child organizations (organizations only)
+    #[serde(default)]
+    pub include_subs: bool,
+    /// Notify when a record is added
+    pub record_added: bool,
+    /// Notify when a record is edited
+    pub record_edited: bool,
+    /// Notify when a comment is posted
+    pub comment_added: bool,
+    /// Notify when the process management status changes
+    pub status_changed: bool,
+    /// Notify when records are imported from a file
+    pub file_imported: bool,
+}
+
+/// A recipient of per-record or reminder notifications.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationTarget {
+    /// The recipient of the notifications
+    pub entity: Entity,
+    /// Whether to also notify members of child organizations (organizations only)
+    #[serde(default)]
+    pub include_subs: bool,
+}
+
+/// A notification sent when a record matches a condition.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerRecordNotification {
+    /// The record condition in Kintone's query syntax (empty for all records)
+    pub filter_cond: String,
+    /// The summary text of the notification
+    pub title: String,
+    /// The recipients of the notification
+    pub targets: Vec<NotificationTarget>,
+}
+
+/// A notification sent at a time relative to a date or datetime field of a record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderNotification {
+    /// When the notification is sent
+    pub timing: ReminderTiming,
+    /// The record condition in Kintone's query syntax (empty for all records)
+    pub filter_cond: String,
+    /// The summary text of the notification
+    pub title: String,
+    /// The recipients of the notification
+    pub targets: Vec<NotificationTarget>,
+}
+
+/// The timing of a reminder notification.
+///
+/// The timing is relative to the value of a date or datetime field. Specify either
+/// `hours_later` (datetime fields only) or `time`, but not both.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderTiming {
+    /// The field code of the date or datetime field
+    pub code: String,
+    /// The number of days after (positive) or before (negative) the field value
+    pub days_later: i64,
+    /// The number of hours after (positive) or before (negative) the field value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours_later: Option<i64>,
+    /// The time of day to send the notification, in `HH:MM` format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EntityType;
+
+    #[test]
+    fn deserialize_reminder_notification() {
+        let json = r#"{
+            "timing": {"code": "due_date", "daysLater": -1, "time": "08:30"},
+            "filterCond": "",
+            "title": "Due tomorrow",
+            "targets": [
+                {"entity": {"type": "FIELD_ENTITY", "code": "assignee"}, "includeSubs": false}
+            ]
+        }"#;
+        let notification: ReminderNotification = serde_json::from_str(json).unwrap();
+        assert_eq!(notification.timing.days_later, -1);
+        assert_eq!(notification.timing.hours_later, None);
+        assert_eq!(notification.timing.time.as_deref(), Some("08:30"));
+        assert_eq!(notification.targets[0].entity.entity_type, EntityType::FIELD_ENTITY);
+    }
+}
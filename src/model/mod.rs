@@ -37,7 +37,8 @@ pub mod space;
 /// Represents the type of entity in Kintone's user management system.
 ///
 /// Kintone supports three types of entities for access control and assignment:
-/// users, groups, and organizations. This enum is used throughout the API
+/// users, groups, and organizations. Settings such as notifications can also refer to
+/// the entities selected in a record field. This enum is used throughout the API
 /// to specify which type of entity is being referenced.
 ///
 /// # Examples
@@ -57,6 +58,10 @@ pub enum EntityType {
     GROUP,
     /// Represents an organizational unit
     ORGANIZATION,
+    /// Represents a user, group, or organization selection field of a record.
+    /// The code is the field code. Used in notification and permission settings.
+    #[allow(non_camel_case_types)]
+    FIELD_ENTITY,
 }
 
 /// Represents a generic entity in Kintone's user management system.
@@ -81,11 +86,9 @@ pub enum EntityType {
 ///     code: "john.doe".to_owned(),
 /// };
 ///
-/// // Create a group entity
-/// let group_entity = Entity {
-///     entity_type: EntityType::GROUP,
-///     code: "development-team".to_owned(),
-/// };
+/// // Or use the shorthand constructors
+/// let group_entity = Entity::group("development-team");
+/// assert_eq!(group_entity.entity_type, EntityType::GROUP);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Entity {
@@ -94,6 +97,53 @@ pub struct Entity {
     pub code: String,
 }
 
+impl Entity {
+    /// Creates an entity of the given type.
+    pub fn new(entity_type: EntityType, code: impl Into<String>) -> Self {
+        Entity {
+            entity_type,
+            code: code.into(),
+        }
+    }
+
+    /// Creates a user entity from a login name.
+    pub fn user(code: impl Into<String>) -> Self {
+        Self::new(EntityType::USER, code)
+    }
+
+    /// Creates a group (role) entity from a group code.
+    pub fn group(code: impl Into<String>) -> Self {
+        Self::new(EntityType::GROUP, code)
+    }
+
+    /// Creates an organization entity from an organization code.
+    pub fn organization(code: impl Into<String>) -> Self {
+        Self::new(EntityType::ORGANIZATION, code)
+    }
+
+    /// Creates an entity that refers to the users selected in a record field.
+    pub fn field_entity(field_code: impl Into<String>) -> Self {
+        Self::new(EntityType::FIELD_ENTITY, field_code)
+    }
+}
+
+/// Creates entities of the same type from a list of codes.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::model::{Entity, EntityType, entities_from_codes};
+///
+/// let users = entities_from_codes(EntityType::USER, ["alice", "bob"]);
+/// assert_eq!(users, vec![Entity::user("alice"), Entity::user("bob")]);
+/// ```
+pub fn entities_from_codes(
+    entity_type: EntityType,
+    codes: impl IntoIterator<Item = impl Into<String>>,
+) -> Vec<Entity> {
+    codes.into_iter().map(|code| Entity::new(entity_type.clone(), code)).collect()
+}
+
 /// Represents a user in the Kintone system.
 ///
 /// This structure contains basic information about a Kintone user, including
@@ -102,7 +152,7 @@ pub struct Entity {
 ///
 /// # Fields
 ///
-/// * `name` - The display name of the user
+/// * `name` - The display name of the user. Not required when writing (see [`User::from_code`])
 /// * `code` - The unique identifier code for the user (typically their login name)
 ///
 /// # Examples
@@ -120,10 +170,33 @@ pub struct Entity {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub name: String,
     pub code: String,
 }
 
+impl User {
+    /// Creates a user reference from its code, for use when writing field values.
+    ///
+    /// Kintone returns both `code` and `name` when reading, but only requires `code` when
+    /// writing. An empty `name` is omitted from the serialized JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::User;
+    ///
+    /// let user = User::from_code("john.doe");
+    /// assert_eq!(serde_json::to_string(&user).unwrap(), r#"{"code":"john.doe"}"#);
+    /// ```
+    pub fn from_code(code: impl Into<String>) -> Self {
+        Self {
+            name: String::new(),
+            code: code.into(),
+        }
+    }
+}
+
 /// Represents a group in the Kintone system.
 ///
 /// Groups are collections of users that can be managed together for permissions,
@@ -132,7 +205,7 @@ pub struct User {
 ///
 /// # Fields
 ///
-/// * `name` - The display name of the group
+/// * `name` - The display name of the group. Not required when writing (see [`Group::from_code`])
 /// * `code` - The unique identifier code for the group
 ///
 /// # Examples
@@ -150,10 +223,33 @@ pub struct User {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Group {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub name: String,
     pub code: String,
 }
 
+impl Group {
+    /// Creates a group reference from its code, for use when writing field values.
+    ///
+    /// Kintone returns both `code` and `name` when reading, but only requires `code` when
+    /// writing. An empty `name` is omitted from the serialized JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::Group;
+    ///
+    /// let group = Group::from_code("dev-team");
+    /// assert_eq!(serde_json::to_string(&group).unwrap(), r#"{"code":"dev-team"}"#);
+    /// ```
+    pub fn from_code(code: impl Into<String>) -> Self {
+        Self {
+            name: String::new(),
+            code: code.into(),
+        }
+    }
+}
+
 /// Represents an organizational unit in the Kintone system.
 ///
 /// Organizations represent hierarchical structures within your company or entity.
@@ -162,7 +258,8 @@ pub struct Group {
 ///
 /// # Fields
 ///
-/// * `name` - The display name of the organization
+/// * `name` - The display name of the organization. Not required when writing
+///   (see [`Organization::from_code`])
 /// * `code` - The unique identifier code for the organization
 ///
 /// # Examples
@@ -180,10 +277,33 @@ pub struct Group {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Organization {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub name: String,
     pub code: String,
 }
 
+impl Organization {
+    /// Creates an organization reference from its code, for use when writing field values.
+    ///
+    /// Kintone returns both `code` and `name` when reading, but only requires `code` when
+    /// writing. An empty `name` is omitted from the serialized JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::Organization;
+    ///
+    /// let org = Organization::from_code("eng-div");
+    /// assert_eq!(serde_json::to_string(&org).unwrap(), r#"{"code":"eng-div"}"#);
+    /// ```
+    pub fn from_code(code: impl Into<String>) -> Self {
+        Self {
+            name: String::new(),
+            code: code.into(),
+        }
+    }
+}
+
 /// Represents metadata for a file stored in Kintone.
 ///
 /// This structure contains information about files that have been uploaded to Kintone,
@@ -223,12 +343,54 @@ pub struct Organization {
 #[serde(rename_all = "camelCase")]
 pub struct FileBody {
     pub file_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[serde(with = "option_stringified")]
+    #[serde(
+        default,
+        with = "option_stringified",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub size: Option<usize>,
 }
 
+impl FileBody {
+    /// Downloads the file this attachment refers to.
+    ///
+    /// This is a shorthand for [`crate::v1::file::download`] with [`file_key`](Self::file_key).
+    /// The returned response carries the attachment's [`name`](Self::name), and falls back to its
+    /// [`content_type`](Self::content_type) if the server does not report a MIME type.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// let record = kintone::v1::record::get_record(123, 456).send(&client)?.record;
+    /// if let Some(FieldValue::File(files)) = record.get("attachments") {
+    ///     for file in files {
+    ///         let mut download = file.download(&client)?;
+    ///         let mut output = std::fs::File::create(download.name.as_deref().unwrap_or("file"))?;
+    ///         std::io::copy(&mut download.content, &mut output)?;
+    ///     }
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn download(
+        &self,
+        client: &crate::client::KintoneClient,
+    ) -> Result<crate::v1::file::DownloadFileResponse, crate::error::ApiError> {
+        let mut response = crate::v1::file::download(self.file_key.clone()).send(client)?;
+        response.name = self.name.clone();
+        if response.mime_type.is_none() {
+            response.mime_type = self.content_type.as_deref().and_then(|t| t.parse().ok());
+        }
+        Ok(response)
+    }
+}
+
 /// Creates a new file body builder.
 ///
 /// # Arguments
@@ -332,3 +494,45 @@ impl std::fmt::Display for Order {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{mock_client, test_client_builder};
+    use crate::middleware::{Handler, MockHandler, RequestBody, ResponseBody};
+
+    #[test]
+    fn file_body_download_carries_name_and_content_type() {
+        /// Serves the file without a `Content-Type` header.
+        struct Untyped;
+        impl Handler for Untyped {
+            fn handle(
+                &self,
+                _req: http::Request<RequestBody>,
+            ) -> Result<http::Response<ResponseBody>, crate::error::ApiError> {
+                let body = ResponseBody::from_reader(std::io::Cursor::new(b"%PDF"), None);
+                Ok(http::Response::new(body))
+            }
+        }
+        let file = file_body("key1").name("report.pdf").content_type("application/pdf").build();
+
+        let client = test_client_builder().with_handler(Untyped).build();
+        let mut download = file.download(&client).unwrap();
+        assert_eq!(download.name.as_deref(), Some("report.pdf"));
+        assert_eq!(download.mime_type, Some(mime::APPLICATION_PDF));
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut download.content, &mut content).unwrap();
+        assert_eq!(content, "%PDF");
+
+        let download = file_body("key2").build().download(&client).unwrap();
+        assert_eq!(download.name, None);
+        assert_eq!(download.mime_type, None);
+
+        // A MIME type reported by the server takes precedence
+        let mock =
+            MockHandler::new().with_response(http::Method::GET, "/k/v1/file.json", 200, "{}");
+        let download = file.download(&mock_client(&mock)).unwrap();
+        assert_eq!(download.mime_type, Some(mime::APPLICATION_JSON));
+        assert!(mock.requests()[0].uri.ends_with("/k/v1/file.json?fileKey=key1"));
+    }
+}
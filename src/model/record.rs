@@ -8,8 +8,10 @@
 //! - [`Record`] - A collection of field values representing a single record
 //! - [`FieldValue`] - Enum containing all possible field value types
 //! - [`FieldType`] - Enum identifying the type of a field
+//! - [`CalcValue`] - The parsed result of a calculated field
 //! - [`TableRow`] - Represents a row within a table field
 //! - [`RecordComment`] - Comments associated with records
+//! - [`FromRecord`] / [`ToRecord`] - Traits for mapping records onto user-defined structs
 //!
 //! # Basic Usage
 //!
@@ -36,6 +38,18 @@
 //!
 //! ## Alternative Initialization Methods
 //!
+//! The constructors on [`FieldValue`] reduce the conversion noise:
+//!
+//! ```rust
+//! use kintone::model::record::{Record, FieldValue};
+//!
+//! let record = Record::from([
+//!     ("title", FieldValue::text("Project Alpha")),
+//!     ("budget", FieldValue::number(50000)),
+//!     ("active", FieldValue::checkbox(["Yes"])),
+//! ]);
+//! ```
+//!
 //! You can also create records using the `From` trait with an array of field tuples:
 //!
 //! ```rust
@@ -92,8 +106,8 @@ use enum_assoc::Assoc;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    internal::serde_helper::{stringified, stringified_or_empty},
-    model::{Entity, FileBody, Group, Organization, User},
+    internal::serde_helper::{decimal_or_empty, empty_as_none, null_as_default, stringified},
+    model::{Entity, FileBody, Group, Organization, User, app::field::DisplayFormat},
 };
 
 /// Represents a record in a Kintone application.
@@ -120,7 +134,11 @@ use crate::{
 ///     println!("Name: {}", name);
 /// }
 /// ```
-#[derive(Clone, Serialize, Deserialize)]
+///
+/// Two records are equal when they have the same fields with the same values, regardless of
+/// the order in which the fields were added. Use [`Record::content_eq`] to ignore built-in
+/// fields such as `$revision`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Record {
     #[serde(flatten)]
     fields: BTreeMap<String, FieldValue>,
@@ -143,6 +161,66 @@ impl Record {
         }
     }
 
+    /// Parses a record directly from a JSON stream.
+    ///
+    /// Unlike reading the whole input into a `String` first, this parses the fields as
+    /// they are read, which keeps peak memory low when processing large exports.
+    /// Wrap unbuffered sources such as files or sockets in a [`std::io::BufReader`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let json = r#"{"name": {"type": "SINGLE_LINE_TEXT", "value": "John"}}"#;
+    /// let record = Record::from_reader(json.as_bytes()).unwrap();
+    /// assert_eq!(
+    ///     record.get("name"),
+    ///     Some(&FieldValue::SingleLineText("John".to_owned()))
+    /// );
+    /// ```
+    pub fn from_reader(reader: impl std::io::Read) -> Result<Self, serde_json::Error> {
+        serde_json::from_reader(reader)
+    }
+
+    /// Converts the record into a [`serde_json::Value`] in the Kintone REST API format.
+    ///
+    /// This is handy for passing records to code that works on generic JSON values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let record = Record::from([("name", FieldValue::text("John"))]);
+    /// let value = record.to_json_value();
+    /// assert_eq!(value["name"]["type"], "SINGLE_LINE_TEXT");
+    /// assert_eq!(value["name"]["value"], "John");
+    /// ```
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("a record is always representable as JSON")
+    }
+
+    /// Parses a record from a [`serde_json::Value`] in the Kintone REST API format.
+    ///
+    /// This is the inverse of [`Record::to_json_value`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let value = serde_json::json!({
+    ///     "name": {"type": "SINGLE_LINE_TEXT", "value": "John"}
+    /// });
+    /// let record = Record::from_json_value(value).unwrap();
+    /// assert_eq!(record.get("name"), Some(&FieldValue::text("John")));
+    /// assert_eq!(Record::from_json_value(record.to_json_value()).unwrap().get("name"), record.get("name"));
+    /// ```
+    pub fn from_json_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+
     /// Creates a copy of the record without built-in system fields.
     ///
     /// Built-in fields are system-managed fields like record ID, creator, creation time,
@@ -175,6 +253,108 @@ impl Record {
             .collect()
     }
 
+    /// Computes the changes needed to turn this record into `other`.
+    ///
+    /// Returns a record containing only the fields of `other` whose values differ from this
+    /// record, including fields that are absent from this record. Built-in system fields are
+    /// always excluded, so the result can be passed directly to
+    /// [`update_record`](crate::v1::record::update_record).
+    ///
+    /// Fields present in this record but absent from `other` are not included: Kintone leaves
+    /// fields that are omitted from an update unchanged, so there is no way to express their removal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let mut original = Record::new();
+    /// original.put_field("$revision", FieldValue::__REVISION__(3));
+    /// original.put_field("name", FieldValue::SingleLineText("John".to_owned()));
+    /// original.put_field("city", FieldValue::SingleLineText("Tokyo".to_owned()));
+    ///
+    /// let mut modified = original.clone();
+    /// modified.put_field("city", FieldValue::SingleLineText("Osaka".to_owned()));
+    ///
+    /// let diff = original.diff(&modified);
+    /// assert_eq!(diff.field_codes().collect::<Vec<_>>(), ["city"]);
+    /// ```
+    pub fn diff(&self, other: &Record) -> Record {
+        other
+            .fields()
+            .filter(|(code, value)| {
+                !value.field_type().is_builtin() && self.get(code) != Some(*value)
+            })
+            .map(|(code, value)| (code.to_owned(), value.clone()))
+            .collect()
+    }
+
+    /// Moves every field of `other` into this record, replacing fields that exist in both.
+    ///
+    /// This is convenient for layering records, e.g. applying overrides on top of defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let mut record = Record::from([("name", FieldValue::text("John")), ("city", FieldValue::text("Tokyo"))]);
+    /// record.merge(Record::from([("city", FieldValue::text("Osaka"))]));
+    /// assert_eq!(record.get("city"), Some(&FieldValue::text("Osaka")));
+    /// assert_eq!(record.get("name"), Some(&FieldValue::text("John")));
+    /// ```
+    pub fn merge(&mut self, other: Record) {
+        self.fields.extend(other.fields);
+    }
+
+    /// Moves the fields of `other` that this record lacks into it, keeping existing values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let mut record = Record::from([("city", FieldValue::text("Osaka"))]);
+    /// let defaults = Record::from([("city", FieldValue::text("Tokyo")), ("country", FieldValue::text("Japan"))]);
+    /// record.merge_if_absent(defaults);
+    /// assert_eq!(record.get("city"), Some(&FieldValue::text("Osaka")));
+    /// assert_eq!(record.get("country"), Some(&FieldValue::text("Japan")));
+    /// ```
+    pub fn merge_if_absent(&mut self, other: Record) {
+        for (code, value) in other.fields {
+            self.fields.entry(code).or_insert(value);
+        }
+    }
+
+    /// Returns `true` if both records have the same user data, ignoring built-in system fields.
+    ///
+    /// Records fetched at different times typically differ in their revision and modification
+    /// time even when no user data has changed. This compares only the non-built-in fields,
+    /// which makes it suitable for "has anything actually changed?" checks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let mut a = Record::new();
+    /// a.put_field("$revision", FieldValue::__REVISION__(1));
+    /// a.put_field("name", FieldValue::SingleLineText("John".to_owned()));
+    ///
+    /// let mut b = a.clone();
+    /// b.put_field("$revision", FieldValue::__REVISION__(2));
+    /// assert!(a.content_eq(&b));
+    ///
+    /// b.put_field("name", FieldValue::SingleLineText("Jane".to_owned()));
+    /// assert!(!a.content_eq(&b));
+    /// ```
+    pub fn content_eq(&self, other: &Record) -> bool {
+        fn user_fields(record: &Record) -> impl Iterator<Item = (&String, &FieldValue)> {
+            record.fields.iter().filter(|(_, value)| !value.field_type().is_builtin())
+        }
+        user_fields(self).eq(user_fields(other))
+    }
+
     /// Gets a reference to the field value for the specified field code.
     ///
     /// # Arguments
@@ -201,6 +381,24 @@ impl Record {
         self.fields.get(field_code)
     }
 
+    /// Gets the field value for the specified field code, failing if the field does not exist.
+    ///
+    /// This is a convenience for implementing [`FromRecord`], where a missing field is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{FieldAccessError, Record};
+    ///
+    /// let record = Record::new();
+    /// assert!(matches!(record.require("name"), Err(FieldAccessError::Missing { .. })));
+    /// ```
+    pub fn require(&self, field_code: &str) -> Result<&FieldValue, FieldAccessError> {
+        self.get(field_code).ok_or_else(|| FieldAccessError::Missing {
+            field_code: field_code.to_owned(),
+        })
+    }
+
     /// Gets a mutable reference to the field value for the specified field code.
     ///
     /// # Arguments
@@ -426,6 +624,123 @@ impl Record {
         };
         Some(*value)
     }
+
+    /// Gets the value of the record number field, such as `"PRJ-42"`, if the record has one.
+    ///
+    /// Unlike `$id`, the record number field has an app-specific field code, so it is found
+    /// by its type. The value includes the app code prefix when one is configured, which makes
+    /// it suitable as a human-friendly label for the record.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let record = Record::from([("number", FieldValue::RecordNumber("PRJ-42".to_owned()))]);
+    /// assert_eq!(record.record_number(), Some("PRJ-42"));
+    /// assert_eq!(Record::new().record_number(), None);
+    /// ```
+    pub fn record_number(&self) -> Option<&str> {
+        self.field_values().find_map(|value| match value {
+            FieldValue::RecordNumber(number) => Some(number.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Sets the record ID by storing it in the built-in `$id` field.
+    ///
+    /// [`update_record`](crate::v1::record::update_record) uses this as the ID of the record to
+    /// update when none is given to the request itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let mut record = Record::new();
+    /// record.set_id(42);
+    /// assert_eq!(record.id(), Some(42));
+    /// assert_eq!(record.get("$id"), Some(&FieldValue::__ID__(42)));
+    /// ```
+    pub fn set_id(&mut self, id: u64) {
+        self.put_field("$id", FieldValue::__ID__(id));
+    }
+
+    /// Sets the revision number by storing it in the built-in `$revision` field.
+    ///
+    /// [`update_record`](crate::v1::record::update_record) uses this as the expected revision
+    /// when none is given to the request itself, so a fetched record can be modified and sent
+    /// back with optimistic locking.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::Record;
+    ///
+    /// let mut record = Record::new();
+    /// record.set_revision(3);
+    /// assert_eq!(record.revision(), Some(3));
+    /// ```
+    pub fn set_revision(&mut self, revision: u64) {
+        self.put_field("$revision", FieldValue::__REVISION__(revision));
+    }
+
+    /// Gets the current process management status of the record.
+    ///
+    /// The status field is located by its type rather than its field code, so this works
+    /// across apps regardless of how the field is named.
+    ///
+    /// # Returns
+    ///
+    /// `Some(status)` if the record has a status field, `None` otherwise
+    /// (e.g. when process management is disabled for the app)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let mut record = Record::new();
+    /// assert!(record.status().is_none());
+    ///
+    /// record.put_field("Status", FieldValue::Status("In progress".to_owned()));
+    /// assert_eq!(record.status(), Some("In progress"));
+    /// ```
+    pub fn status(&self) -> Option<&str> {
+        self.field_values().find_map(|value| match value {
+            FieldValue::Status(status) => Some(status.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Gets the current assignees of the record's process management status.
+    ///
+    /// Like [`Record::status`], the assignee field is located by its type rather than
+    /// its field code.
+    ///
+    /// # Returns
+    ///
+    /// `Some(assignees)` if the record has an assignee field, `None` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::User;
+    /// use kintone::model::record::{Record, FieldValue};
+    ///
+    /// let mut record = Record::new();
+    /// assert!(record.status_assignees().is_none());
+    ///
+    /// let user = User { name: "John Doe".to_owned(), code: "john".to_owned() };
+    /// record.put_field("Assignee", FieldValue::StatusAssignee(vec![user]));
+    /// assert_eq!(record.status_assignees().unwrap()[0].code, "john");
+    /// ```
+    pub fn status_assignees(&self) -> Option<&[User]> {
+        self.field_values().find_map(|value| match value {
+            FieldValue::StatusAssignee(users) => Some(users.as_slice()),
+            _ => None,
+        })
+    }
 }
 
 impl std::fmt::Debug for Record {
@@ -446,19 +761,286 @@ impl Default for Record {
     }
 }
 
-impl<const N: usize, S: Into<String>> From<[(S, FieldValue); N]> for Record {
-    fn from(fields: [(S, FieldValue); N]) -> Self {
-        Self {
-            fields: BTreeMap::from(fields.map(|(k, v)| (k.into(), v))),
-        }
+impl<const N: usize, S: Into<String>> From<[(S, FieldValue); N]> for Record {
+    fn from(fields: [(S, FieldValue); N]) -> Self {
+        Self {
+            fields: BTreeMap::from(fields.map(|(k, v)| (k.into(), v))),
+        }
+    }
+}
+
+/// Builds a record from field codes and values computed at runtime.
+///
+/// If a field code appears more than once, the last value wins.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::model::record::{FieldValue, Record};
+///
+/// let codes = vec!["title", "memo"];
+/// let record: Record = codes.into_iter().map(|code| (code, FieldValue::text(code))).collect();
+/// assert_eq!(record.get("memo"), Some(&FieldValue::text("memo")));
+/// ```
+impl<S: Into<String>> FromIterator<(S, FieldValue)> for Record {
+    fn from_iter<T: IntoIterator<Item = (S, FieldValue)>>(iter: T) -> Self {
+        Self {
+            fields: iter.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+        }
+    }
+}
+
+/// Adds or replaces fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::model::record::{FieldValue, Record};
+///
+/// let mut record = Record::from([("title", FieldValue::text("old"))]);
+/// record.extend(vec![("title".to_owned(), FieldValue::text("new")), ("memo".to_owned(), FieldValue::text("m"))]);
+/// assert_eq!(record.get("title"), Some(&FieldValue::text("new")));
+/// assert_eq!(record.fields().len(), 2);
+/// ```
+impl<S: Into<String>> Extend<(S, FieldValue)> for Record {
+    fn extend<T: IntoIterator<Item = (S, FieldValue)>>(&mut self, iter: T) {
+        self.fields.extend(iter.into_iter().map(|(k, v)| (k.into(), v)));
+    }
+}
+
+/// Consumes the record, yielding its field codes and values in field code order.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::model::record::{FieldValue, Record};
+///
+/// let record = Record::from([("b", FieldValue::text("2")), ("a", FieldValue::text("1"))]);
+/// let codes: Vec<String> = record.into_iter().map(|(code, _)| code).collect();
+/// assert_eq!(codes, ["a", "b"]);
+/// ```
+impl IntoIterator for Record {
+    type Item = (String, FieldValue);
+    type IntoIter = IntoFields;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoFields(self.fields.into_iter())
+    }
+}
+
+impl<'a> IntoIterator for &'a Record {
+    type Item = (&'a str, &'a FieldValue);
+    type IntoIter = Fields<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Fields(self.fields.iter())
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Record {
+    type Item = (&'a str, &'a mut FieldValue);
+    type IntoIter = FieldsMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FieldsMut(self.fields.iter_mut())
+    }
+}
+
+/// An owning iterator over the fields of a [`Record`], created by `into_iter`.
+#[derive(Debug)]
+pub struct IntoFields(std::collections::btree_map::IntoIter<String, FieldValue>);
+
+impl Iterator for IntoFields {
+    type Item = (String, FieldValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IntoFields {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for IntoFields {}
+
+/// An iterator over the fields of a borrowed [`Record`].
+#[derive(Debug, Clone)]
+pub struct Fields<'a>(std::collections::btree_map::Iter<'a, String, FieldValue>);
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = (&'a str, &'a FieldValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k.as_str(), v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Fields<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+impl ExactSizeIterator for Fields<'_> {}
+
+/// An iterator over the fields of a mutably borrowed [`Record`].
+#[derive(Debug)]
+pub struct FieldsMut<'a>(std::collections::btree_map::IterMut<'a, String, FieldValue>);
+
+impl<'a> Iterator for FieldsMut<'a> {
+    type Item = (&'a str, &'a mut FieldValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k.as_str(), v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for FieldsMut<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+impl ExactSizeIterator for FieldsMut<'_> {}
+
+/// Error returned when a record cannot be mapped onto a user-defined type.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum FieldAccessError {
+    /// The record does not contain the field
+    #[error("field {field_code:?} is missing")]
+    Missing { field_code: String },
+
+    /// The field exists but has a different type than expected
+    #[error("field {field_code:?} has type {actual:?}, expected {expected:?}")]
+    TypeMismatch {
+        field_code: String,
+        expected: FieldType,
+        actual: FieldType,
+    },
+
+    /// The field has the expected type but its value cannot be converted
+    #[error("field {field_code:?} has an invalid value: {message}")]
+    InvalidValue { field_code: String, message: String },
+}
+
+impl FieldAccessError {
+    /// Creates a [`FieldAccessError::TypeMismatch`] for the given field value.
+    pub fn type_mismatch(
+        field_code: impl Into<String>,
+        expected: FieldType,
+        actual: &FieldValue,
+    ) -> Self {
+        Self::TypeMismatch {
+            field_code: field_code.into(),
+            expected,
+            actual: actual.field_type(),
+        }
+    }
+}
+
+/// A type that can be constructed from a [`Record`].
+///
+/// Implement this trait to map records of a particular app onto your own struct instead of
+/// reading values out of the record one by one.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::model::record::{FieldAccessError, FieldType, FieldValue, FromRecord, Record};
+///
+/// struct Customer {
+///     name: String,
+///     age: i64,
+/// }
+///
+/// impl FromRecord for Customer {
+///     fn from_record(record: &Record) -> Result<Self, FieldAccessError> {
+///         let name = match record.require("name")? {
+///             FieldValue::SingleLineText(name) => name.clone(),
+///             other => return Err(FieldAccessError::type_mismatch("name", FieldType::SingleLineText, other)),
+///         };
+///         let age = match record.require("age")? {
+///             FieldValue::Number(Some(age)) => age.to_string().parse().map_err(|_| {
+///                 FieldAccessError::InvalidValue {
+///                     field_code: "age".to_owned(),
+///                     message: format!("{age} is not an integer"),
+///                 }
+///             })?,
+///             other => return Err(FieldAccessError::type_mismatch("age", FieldType::Number, other)),
+///         };
+///         Ok(Customer { name, age })
+///     }
+/// }
+///
+/// let record = Record::from([
+///     ("name", FieldValue::SingleLineText("John Doe".to_owned())),
+///     ("age", FieldValue::Number(Some(30.into()))),
+/// ]);
+/// let customer = Customer::from_record(&record)?;
+/// assert_eq!(customer.age, 30);
+/// # Ok::<(), FieldAccessError>(())
+/// ```
+pub trait FromRecord: Sized {
+    /// Constructs a value from the fields of `record`.
+    fn from_record(record: &Record) -> Result<Self, FieldAccessError>;
+}
+
+/// A type that can be converted into a [`Record`].
+///
+/// This is the counterpart of [`FromRecord`], used when adding or updating records.
+///
+/// # Examples
+///
+/// ```rust
+/// use kintone::model::record::{FieldValue, Record, ToRecord};
+///
+/// struct Customer {
+///     name: String,
+///     age: i64,
+/// }
+///
+/// impl ToRecord for Customer {
+///     fn to_record(&self) -> Record {
+///         Record::from([
+///             ("name", FieldValue::SingleLineText(self.name.clone())),
+///             ("age", FieldValue::Number(Some(self.age.into()))),
+///         ])
+///     }
+/// }
+///
+/// let customer = Customer { name: "John Doe".to_owned(), age: 30 };
+/// assert_eq!(customer.to_record().field_codes().count(), 2);
+/// ```
+pub trait ToRecord {
+    /// Converts the value into a record.
+    fn to_record(&self) -> Record;
+}
+
+impl FromRecord for Record {
+    fn from_record(record: &Record) -> Result<Self, FieldAccessError> {
+        Ok(record.clone())
     }
 }
 
-impl FromIterator<(String, FieldValue)> for Record {
-    fn from_iter<T: IntoIterator<Item = (String, FieldValue)>>(iter: T) -> Self {
-        Self {
-            fields: BTreeMap::from_iter(iter),
-        }
+impl ToRecord for Record {
+    fn to_record(&self) -> Record {
+        self.clone()
     }
 }
 
@@ -480,6 +1062,18 @@ impl FromIterator<(String, FieldValue)> for Record {
 /// assert!(FieldType::CreatedTime.is_builtin());
 /// assert!(FieldType::Creator.is_builtin());
 /// ```
+///
+/// Field types convert to and from the names Kintone uses for them, such as `SINGLE_LINE_TEXT`
+/// and `__ID__`, with [`Display`](std::fmt::Display) and [`FromStr`](std::str::FromStr):
+///
+/// ```rust
+/// use kintone::model::record::FieldType;
+///
+/// let field_type: FieldType = "SINGLE_LINE_TEXT".parse().unwrap();
+/// assert_eq!(field_type, FieldType::SingleLineText);
+/// assert_eq!(FieldType::__REVISION__.to_string(), "__REVISION__");
+/// assert!("single_line_text".parse::<FieldType>().is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Assoc)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[func(pub const fn is_builtin(&self) -> bool)]
@@ -615,11 +1209,123 @@ pub enum FieldType {
     __REVISION__,
 }
 
+impl FieldType {
+    /// Every field type, for looking one up by name.
+    const ALL: [FieldType; 33] = [
+        FieldType::Calc,
+        FieldType::Category,
+        FieldType::CheckBox,
+        FieldType::CreatedTime,
+        FieldType::Creator,
+        FieldType::Date,
+        FieldType::Datetime,
+        FieldType::DropDown,
+        FieldType::File,
+        FieldType::Group,
+        FieldType::GroupSelect,
+        FieldType::Hr,
+        FieldType::Label,
+        FieldType::Link,
+        FieldType::Modifier,
+        FieldType::MultiLineText,
+        FieldType::MultiSelect,
+        FieldType::Number,
+        FieldType::OrganizationSelect,
+        FieldType::RadioButton,
+        FieldType::RecordNumber,
+        FieldType::ReferenceTable,
+        FieldType::RichText,
+        FieldType::SingleLineText,
+        FieldType::Spacer,
+        FieldType::Status,
+        FieldType::StatusAssignee,
+        FieldType::Subtable,
+        FieldType::Time,
+        FieldType::UpdatedTime,
+        FieldType::UserSelect,
+        FieldType::__ID__,
+        FieldType::__REVISION__,
+    ];
+
+    /// The name Kintone uses for this field type. Must agree with the serde representation.
+    const fn name(self) -> &'static str {
+        match self {
+            FieldType::Calc => "CALC",
+            FieldType::Category => "CATEGORY",
+            FieldType::CheckBox => "CHECK_BOX",
+            FieldType::CreatedTime => "CREATED_TIME",
+            FieldType::Creator => "CREATOR",
+            FieldType::Date => "DATE",
+            FieldType::Datetime => "DATETIME",
+            FieldType::DropDown => "DROP_DOWN",
+            FieldType::File => "FILE",
+            FieldType::Group => "GROUP",
+            FieldType::GroupSelect => "GROUP_SELECT",
+            FieldType::Hr => "HR",
+            FieldType::Label => "LABEL",
+            FieldType::Link => "LINK",
+            FieldType::Modifier => "MODIFIER",
+            FieldType::MultiLineText => "MULTI_LINE_TEXT",
+            FieldType::MultiSelect => "MULTI_SELECT",
+            FieldType::Number => "NUMBER",
+            FieldType::OrganizationSelect => "ORGANIZATION_SELECT",
+            FieldType::RadioButton => "RADIO_BUTTON",
+            FieldType::RecordNumber => "RECORD_NUMBER",
+            FieldType::ReferenceTable => "REFERENCE_TABLE",
+            FieldType::RichText => "RICH_TEXT",
+            FieldType::SingleLineText => "SINGLE_LINE_TEXT",
+            FieldType::Spacer => "SPACER",
+            FieldType::Status => "STATUS",
+            FieldType::StatusAssignee => "STATUS_ASSIGNEE",
+            FieldType::Subtable => "SUBTABLE",
+            FieldType::Time => "TIME",
+            FieldType::UpdatedTime => "UPDATED_TIME",
+            FieldType::UserSelect => "USER_SELECT",
+            FieldType::__ID__ => "__ID__",
+            FieldType::__REVISION__ => "__REVISION__",
+        }
+    }
+}
+
+impl std::fmt::Display for FieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for FieldType {
+    type Err = ParseFieldTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|field_type| field_type.name() == s)
+            .ok_or_else(|| ParseFieldTypeError(s.to_owned()))
+    }
+}
+
+/// Error returned when a string is not the name of a [`FieldType`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown field type: {0:?}")]
+pub struct ParseFieldTypeError(String);
+
 /// Represents the value of a field in a Kintone record.
 ///
 /// Each variant corresponds to a specific field type and contains the appropriate value type.
 /// The enum is marked as `#[non_exhaustive]` to allow for future field types without breaking changes.
 ///
+/// Every [`FieldType`] that can hold data has a corresponding variant. The layout-only types
+/// ([`FieldType::Group`], [`FieldType::Label`], [`FieldType::Spacer`], [`FieldType::Hr`]) and
+/// [`FieldType::ReferenceTable`] never appear in record payloads, so they have no variant.
+///
+/// When writing records, leaving a field out keeps its current value. To clear a field, send
+/// it with an empty value. For [`UserSelect`](Self::UserSelect),
+/// [`OrganizationSelect`](Self::OrganizationSelect), [`GroupSelect`](Self::GroupSelect), and
+/// other list-valued fields that is an empty `Vec`, which is sent as `"value": []`.
+/// [`Date`](Self::Date), [`Time`](Self::Time), and [`DateTime`](Self::DateTime) are cleared
+/// with `None`, which is sent as `"value": ""`; when reading, both `""` and `null` are
+/// accepted as a blank value.
+///
 /// # Examples
 ///
 /// ```rust
@@ -657,10 +1363,11 @@ pub enum FieldValue {
     Creator(User),
 
     #[assoc(field_type = FieldType::Date)]
-    Date(Option<NaiveDate>),
+    Date(#[serde(with = "empty_as_none")] Option<NaiveDate>),
 
     #[assoc(field_type = FieldType::Datetime)]
-    DateTime(Option<DateTime<FixedOffset>>),
+    #[serde(rename = "DATETIME")]
+    DateTime(#[serde(with = "empty_as_none")] Option<DateTime<FixedOffset>>),
 
     #[assoc(field_type = FieldType::DropDown)]
     DropDown(Option<String>),
@@ -668,7 +1375,7 @@ pub enum FieldValue {
     #[assoc(field_type = FieldType::File)]
     File(Vec<FileBody>),
 
-    #[assoc(field_type = FieldType::File)]
+    #[assoc(field_type = FieldType::GroupSelect)]
     GroupSelect(Vec<Group>),
 
     #[assoc(field_type = FieldType::Link)]
@@ -684,7 +1391,7 @@ pub enum FieldValue {
     MultiSelect(Vec<String>),
 
     #[assoc(field_type = FieldType::Number)]
-    Number(#[serde(with = "stringified_or_empty")] Option<BigDecimal>),
+    Number(#[serde(with = "decimal_or_empty")] Option<BigDecimal>),
 
     #[assoc(field_type = FieldType::OrganizationSelect)]
     OrganizationSelect(Vec<Organization>),
@@ -695,23 +1402,25 @@ pub enum FieldValue {
     #[assoc(field_type = FieldType::RecordNumber)]
     RecordNumber(String),
 
-    #[assoc(field_type = FieldType::ReferenceTable)]
+    #[assoc(field_type = FieldType::RichText)]
     RichText(String),
 
     #[assoc(field_type = FieldType::SingleLineText)]
     SingleLineText(String),
 
+    /// The workflow status. Empty when the app has no workflow enabled.
     #[assoc(field_type = FieldType::Status)]
-    Status(String),
+    Status(#[serde(deserialize_with = "null_as_default::deserialize")] String),
 
+    /// The assignees of the current workflow step. Empty when the app has no workflow enabled.
     #[assoc(field_type = FieldType::StatusAssignee)]
-    StatusAssignee(Vec<User>),
+    StatusAssignee(#[serde(deserialize_with = "null_as_default::deserialize")] Vec<User>),
 
     #[assoc(field_type = FieldType::Subtable)]
     Subtable(Vec<TableRow>),
 
     #[assoc(field_type = FieldType::Time)]
-    Time(Option<NaiveTime>),
+    Time(#[serde(with = "empty_as_none")] Option<NaiveTime>),
 
     #[assoc(field_type = FieldType::UpdatedTime)]
     UpdatedTime(DateTime<FixedOffset>),
@@ -728,6 +1437,289 @@ pub enum FieldValue {
     __REVISION__(#[serde(with = "stringified")] u64),
 }
 
+impl FieldValue {
+    /// Creates a single-line text value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// assert_eq!(FieldValue::text("Project Alpha"), FieldValue::SingleLineText("Project Alpha".to_owned()));
+    /// ```
+    pub fn text(value: impl Into<String>) -> Self {
+        FieldValue::SingleLineText(value.into())
+    }
+
+    /// Creates a multi-line text value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// let value = FieldValue::multi_line_text("line 1\nline 2");
+    /// assert_eq!(value, FieldValue::MultiLineText("line 1\nline 2".to_owned()));
+    /// ```
+    pub fn multi_line_text(value: impl Into<String>) -> Self {
+        FieldValue::MultiLineText(value.into())
+    }
+
+    /// Creates a number value from an integer or a [`BigDecimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bigdecimal::BigDecimal;
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// assert_eq!(FieldValue::number(30), FieldValue::Number(Some(30.into())));
+    ///
+    /// let price: BigDecimal = "19.99".parse().unwrap();
+    /// assert_eq!(FieldValue::number(price.clone()), FieldValue::Number(Some(price)));
+    /// ```
+    pub fn number(value: impl Into<BigDecimal>) -> Self {
+        FieldValue::Number(Some(value.into()))
+    }
+
+    /// Creates a check box value from the selected options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// let value = FieldValue::checkbox(["Yes", "Maybe"]);
+    /// assert_eq!(value, FieldValue::CheckBox(vec!["Yes".to_owned(), "Maybe".to_owned()]));
+    /// ```
+    pub fn checkbox<I>(options: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        FieldValue::CheckBox(options.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a multi-select value from the selected options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// let value = FieldValue::multi_select(vec!["Tokyo".to_owned(), "Osaka".to_owned()]);
+    /// assert_eq!(value, FieldValue::MultiSelect(vec!["Tokyo".to_owned(), "Osaka".to_owned()]));
+    /// ```
+    pub fn multi_select<I>(options: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        FieldValue::MultiSelect(options.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a drop-down value with the selected option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// assert_eq!(FieldValue::drop_down("High"), FieldValue::DropDown(Some("High".to_owned())));
+    /// ```
+    pub fn drop_down(option: impl Into<String>) -> Self {
+        FieldValue::DropDown(Some(option.into()))
+    }
+
+    /// Creates a radio button value with the selected option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// assert_eq!(FieldValue::radio_button("Yes"), FieldValue::RadioButton(Some("Yes".to_owned())));
+    /// ```
+    pub fn radio_button(option: impl Into<String>) -> Self {
+        FieldValue::RadioButton(Some(option.into()))
+    }
+
+    /// Creates a date value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+    /// assert_eq!(FieldValue::date(date), FieldValue::Date(Some(date)));
+    /// ```
+    pub fn date(date: NaiveDate) -> Self {
+        FieldValue::Date(Some(date))
+    }
+
+    /// Returns an empty single-line text value.
+    ///
+    /// Fields that are not included in a record are left unchanged by
+    /// [`update_record`](crate::v1::record::update_record). To clear a field, include it with
+    /// an empty value instead. The same helpers exist for other field types
+    /// ([`FieldValue::empty_number`], [`FieldValue::empty_date`], ...). Fields holding lists,
+    /// such as check boxes or user selections, are cleared with an empty `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kintone::model::record::{FieldValue, Record};
+    ///
+    /// // Clears "memo" and "due_date", leaving the other fields unchanged
+    /// let record = Record::from([
+    ///     ("memo", FieldValue::empty_text()),
+    ///     ("due_date", FieldValue::empty_date()),
+    ///     ("tags", FieldValue::CheckBox(vec![])),
+    /// ]);
+    /// ```
+    pub fn empty_text() -> Self {
+        FieldValue::SingleLineText(String::new())
+    }
+
+    /// Returns an empty multi-line text value. See [`FieldValue::empty_text`].
+    pub fn empty_multi_line_text() -> Self {
+        FieldValue::MultiLineText(String::new())
+    }
+
+    /// Returns an empty number value. See [`FieldValue::empty_text`].
+    pub fn empty_number() -> Self {
+        FieldValue::Number(None)
+    }
+
+    /// Returns an empty date value. See [`FieldValue::empty_text`].
+    pub fn empty_date() -> Self {
+        FieldValue::Date(None)
+    }
+
+    /// Returns an empty time value. See [`FieldValue::empty_text`].
+    pub fn empty_time() -> Self {
+        FieldValue::Time(None)
+    }
+
+    /// Returns an empty date and time value. See [`FieldValue::empty_text`].
+    pub fn empty_datetime() -> Self {
+        FieldValue::DateTime(None)
+    }
+
+    /// Returns an empty drop-down value. See [`FieldValue::empty_text`].
+    pub fn empty_drop_down() -> Self {
+        FieldValue::DropDown(None)
+    }
+
+    /// Parses the value of a calculated field according to its display format.
+    ///
+    /// Kintone returns the result of a calculated field as a string whose format depends on
+    /// the `format` of the field ([`CalcFieldProperty::format`]), which is not included in the
+    /// record. Pass the format obtained from the form field settings, or `None` if the field has
+    /// no format (in which case the value is treated as a number).
+    ///
+    /// Returns `None` if this is not a `Calc` value, if the value is empty (e.g. because the
+    /// expression refers to empty fields), or if it cannot be parsed.
+    ///
+    /// [`CalcFieldProperty::format`]: crate::model::app::field::CalcFieldProperty::format
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, TimeDelta};
+    /// use kintone::model::app::field::DisplayFormat;
+    /// use kintone::model::record::{CalcValue, FieldValue};
+    ///
+    /// let value = FieldValue::Calc("2024-03-31".to_owned());
+    /// assert_eq!(
+    ///     value.parse_calc(Some(&DisplayFormat::Date)),
+    ///     Some(CalcValue::Date(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()))
+    /// );
+    ///
+    /// let value = FieldValue::Calc("49:30".to_owned());
+    /// assert_eq!(
+    ///     value.parse_calc(Some(&DisplayFormat::HourMinute)),
+    ///     Some(CalcValue::Duration(TimeDelta::minutes(49 * 60 + 30)))
+    /// );
+    /// ```
+    pub fn parse_calc(&self, format: Option<&DisplayFormat>) -> Option<CalcValue> {
+        let FieldValue::Calc(value) = self else {
+            return None;
+        };
+        if value.is_empty() {
+            return None;
+        }
+        match format {
+            None | Some(DisplayFormat::Number) | Some(DisplayFormat::NumberDigit) => {
+                value.replace(',', "").parse().ok().map(CalcValue::Number)
+            }
+            Some(DisplayFormat::DateTime) => {
+                DateTime::parse_from_rfc3339(value).ok().map(CalcValue::DateTime)
+            }
+            Some(DisplayFormat::Date) => value.parse().ok().map(CalcValue::Date),
+            Some(DisplayFormat::Time) => {
+                NaiveTime::parse_from_str(value, "%H:%M").ok().map(CalcValue::Time)
+            }
+            Some(DisplayFormat::HourMinute) | Some(DisplayFormat::DayHourMinute) => {
+                parse_hour_minute(value).map(CalcValue::Duration)
+            }
+        }
+    }
+
+    /// Parses the value of a calculated field as a number.
+    ///
+    /// This is a shorthand for [`FieldValue::parse_calc`] for fields with a numeric format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bigdecimal::BigDecimal;
+    /// use kintone::model::record::FieldValue;
+    ///
+    /// let value = FieldValue::Calc("1234.5".to_owned());
+    /// assert_eq!(value.calc_as_number(), Some("1234.5".parse::<BigDecimal>().unwrap()));
+    /// ```
+    pub fn calc_as_number(&self) -> Option<BigDecimal> {
+        match self.parse_calc(None)? {
+            CalcValue::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a duration in `[-]H:MM` format, where the hours may exceed 24.
+fn parse_hour_minute(value: &str) -> Option<chrono::TimeDelta> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+    let (hours, minutes) = value.split_once(':')?;
+    let hours: i64 = hours.parse().ok()?;
+    let minutes: i64 = minutes.parse().ok()?;
+    let delta = chrono::TimeDelta::try_minutes(hours.checked_mul(60)?.checked_add(minutes)?)?;
+    Some(if negative { -delta } else { delta })
+}
+
+/// The result of a calculated field, parsed according to its display format.
+///
+/// See [`FieldValue::parse_calc`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CalcValue {
+    /// A number (`NUMBER` and `NUMBER_DIGIT` formats)
+    Number(BigDecimal),
+    /// A date and time (`DATETIME` format)
+    DateTime(DateTime<FixedOffset>),
+    /// A date (`DATE` format)
+    Date(NaiveDate),
+    /// A time of day (`TIME` format)
+    Time(NaiveTime),
+    /// A length of time (`HOUR_MINUTE` and `DAY_HOUR_MINUTE` formats)
+    Duration(chrono::TimeDelta),
+}
+
 /// Represents a single row in a subtable field.
 ///
 /// A `TableRow` contains a collection of fields indexed by field code,
@@ -834,14 +1826,22 @@ impl<const N: usize, S: Into<String>> From<[(S, FieldValue); N]> for TableRow {
     }
 }
 
-impl FromIterator<(String, FieldValue)> for TableRow {
-    fn from_iter<T: IntoIterator<Item = (String, FieldValue)>>(iter: T) -> Self {
+/// Builds a table row from field codes and values. See the impl for [`Record`].
+impl<S: Into<String>> FromIterator<(S, FieldValue)> for TableRow {
+    fn from_iter<T: IntoIterator<Item = (S, FieldValue)>>(iter: T) -> Self {
         Self {
-            fields: BTreeMap::from_iter(iter),
+            fields: iter.into_iter().map(|(k, v)| (k.into(), v)).collect(),
         }
     }
 }
 
+/// Adds or replaces fields. See the impl for [`Record`].
+impl<S: Into<String>> Extend<(S, FieldValue)> for TableRow {
+    fn extend<T: IntoIterator<Item = (S, FieldValue)>>(&mut self, iter: T) {
+        self.fields.extend(iter.into_iter().map(|(k, v)| (k.into(), v)));
+    }
+}
+
 /// Represents a comment to be posted to a Kintone record.
 ///
 /// This struct is used when creating new comments on records.
@@ -990,6 +1990,7 @@ mod tests {
     use super::*;
 
     const RECORD_JSON1: &str = include_str!("../testdata/record1.json");
+    const RECORD_FILES_JSON: &str = include_str!("../testdata/record_files.json");
 
     fn assert_json_eq(json1: &str, json2: &str) {
         let value1: serde_json::Value = serde_json::from_str(json1).unwrap();
@@ -1003,4 +2004,489 @@ mod tests {
         let serialized = serde_json::to_string_pretty(&record).unwrap();
         assert_json_eq(RECORD_JSON1, &serialized);
     }
+
+    #[test]
+    fn deserialize_record_without_workflow() {
+        let json = include_str!("../testdata/record_no_workflow.json");
+        let record: Record = serde_json::from_str(json).unwrap();
+        for code in ["ステータス", "status_empty"] {
+            assert_eq!(record.get(code), Some(&FieldValue::Status(String::new())), "{code}");
+        }
+        for code in ["作業者", "assignee_empty"] {
+            assert_eq!(record.get(code), Some(&FieldValue::StatusAssignee(vec![])), "{code}");
+        }
+        assert_eq!(record.id(), Some(12));
+        assert_eq!(record.status(), Some(""));
+
+        // Records of apps without workflow usually lack the fields altogether
+        let record: Record =
+            serde_json::from_str(r#"{"$id": {"type": "__ID__", "value": "1"}}"#).unwrap();
+        assert_eq!(record.status(), None);
+        assert_eq!(record.status_assignees(), None);
+    }
+
+    #[test]
+    fn deserialize_and_serialize_file_field() {
+        let record: Record = serde_json::from_str(RECORD_FILES_JSON).unwrap();
+        let Some(FieldValue::File(files)) = record.get("添付ファイル") else {
+            panic!("not a file field: {record:?}");
+        };
+        assert_eq!(
+            files,
+            &[
+                FileBody {
+                    file_key: "201202061155587E339F9067544F1A92C743460E3D12B3297".to_owned(),
+                    content_type: Some("text/plain".to_owned()),
+                    name: Some("17to20_VerupLog (1).txt".to_owned()),
+                    size: Some(23175),
+                },
+                FileBody {
+                    file_key: "201202061155583C763E30196F419E83E91D2E4A03746C273".to_owned(),
+                    content_type: Some("image/png".to_owned()),
+                    name: Some("スクリーンショット.png".to_owned()),
+                    size: Some(1048576),
+                },
+            ]
+        );
+
+        let serialized = serde_json::to_string_pretty(&record).unwrap();
+        assert_json_eq(RECORD_FILES_JSON, &serialized);
+    }
+
+    #[test]
+    fn record_equality_ignores_field_order() {
+        let a = Record::from([("a", FieldValue::text("1")), ("b", FieldValue::text("2"))]);
+        let b = Record::from([("b", FieldValue::text("2")), ("a", FieldValue::text("1"))]);
+        assert_eq!(a, b);
+        assert_ne!(a, Record::from([("a", FieldValue::text("1"))]));
+
+        let record: Record = serde_json::from_str(RECORD_JSON1).unwrap();
+        let roundtrip: Record =
+            serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+        assert_eq!(record, roundtrip);
+    }
+
+    #[test]
+    fn iterate_record_by_value_and_reference() {
+        let mut record = Record::from([("a", FieldValue::text("1")), ("b", FieldValue::text("2"))]);
+        for (_, value) in &mut record {
+            if let FieldValue::SingleLineText(text) = value {
+                text.push('!');
+            }
+        }
+        let borrowed: Vec<(&str, &FieldValue)> = (&record).into_iter().collect();
+        assert_eq!(borrowed, record.fields().collect::<Vec<_>>());
+
+        let owned: Vec<(String, FieldValue)> = record.into_iter().rev().collect();
+        assert_eq!(
+            owned,
+            [
+                ("b".to_owned(), FieldValue::text("2!")),
+                ("a".to_owned(), FieldValue::text("1!"))
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_records() {
+        let original = Record::from([
+            ("$id", FieldValue::__ID__(1)),
+            ("$revision", FieldValue::__REVISION__(5)),
+            ("unchanged", FieldValue::SingleLineText("same".to_owned())),
+            ("changed", FieldValue::SingleLineText("before".to_owned())),
+            ("removed", FieldValue::SingleLineText("gone".to_owned())),
+        ]);
+        let modified = Record::from([
+            ("$id", FieldValue::__ID__(1)),
+            ("$revision", FieldValue::__REVISION__(6)),
+            ("unchanged", FieldValue::SingleLineText("same".to_owned())),
+            ("changed", FieldValue::SingleLineText("after".to_owned())),
+            ("added", FieldValue::Number(Some(42.into()))),
+        ]);
+
+        let diff = original.diff(&modified);
+        assert_eq!(diff.field_codes().collect::<Vec<_>>(), ["added", "changed"]);
+        assert_eq!(diff.get("added"), Some(&FieldValue::Number(Some(42.into()))));
+        assert_eq!(diff.get("changed"), Some(&FieldValue::SingleLineText("after".to_owned())));
+
+        assert_eq!(original.diff(&original).fields().len(), 0);
+    }
+
+    #[test]
+    fn deserialize_blank_temporal_fields() {
+        for blank in [r#""""#, "null"] {
+            let json = format!(
+                r#"{{
+                    "date": {{"type": "DATE", "value": {blank}}},
+                    "datetime": {{"type": "DATETIME", "value": {blank}}},
+                    "time": {{"type": "TIME", "value": {blank}}}
+                }}"#
+            );
+            let record: Record = serde_json::from_str(&json).unwrap();
+            assert_eq!(record.get("date"), Some(&FieldValue::Date(None)));
+            assert_eq!(record.get("datetime"), Some(&FieldValue::DateTime(None)));
+            assert_eq!(record.get("time"), Some(&FieldValue::Time(None)));
+
+            let serialized = serde_json::to_value(&record).unwrap();
+            assert_eq!(serialized["date"]["value"], "");
+            assert_eq!(serialized["datetime"]["value"], "");
+            assert_eq!(serialized["time"]["value"], "");
+            let roundtrip: Record = serde_json::from_value(serialized).unwrap();
+            assert!(roundtrip.fields().eq(record.fields()));
+        }
+    }
+
+    #[test]
+    fn deserialize_temporal_fields() {
+        let json = r#"{
+            "date": {"type": "DATE", "value": "2024-03-15"},
+            "datetime": {"type": "DATETIME", "value": "2024-03-15T09:30:00Z"},
+            "time": {"type": "TIME", "value": "09:30"}
+        }"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            record.get("date"),
+            Some(&FieldValue::Date(NaiveDate::from_ymd_opt(2024, 3, 15)))
+        );
+        assert_eq!(
+            record.get("datetime"),
+            Some(&FieldValue::DateTime(Some("2024-03-15T09:30:00Z".parse().unwrap())))
+        );
+        assert_eq!(record.get("time"), Some(&FieldValue::Time(NaiveTime::from_hms_opt(9, 30, 0))));
+
+        let serialized = serde_json::to_string(&record).unwrap();
+        let roundtrip: Record = serde_json::from_str(&serialized).unwrap();
+        assert!(roundtrip.fields().eq(record.fields()));
+    }
+
+    #[test]
+    fn serialize_user_select_with_code_only() {
+        let record = Record::from([(
+            "users",
+            FieldValue::UserSelect(vec![User::from_code("alice"), User::from_code("bob")]),
+        )]);
+        let serialized = serde_json::to_string(&record).unwrap();
+        assert_json_eq(
+            &serialized,
+            r#"{"users": {"type": "USER_SELECT", "value": [{"code": "alice"}, {"code": "bob"}]}}"#,
+        );
+    }
+
+    #[test]
+    fn deserialize_user_select_with_name() {
+        let json =
+            r#"{"users": {"type": "USER_SELECT", "value": [{"code": "alice", "name": "Alice"}]}}"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        let user = User {
+            name: "Alice".to_owned(),
+            code: "alice".to_owned(),
+        };
+        assert_eq!(record.get("users"), Some(&FieldValue::UserSelect(vec![user])));
+    }
+
+    #[test]
+    fn content_eq_ignores_builtins() {
+        let a = Record::from([
+            ("$revision", FieldValue::__REVISION__(1)),
+            ("name", FieldValue::SingleLineText("John".to_owned())),
+        ]);
+        let b = Record::from([
+            ("$revision", FieldValue::__REVISION__(2)),
+            ("name", FieldValue::SingleLineText("John".to_owned())),
+        ]);
+        assert!(a.content_eq(&b));
+
+        let c = Record::from([("name", FieldValue::SingleLineText("John".to_owned()))]);
+        assert!(a.content_eq(&c));
+
+        let d = Record::from([
+            ("name", FieldValue::SingleLineText("John".to_owned())),
+            ("extra", FieldValue::SingleLineText("".to_owned())),
+        ]);
+        assert!(!a.content_eq(&d));
+    }
+
+    #[test]
+    fn deserialize_category_and_select_fields() {
+        let json = r#"{
+            "categories": {"type": "CATEGORY", "value": ["A", "B"]},
+            "groups": {"type": "GROUP_SELECT", "value": [{"code": "g1", "name": "Group 1"}]},
+            "rich": {"type": "RICH_TEXT", "value": "<b>bold</b>"}
+        }"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            record.get("categories"),
+            Some(&FieldValue::Category(vec!["A".to_owned(), "B".to_owned()]))
+        );
+        assert_eq!(record.get("categories").unwrap().field_type(), FieldType::Category);
+        assert_eq!(record.get("groups").unwrap().field_type(), FieldType::GroupSelect);
+        assert_eq!(record.get("rich").unwrap().field_type(), FieldType::RichText);
+    }
+
+    #[test]
+    fn field_value_type_tag_matches_field_type() {
+        let values = [
+            FieldValue::Calc("1".to_owned()),
+            FieldValue::Category(vec![]),
+            FieldValue::CheckBox(vec![]),
+            FieldValue::CreatedTime("2024-01-01T00:00:00Z".parse().unwrap()),
+            FieldValue::Creator(User::from_code("u")),
+            FieldValue::Date(None),
+            FieldValue::DateTime(None),
+            FieldValue::DropDown(None),
+            FieldValue::File(vec![]),
+            FieldValue::GroupSelect(vec![]),
+            FieldValue::Link("".to_owned()),
+            FieldValue::Modifier(User::from_code("u")),
+            FieldValue::MultiLineText("".to_owned()),
+            FieldValue::MultiSelect(vec![]),
+            FieldValue::Number(None),
+            FieldValue::OrganizationSelect(vec![]),
+            FieldValue::RadioButton(None),
+            FieldValue::RecordNumber("1".to_owned()),
+            FieldValue::RichText("".to_owned()),
+            FieldValue::SingleLineText("".to_owned()),
+            FieldValue::Status("".to_owned()),
+            FieldValue::StatusAssignee(vec![]),
+            FieldValue::Subtable(vec![]),
+            FieldValue::Time(None),
+            FieldValue::UpdatedTime("2024-01-01T00:00:00Z".parse().unwrap()),
+            FieldValue::UserSelect(vec![]),
+            FieldValue::__ID__(1),
+            FieldValue::__REVISION__(1),
+        ];
+        for value in values {
+            let json = serde_json::to_value(&value).unwrap();
+            let field_type: FieldType = serde_json::from_value(json["type"].clone()).unwrap();
+            assert_eq!(field_type, value.field_type(), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn empty_entity_selections_serialize_as_empty_arrays() {
+        let record = Record::from([
+            ("users", FieldValue::UserSelect(vec![])),
+            ("orgs", FieldValue::OrganizationSelect(vec![])),
+            ("groups", FieldValue::GroupSelect(vec![])),
+        ]);
+        let json = record.to_json_value();
+        for code in ["users", "orgs", "groups"] {
+            assert_eq!(json[code]["value"], serde_json::json!([]), "{code}");
+        }
+        assert_eq!(Record::from_json_value(json).unwrap(), record);
+    }
+
+    #[test]
+    fn record_from_reader() {
+        let from_str: Record = serde_json::from_str(RECORD_JSON1).unwrap();
+        let from_reader = Record::from_reader(RECORD_JSON1.as_bytes()).unwrap();
+        assert!(from_reader.fields().eq(from_str.fields()));
+    }
+
+    #[test]
+    fn from_record_reports_missing_and_mismatched_fields() {
+        struct Task {
+            title: String,
+        }
+        impl FromRecord for Task {
+            fn from_record(record: &Record) -> Result<Self, FieldAccessError> {
+                match record.require("title")? {
+                    FieldValue::SingleLineText(title) => Ok(Task {
+                        title: title.clone(),
+                    }),
+                    other => Err(FieldAccessError::type_mismatch(
+                        "title",
+                        FieldType::SingleLineText,
+                        other,
+                    )),
+                }
+            }
+        }
+
+        let record = Record::from([("title", FieldValue::SingleLineText("Write docs".to_owned()))]);
+        assert_eq!(Task::from_record(&record).unwrap().title, "Write docs");
+
+        let err = Task::from_record(&Record::new()).err().unwrap();
+        assert_eq!(
+            err,
+            FieldAccessError::Missing {
+                field_code: "title".to_owned()
+            }
+        );
+
+        let record = Record::from([("title", FieldValue::Number(None))]);
+        let err = Task::from_record(&record).err().unwrap();
+        assert_eq!(
+            err,
+            FieldAccessError::TypeMismatch {
+                field_code: "title".to_owned(),
+                expected: FieldType::SingleLineText,
+                actual: FieldType::Number,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_calc_values() {
+        use crate::model::app::field::DisplayFormat;
+
+        let calc = |s: &str| FieldValue::Calc(s.to_owned());
+        assert_eq!(
+            calc("1,234.5").parse_calc(Some(&DisplayFormat::NumberDigit)),
+            Some(CalcValue::Number("1234.5".parse().unwrap()))
+        );
+        assert_eq!(
+            calc("2024-03-31T09:00:00Z").parse_calc(Some(&DisplayFormat::DateTime)),
+            Some(CalcValue::DateTime(
+                DateTime::parse_from_rfc3339("2024-03-31T09:00:00Z").unwrap()
+            ))
+        );
+        assert_eq!(
+            calc("08:05").parse_calc(Some(&DisplayFormat::Time)),
+            Some(CalcValue::Time(NaiveTime::from_hms_opt(8, 5, 0).unwrap()))
+        );
+        assert_eq!(
+            calc("-1:30").parse_calc(Some(&DisplayFormat::DayHourMinute)),
+            Some(CalcValue::Duration(chrono::TimeDelta::minutes(-90)))
+        );
+        assert_eq!(calc("").parse_calc(None), None);
+        assert_eq!(calc("not a number").calc_as_number(), None);
+        assert_eq!(FieldValue::Number(Some(1.into())).calc_as_number(), None);
+    }
+
+    #[test]
+    fn number_field_preserves_precision() {
+        // 30 significant digits: far beyond what f64 can represent exactly
+        let json = r#"{
+            "amount": {"type": "NUMBER", "value": "123456789012345678901234.567890"},
+            "rate": {"type": "NUMBER", "value": "-0.000000000000000000000000000001"}
+        }"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        let Some(FieldValue::Number(Some(amount))) = record.get("amount") else {
+            panic!("amount is not a number");
+        };
+        assert_eq!(amount, &"123456789012345678901234.567890".parse::<BigDecimal>().unwrap());
+        assert_eq!(amount.to_string(), "123456789012345678901234.567890");
+
+        let serialized = serde_json::to_value(&record).unwrap();
+        assert_eq!(serialized["amount"]["value"], "123456789012345678901234.567890");
+        assert_eq!(serialized["rate"]["value"], "-0.000000000000000000000000000001");
+    }
+
+    #[test]
+    fn field_type_names_round_trip() {
+        for field_type in [
+            FieldType::Calc,
+            FieldType::Datetime,
+            FieldType::StatusAssignee,
+            FieldType::UserSelect,
+            FieldType::__ID__,
+        ] {
+            let name = field_type.to_string();
+            assert_eq!(serde_json::to_value(field_type).unwrap(), name.as_str());
+            assert_eq!(name.parse::<FieldType>(), Ok(field_type));
+        }
+        assert_eq!(FieldType::MultiLineText.to_string(), "MULTI_LINE_TEXT");
+        let err = "TEXT".parse::<FieldType>().unwrap_err();
+        assert_eq!(err.to_string(), r#"unknown field type: "TEXT""#);
+    }
+
+    #[test]
+    fn field_type_names_match_serde() {
+        for field_type in FieldType::ALL {
+            let name = field_type.to_string();
+            assert_eq!(serde_json::to_value(field_type).unwrap(), name.as_str());
+            let deserialized: FieldType = serde_json::from_value(name.clone().into()).unwrap();
+            assert_eq!(deserialized, field_type);
+        }
+    }
+
+    #[test]
+    fn number_field_accepts_digit_grouping() {
+        let number = |value: &str| {
+            let json = format!(r#"{{"n": {{"type": "NUMBER", "value": "{value}"}}}}"#);
+            serde_json::from_str::<Record>(&json).map(|r| r.get("n").cloned())
+        };
+        let expected = |s: &str| Some(FieldValue::Number(Some(s.parse().unwrap())));
+        assert_eq!(number("1,000").unwrap(), expected("1000"));
+        assert_eq!(number("-1,234,567.25").unwrap(), expected("-1234567.25"));
+        assert_eq!(number("999").unwrap(), expected("999"));
+        // Commas that are not thousands separators are not guessed at
+        assert!(number("1,5").is_err());
+        assert!(number("1.000,5").is_err());
+        assert!(number("1,00,000").is_err());
+        assert!(number(",100").is_err());
+    }
+
+    #[test]
+    fn deserialize_datetimes_in_utc_and_offset_forms() {
+        let json = r#"{
+            "created": {"type": "CREATED_TIME", "value": "2024-04-01T00:30:00Z"},
+            "updated": {"type": "UPDATED_TIME", "value": "2024-04-01T09:30:00+09:00"},
+            "due_utc": {"type": "DATETIME", "value": "2024-04-01T00:30:00Z"},
+            "due_jst": {"type": "DATETIME", "value": "2024-04-01T09:30:00+09:00"}
+        }"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        let expected = DateTime::parse_from_rfc3339("2024-04-01T00:30:00Z").unwrap();
+
+        let Some(FieldValue::CreatedTime(created)) = record.get("created") else {
+            panic!("created is not a CREATED_TIME");
+        };
+        let Some(FieldValue::UpdatedTime(updated)) = record.get("updated") else {
+            panic!("updated is not an UPDATED_TIME");
+        };
+        let Some(FieldValue::DateTime(Some(due_utc))) = record.get("due_utc") else {
+            panic!("due_utc is not a DATETIME");
+        };
+        let Some(FieldValue::DateTime(Some(due_jst))) = record.get("due_jst") else {
+            panic!("due_jst is not a DATETIME");
+        };
+        // The instants are equal, and the original offsets are preserved
+        assert_eq!(*created, expected);
+        assert_eq!(*updated, expected);
+        assert_eq!(*due_utc, expected);
+        assert_eq!(*due_jst, expected);
+        assert_eq!(due_utc.offset().local_minus_utc(), 0);
+        assert_eq!(due_jst.offset().local_minus_utc(), 9 * 3600);
+    }
+
+    #[test]
+    fn collect_and_extend_table_row() {
+        let computed: Vec<(String, FieldValue)> =
+            (1..=2).map(|i| (format!("col{i}"), FieldValue::number(i))).collect();
+        let mut row: TableRow = computed.into_iter().collect();
+        row.extend([
+            ("col2", FieldValue::text("two")),
+            ("col3", FieldValue::text("three")),
+        ]);
+
+        let codes: Vec<&str> = row.fields().map(|(code, _)| code).collect();
+        assert_eq!(codes, ["col1", "col2", "col3"]);
+        assert_eq!(row.get("col2"), Some(&FieldValue::text("two")));
+    }
+
+    #[test]
+    fn merge_records() {
+        let defaults = Record::from([
+            ("status", FieldValue::text("open")),
+            ("priority", FieldValue::number(3)),
+        ]);
+        let overrides = Record::from([
+            ("priority", FieldValue::number(1)),
+            ("title", FieldValue::text("Fix")),
+        ]);
+
+        let mut merged = defaults.clone();
+        merged.merge(overrides.clone());
+        assert_eq!(merged.get("status"), Some(&FieldValue::text("open")));
+        assert_eq!(merged.get("priority"), Some(&FieldValue::number(1)));
+        assert_eq!(merged.get("title"), Some(&FieldValue::text("Fix")));
+
+        let mut filled = overrides;
+        filled.merge_if_absent(defaults);
+        assert_eq!(filled.get("status"), Some(&FieldValue::text("open")));
+        assert_eq!(filled.get("priority"), Some(&FieldValue::number(1)));
+        assert_eq!(filled.get("title"), Some(&FieldValue::text("Fix")));
+        assert_eq!(filled.fields().len(), 3);
+    }
 }
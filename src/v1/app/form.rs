@@ -1,13 +1,20 @@
 //! # Kintone App Form API
 //!
 //! This module provides functions for managing form fields in Kintone apps.
-//! It includes operations for adding, updating, and removing fields in the preview environment.
+//! It includes operations for reading fields, and for adding, updating, and removing fields in
+//! the preview environment.
 //!
 //! ## Available Operations
 //!
 //! ### Form Field Management
+//! - [`get_form_fields`] - Get the fields of an app's form
+//! - [`get_field_labels`] - Get the field labels of an app in several languages
 //! - [`add_form_field`] - Add a new field to an app's form in the preview environment
 //!
+//! ### Form Layout Management
+//! - [`get_form_layout`] - Get the layout of an app's form
+//! - [`update_form_layout`] - Update the layout of an app's form in the preview environment
+//!
 //! ## Usage Pattern
 //!
 //! All functions in this module follow the builder pattern:
@@ -39,7 +46,15 @@ use std::collections::HashMap;
 use crate::client::{KintoneClient, RequestBuilder};
 use crate::error::ApiError;
 use crate::internal::serde_helper::{option_stringified, stringified};
-use crate::model::app::field::FieldProperty;
+use crate::internal::validation::check_text_len;
+use crate::model::app::field::{FieldProperty, RelatedApp};
+use crate::model::app::layout::LayoutRow;
+
+/// The maximum number of characters in a field code.
+pub const MAX_FIELD_CODE_LENGTH: usize = 128;
+
+/// The maximum number of characters in a field label.
+pub const MAX_FIELD_LABEL_LENGTH: usize = 128;
 
 /// Adds new fields to an app's form in the preview environment.
 ///
@@ -106,6 +121,7 @@ struct AddFormFieldRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AddFormFieldResponse {
     #[serde(with = "stringified")]
     pub revision: u64,
@@ -113,6 +129,9 @@ pub struct AddFormFieldResponse {
 
 impl AddFormFieldRequest {
     /// Adds a field to be created.
+    ///
+    /// Lookup fields are added with [`FieldProperty::Lookup`]; the property's `field_type`
+    /// selects the type of the copied key value, and its `lookup` describes the related app.
     pub fn field(mut self, field_property: FieldProperty) -> Self {
         self.body
             .properties
@@ -129,14 +148,547 @@ impl AddFormFieldRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     /// Sends the request to add the fields.
     ///
     /// # Returns
     /// A Result containing the AddFormFieldResponse with the new revision number, or an ApiError.
+    /// Field codes and labels that exceed [`MAX_FIELD_CODE_LENGTH`] or [`MAX_FIELD_LABEL_LENGTH`]
+    /// are rejected with [`ApiError::InvalidRequest`] before the request is sent, as are lookup
+    /// and reference table fields whose related app has neither an ID nor a code.
     ///
     /// # Authentication
     /// This API requires app management permissions.
     pub fn send(self, client: &KintoneClient) -> Result<AddFormFieldResponse, ApiError> {
+        for property in self.body.properties.values() {
+            validate_field_property(property)?;
+        }
+        self.builder.send(client, self.body)
+    }
+}
+
+fn validate_field_property(property: &FieldProperty) -> Result<(), ApiError> {
+    check_text_len("field code", property.field_code(), MAX_FIELD_CODE_LENGTH)?;
+    check_text_len("field label", property.label(), MAX_FIELD_LABEL_LENGTH)?;
+    let related_app = match property {
+        FieldProperty::Subtable(subtable) => {
+            for field in subtable.fields.values() {
+                validate_field_property(field)?;
+            }
+            None
+        }
+        FieldProperty::Lookup(lookup) => Some(&lookup.lookup.related_app),
+        FieldProperty::ReferenceTable(reference) => Some(&reference.reference_table.related_app),
+        _ => None,
+    };
+    if related_app.is_some_and(RelatedApp::is_unspecified) {
+        return Err(ApiError::InvalidRequest(format!(
+            "field {:?} must specify its related app by ID or code",
+            property.field_code()
+        )));
+    }
+    Ok(())
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieves the fields of an app's form.
+///
+/// Labels and option names are returned in a single language. Which one is chosen by
+/// [`lang`](GetFormFieldsRequest::lang); without it, Kintone uses the default language of
+/// the app. To collect the labels in several languages at once, use [`get_field_labels`].
+///
+/// By default the fields of the production environment are returned. Fields added with
+/// [`add_form_field`] but not yet deployed are read with
+/// [`preview(true)`](GetFormFieldsRequest::preview).
+///
+/// # Arguments
+/// * `app` - The ID of the app
+/// * `preview` (optional) - Read the fields of the preview environment (default: `false`)
+/// * `lang` (optional) - The language of the labels: `"default"`, `"ja"`, `"en"`, `"zh"`, or `"user"`
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let response = kintone::v1::app::form::get_form_fields(123).lang("en").send(&client)?;
+/// for (code, property) in &response.properties {
+///     println!("{code}: {}", property.label());
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/form/get-form-fields/>
+pub fn get_form_fields(app: u64) -> GetFormFieldsRequest {
+    let builder =
+        RequestBuilder::new(http::Method::GET, "/v1/app/form/fields.json").query("app", app);
+    GetFormFieldsRequest { builder }
+}
+
+#[must_use]
+pub struct GetFormFieldsRequest {
+    builder: RequestBuilder,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetFormFieldsResponse {
+    pub properties: HashMap<String, FieldProperty>,
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl GetFormFieldsRequest {
+    /// Reads the fields of the preview environment instead of the production environment.
+    pub fn preview(mut self, preview: bool) -> Self {
+        let path = if preview {
+            "/v1/preview/app/form/fields.json"
+        } else {
+            "/v1/app/form/fields.json"
+        };
+        self.builder = self.builder.api_path(path);
+        self
+    }
+
+    /// Sets the language of the labels in the response.
+    pub fn lang(mut self, lang: &str) -> Self {
+        self.builder = self.builder.query("lang", lang);
+        self
+    }
+
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    /// Sends the request to get the form fields.
+    pub fn send(self, client: &KintoneClient) -> Result<GetFormFieldsResponse, ApiError> {
+        self.builder.call(client)
+    }
+}
+
+/// Retrieves the label of every field of an app in each of the given languages.
+///
+/// Kintone returns labels in one language per request, so this sends one
+/// [`get_form_fields`] request per language. The result maps each field code to its labels,
+/// keyed by language. Fields inside subtables are included under their own field codes.
+///
+/// # Arguments
+/// * `client` - The client used to send the requests
+/// * `app` - The ID of the app
+/// * `langs` - The languages to retrieve, e.g. `["ja", "en", "zh"]`
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let labels = kintone::v1::app::form::get_field_labels(&client, 123, &["ja", "en", "zh"])?;
+/// for (code, by_lang) in &labels {
+///     if by_lang.get("en") == by_lang.get("ja") {
+///         println!("{code} has no English label");
+///     }
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn get_field_labels(
+    client: &KintoneClient,
+    app: u64,
+    langs: &[&str],
+) -> Result<HashMap<String, HashMap<String, String>>, ApiError> {
+    let mut labels: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for &lang in langs {
+        let response = get_form_fields(app).lang(lang).send(client)?;
+        let mut properties: Vec<&FieldProperty> = response.properties.values().collect();
+        while let Some(property) = properties.pop() {
+            if let FieldProperty::Subtable(subtable) = property {
+                properties.extend(subtable.fields.values());
+            }
+            labels
+                .entry(property.field_code().to_owned())
+                .or_default()
+                .insert(lang.to_owned(), property.label().to_owned());
+        }
+    }
+    Ok(labels)
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieves the layout of an app's form.
+///
+/// The layout describes how fields are arranged into rows, subtables, and groups, and the
+/// size of each element.
+///
+/// Kintone keeps a single layout per app. The mobile form is derived from it by Kintone and
+/// cannot be read or changed separately through the REST API, and the API has no per-device
+/// visibility setting. Whether a group is expanded by default is a property of the group field
+/// ([`GroupFieldProperty::open_group`]), not of the layout.
+///
+/// [`GroupFieldProperty::open_group`]: crate::model::app::field::GroupFieldProperty::open_group
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::app::layout::LayoutRow;
+///
+/// let response = kintone::v1::app::form::get_form_layout(123).send(&client)?;
+/// for row in &response.layout {
+///     if let LayoutRow::Group { code, .. } = row {
+///         println!("Group: {code}");
+///     }
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/form/get-form-layout/>
+pub fn get_form_layout(app: u64) -> GetFormLayoutRequest {
+    let builder =
+        RequestBuilder::new(http::Method::GET, "/v1/app/form/layout.json").query("app", app);
+    GetFormLayoutRequest { builder }
+}
+
+#[must_use]
+pub struct GetFormLayoutRequest {
+    builder: RequestBuilder,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetFormLayoutResponse {
+    pub layout: Vec<LayoutRow>,
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl GetFormLayoutRequest {
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    /// Sends the request to get the form layout.
+    pub fn send(self, client: &KintoneClient) -> Result<GetFormLayoutResponse, ApiError> {
+        self.builder.call(client)
+    }
+}
+
+/// Updates the layout of an app's form in the preview environment.
+///
+/// The given layout replaces the whole layout of the form. Every field of the form must be
+/// placed exactly once; fields can be added beforehand with [`add_form_field`].
+///
+/// **Important**: The changes are made to the preview environment. To apply them to the
+/// production environment, use [`crate::v1::app::settings::deploy_app`].
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::app::layout::{LayoutElement, LayoutRow};
+/// use kintone::model::record::FieldType;
+///
+/// let layout = vec![LayoutRow::Row {
+///     fields: vec![
+///         LayoutElement::field(FieldType::SingleLineText, "title").with_width(300),
+///         LayoutElement::field(FieldType::Date, "due_date"),
+///     ],
+/// }];
+/// let response = kintone::v1::app::form::update_form_layout(123)
+///     .layout(layout)
+///     .send(&client)?;
+/// println!("New revision: {}", response.revision);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/form/update-form-layout/>
+pub fn update_form_layout(app: u64) -> UpdateFormLayoutRequest {
+    let builder = RequestBuilder::new(http::Method::PUT, "/v1/preview/app/form/layout.json");
+    UpdateFormLayoutRequest {
+        builder,
+        body: UpdateFormLayoutRequestBody {
+            app,
+            layout: Vec::new(),
+            revision: None,
+        },
+    }
+}
+
+#[must_use]
+pub struct UpdateFormLayoutRequest {
+    builder: RequestBuilder,
+    body: UpdateFormLayoutRequestBody,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateFormLayoutRequestBody {
+    #[serde(with = "stringified")]
+    app: u64,
+    layout: Vec<LayoutRow>,
+    #[serde(with = "option_stringified", skip_serializing_if = "Option::is_none")]
+    revision: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateFormLayoutResponse {
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl UpdateFormLayoutRequest {
+    /// Sets the new layout of the form.
+    pub fn layout(mut self, layout: Vec<LayoutRow>) -> Self {
+        self.body.layout = layout;
+        self
+    }
+
+    /// Sets the expected revision number for validation.
+    ///
+    /// If provided and the actual revision doesn't match, the request will fail.
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.body.revision = Some(revision);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    /// Sends the request to update the form layout.
+    pub fn send(self, client: &KintoneClient) -> Result<UpdateFormLayoutResponse, ApiError> {
         self.builder.send(client, self.body)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock_client;
+    use crate::middleware::MockHandler;
+    use crate::model::app::field::{
+        FieldMapping, LookupFieldProperty, LookupSetting, ReferenceTable,
+        reference_table_field_property, single_line_text_field_property, subtable_field_property,
+    };
+    use crate::model::record::FieldType;
+
+    #[test]
+    fn validate_field_code_and_label_length() {
+        let ok = single_line_text_field_property("name").label("Name").build();
+        assert!(validate_field_property(&ok.into()).is_ok());
+
+        let long_code = "c".repeat(MAX_FIELD_CODE_LENGTH + 1);
+        let field = single_line_text_field_property(long_code).label("Name").build();
+        let err = validate_field_property(&field.into()).unwrap_err();
+        assert!(matches!(err, ApiError::InvalidRequest(msg) if msg.starts_with("field code")));
+
+        // Labels are counted in characters, not bytes
+        let label = "あ".repeat(MAX_FIELD_LABEL_LENGTH);
+        let field = single_line_text_field_property("name").label(label).build();
+        assert!(validate_field_property(&field.into()).is_ok());
+
+        let inner = single_line_text_field_property("item")
+            .label("i".repeat(MAX_FIELD_LABEL_LENGTH + 1))
+            .build();
+        let table = subtable_field_property("items").add_field("item", inner.into()).build();
+        let err = validate_field_property(&table.into()).unwrap_err();
+        assert!(matches!(err, ApiError::InvalidRequest(msg) if msg.starts_with("field label")));
+    }
+
+    #[test]
+    fn get_field_labels_per_language() {
+        let text = |code: &str, label: &str| {
+            serde_json::json!({
+                "type": "SINGLE_LINE_TEXT", "code": code, "label": label, "noLabel": false,
+                "required": false, "unique": false, "maxLength": "64", "minLength": "0",
+                "defaultValue": "", "expression": "", "hideExpression": false
+            })
+        };
+        let fields = |title: &str, item: &str| {
+            serde_json::json!({
+                "properties": {
+                    "title": text("title", title),
+                    "items": {
+                        "type": "SUBTABLE", "code": "items", "label": "-", "noLabel": false,
+                        "fields": { "item": text("item", item) }
+                    }
+                },
+                "revision": "3"
+            })
+            .to_string()
+        };
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/app/form/fields.json",
+                200,
+                fields("件名", "品目"),
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/app/form/fields.json",
+                200,
+                fields("Title", "Item"),
+            );
+        let client = mock_client(&mock);
+
+        let labels = get_field_labels(&client, 1, &["ja", "en"]).unwrap();
+        assert_eq!(labels["title"]["ja"], "件名");
+        assert_eq!(labels["title"]["en"], "Title");
+        assert_eq!(labels["item"]["ja"], "品目");
+        assert_eq!(labels["item"]["en"], "Item");
+        assert_eq!(labels["items"].len(), 2);
+
+        let uris: Vec<String> = mock.requests().into_iter().map(|r| r.uri).collect();
+        assert!(uris[0].ends_with("lang=ja") && uris[1].ends_with("lang=en"), "{uris:?}");
+    }
+
+    #[test]
+    fn get_form_fields_reads_preview() {
+        let client = mock_client(&MockHandler::new());
+
+        assert_eq!(
+            get_form_fields(5).lang("en").preview(true).debug_url(&client),
+            "https://example.cybozu.com/k/v1/preview/app/form/fields.json?app=5&lang=en"
+        );
+        assert_eq!(
+            get_form_fields(5).preview(true).preview(false).debug_url(&client),
+            "https://example.cybozu.com/k/v1/app/form/fields.json?app=5"
+        );
+    }
+
+    #[test]
+    fn add_lookup_field() {
+        let mock = MockHandler::new().with_response(
+            http::Method::POST,
+            "/k/v1/preview/app/form/fields.json",
+            200,
+            r#"{"revision":"8"}"#,
+        );
+        let client = mock_client(&mock);
+
+        let lookup = LookupFieldProperty {
+            code: "customer".to_owned(),
+            field_type: FieldType::SingleLineText,
+            label: "Customer".to_owned(),
+            lookup: LookupSetting {
+                related_app: RelatedApp {
+                    app: Some(12),
+                    code: None,
+                },
+                related_key_field: "name".to_owned(),
+                field_mappings: vec![FieldMapping {
+                    field: "phone".to_owned(),
+                    related_field: "tel".to_owned(),
+                }],
+                lookup_picker_fields: vec!["name".to_owned()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let response =
+            add_form_field(3).field(FieldProperty::Lookup(lookup)).send(&client).unwrap();
+        assert_eq!(response.revision, 8);
+
+        let body = mock.requests()[0].body_json();
+        let customer = &body["properties"]["customer"];
+        assert_eq!(customer["type"], "SINGLE_LINE_TEXT");
+        assert_eq!(customer["label"], "Customer");
+        assert_eq!(customer["lookup"]["relatedApp"]["app"], "12");
+        assert_eq!(customer["lookup"]["relatedKeyField"], "name");
+        assert_eq!(
+            customer["lookup"]["fieldMappings"],
+            serde_json::json!([{"field": "phone", "relatedField": "tel"}])
+        );
+        assert_eq!(customer["lookup"]["lookupPickerFields"], serde_json::json!(["name"]));
+    }
+
+    #[test]
+    fn add_form_field_requires_related_app() {
+        let mock = MockHandler::new().with_response(
+            http::Method::POST,
+            "/k/v1/preview/app/form/fields.json",
+            200,
+            r#"{"revision":"8"}"#,
+        );
+        let client = mock_client(&mock);
+
+        let lookup = |related_app: RelatedApp| {
+            FieldProperty::Lookup(LookupFieldProperty {
+                code: "customer".to_owned(),
+                field_type: FieldType::SingleLineText,
+                label: "Customer".to_owned(),
+                lookup: LookupSetting {
+                    related_app,
+                    related_key_field: "name".to_owned(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+        };
+        let reference = reference_table_field_property("related").label("Related").build();
+        let empty_code = RelatedApp {
+            app: None,
+            code: Some(String::new()),
+        };
+        for property in [
+            lookup(RelatedApp::default()),
+            lookup(empty_code),
+            reference.into(),
+        ] {
+            let code = property.field_code().to_owned();
+            let err = add_form_field(3).field(property).send(&client).unwrap_err();
+            assert!(
+                matches!(&err, ApiError::InvalidRequest(msg) if msg.contains("related app")),
+                "{code}: {err:?}"
+            );
+        }
+        assert!(mock.requests().is_empty(), "invalid fields must not be sent");
+
+        let reference = reference_table_field_property("related")
+            .label("Related")
+            .reference_table(ReferenceTable {
+                related_app: RelatedApp::by_code("CUSTOMER"),
+                ..Default::default()
+            })
+            .build();
+        add_form_field(3)
+            .field(lookup(RelatedApp::by_id(12)))
+            .field(reference.into())
+            .send(&client)
+            .unwrap();
+        assert_eq!(mock.requests().len(), 1);
+    }
+}
@@ -8,12 +8,22 @@
 //! ### App Management
 //! - [`add_app`] - Create a new app in the preview environment
 //! - [`get_apps`] - Retrieve information about multiple apps
+//! - [`clone_app`] - Create a new app with the form and general settings of an existing app
 //!
 //! ### Settings Management
 //! - [`settings::deploy_app`] - Deploy app settings from preview to production environment
+//! - [`settings::get_app_customize`] - Retrieve JavaScript and CSS customization settings
+//! - [`settings::update_app_customize`] - Update JavaScript and CSS customization settings
 //!
 //! ### Form Management
 //! - [`form::add_form_field`] - Add fields to an app's form in the preview environment
+//! - [`form::get_form_layout`] - Get the layout of an app's form
+//! - [`form::update_form_layout`] - Update the layout of an app's form in the preview environment
+//!
+//! ### Notification Management
+//! - [`notification::get_general_notifications`] / [`notification::update_general_notifications`] - General notification settings
+//! - [`notification::get_per_record_notifications`] / [`notification::update_per_record_notifications`] - Per-record notification settings
+//! - [`notification::get_reminder_notifications`] / [`notification::update_reminder_notifications`] - Reminder notification settings
 //!
 //! ## Usage Pattern
 //!
@@ -40,15 +50,29 @@
 //! **Note**: Some app APIs like [`add_app`] require username/password authentication and cannot use API tokens.
 
 pub mod form;
+pub mod notification;
 pub mod settings;
 
+use std::collections::HashMap;
+
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
 use crate::client::{KintoneClient, RequestBuilder};
 use crate::error::ApiError;
 use crate::internal::serde_helper::{option_stringified, stringified};
+use crate::internal::validation::{check_len, check_text_len};
 use crate::model::User;
+use crate::model::app::layout::{LayoutElement, LayoutRow};
+use crate::model::record::FieldType;
+
+/// The maximum number of apps that can be retrieved by [`get_apps`] in a single request.
+///
+/// This also limits the number of IDs, codes, and space IDs that can be given as filters.
+pub const MAX_APPS_PER_REQUEST: usize = 100;
+
+/// The maximum number of characters in an app name.
+pub const MAX_APP_NAME_LENGTH: usize = 64;
 
 /// Creates a new app in the preview environment.
 ///
@@ -95,7 +119,8 @@ pub fn add_app(name: impl Into<String>) -> AddAppRequest {
 /// Retrieves information about multiple apps.
 ///
 /// This function creates a request to get information about apps that match the specified criteria.
-/// You can filter apps by IDs, codes, names, or space IDs. A maximum of 100 apps can be retrieved per request.
+/// You can filter apps by IDs, codes, names, or space IDs. A maximum of 100 apps
+/// ([`MAX_APPS_PER_REQUEST`]) can be retrieved per request.
 ///
 /// # Optional Parameters
 /// * `ids` - Array of app IDs (up to 100 IDs)
@@ -135,7 +160,10 @@ pub fn add_app(name: impl Into<String>) -> AddAppRequest {
 /// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/get-apps/>
 pub fn get_apps() -> GetAppsRequest {
     let builder = RequestBuilder::new(http::Method::GET, "/v1/apps.json");
-    GetAppsRequest { builder }
+    GetAppsRequest {
+        builder,
+        lengths: Vec::new(),
+    }
 }
 
 #[must_use]
@@ -154,6 +182,7 @@ struct AddAppRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AddAppResponse {
     #[serde(with = "stringified")]
     pub app: u64,
@@ -180,31 +209,72 @@ impl AddAppRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     /// Sends the request to create the app.
     ///
     /// # Returns
     /// A Result containing the AddAppResponse with the app ID and revision, or an ApiError.
     ///
+    /// Returns [`ApiError::InvalidRequest`] without sending the request if the app name is
+    /// longer than [`MAX_APP_NAME_LENGTH`].
+    ///
     /// # Authentication
-    /// This API requires username/password authentication. API tokens cannot be used.
+    /// This API requires username/password authentication. API tokens cannot be used: a
+    /// client constructed with [`Auth::ApiToken`](crate::client::Auth::ApiToken) fails with
+    /// [`ApiError::AuthMethodNotSupported`] without sending the request.
     pub fn send(self, client: &KintoneClient) -> Result<AddAppResponse, ApiError> {
-        self.builder.send(client, self.body)
+        check_text_len("app name", &self.body.name, MAX_APP_NAME_LENGTH)?;
+        if client.uses_api_token() {
+            return Err(ApiError::AuthMethodNotSupported {
+                operation: "adding an app".to_owned(),
+            });
+        }
+        self.builder.send(client, self.body).map_err(hint_password_auth)
+    }
+}
+
+/// Adds the authentication hint to a `Forbidden` error, also when it is wrapped by retries.
+fn hint_password_auth(e: ApiError) -> ApiError {
+    match e {
+        ApiError::Forbidden {
+            message,
+            request_id,
+            ..
+        } => ApiError::Forbidden {
+            message,
+            required_permission: Some(
+                "password authentication (API tokens cannot be used to add apps)".to_owned(),
+            ),
+            request_id,
+        },
+        ApiError::Retried { attempts, last } => ApiError::Retried {
+            attempts,
+            last: Box::new(hint_password_auth(*last)),
+        },
+        e => e,
     }
 }
 
 #[must_use]
 pub struct GetAppsRequest {
     builder: RequestBuilder,
+    lengths: Vec<(&'static str, usize)>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetAppsResponse {
     pub apps: Vec<AppInfo>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AppInfo {
     #[serde(with = "stringified")]
     pub app_id: u64,
@@ -231,6 +301,7 @@ impl GetAppsRequest {
         T: Into<u64>,
     {
         let id_strings: Vec<String> = ids.into_iter().map(|id| id.into().to_string()).collect();
+        self.lengths.push(("app IDs", id_strings.len()));
         self.builder = self.builder.query_array("ids", &id_strings);
         self
     }
@@ -244,6 +315,7 @@ impl GetAppsRequest {
         T: Into<String>,
     {
         let code_strings: Vec<String> = codes.into_iter().map(Into::into).collect();
+        self.lengths.push(("app codes", code_strings.len()));
         self.builder = self.builder.query_array("codes", &code_strings);
         self
     }
@@ -264,6 +336,7 @@ impl GetAppsRequest {
     {
         let space_id_strings: Vec<String> =
             space_ids.into_iter().map(|id| id.into().to_string()).collect();
+        self.lengths.push(("space IDs", space_id_strings.len()));
         self.builder = self.builder.query_array("spaceIds", &space_id_strings);
         self
     }
@@ -280,15 +353,303 @@ impl GetAppsRequest {
     ///
     /// Must be between 1 and 100. Default is 100 if not specified.
     pub fn limit(mut self, limit: u64) -> Self {
+        self.lengths.push(("apps", limit as usize));
         self.builder = self.builder.query("limit", limit.to_string());
         self
     }
 
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     /// Sends the request to get the apps.
     ///
     /// # Returns
     /// A Result containing the GetAppsResponse with app information, or an ApiError.
     pub fn send(self, client: &KintoneClient) -> Result<GetAppsResponse, ApiError> {
+        for (name, len) in self.lengths {
+            check_len(name, len, MAX_APPS_PER_REQUEST)?;
+        }
         self.builder.call(client)
     }
 }
+
+//-----------------------------------------------------------------------------
+
+/// Creates a new app with the form and general settings of an existing app.
+///
+/// Kintone has no REST API for duplicating an app, so this reads the form fields, the form
+/// layout, and the general settings of `source_app`, adds a new app with [`add_app`], and
+/// recreates them on it. The new app is left in the preview environment; deploy it with
+/// [`settings::deploy_app`] once any further changes have been made.
+///
+/// Only the form and the description and theme are copied. Records, permissions, process
+/// management, notifications, and customizations are not. Built-in fields such as the record
+/// number and the creator exist in every app, so they are not added again; where the layout of
+/// the source places them, the layout of the new app places its own built-in fields, whose
+/// codes may differ. Lookup and reference table fields keep referring to the same related apps.
+///
+/// If a step fails after the app has been added, the error is returned and the partially set
+/// up app is left in place.
+///
+/// # Arguments
+/// * `client` - The client used to send the requests
+/// * `source_app` - The ID of the app to copy
+/// * `new_name` - The name of the new app (up to 64 characters)
+///
+/// # Returns
+/// The ID of the new app.
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::v1::app::{clone_app, settings::deploy_app};
+///
+/// let app = clone_app(&client, 123, "Project Management (Tokyo)")?;
+/// deploy_app().app(app, None).send(&client)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Authentication
+/// This requires username/password authentication with app management permissions on the
+/// source app, as [`add_app`] does.
+pub fn clone_app(
+    client: &KintoneClient,
+    source_app: u64,
+    new_name: impl Into<String>,
+) -> Result<u64, ApiError> {
+    let source_settings = settings::get_app_settings(source_app).send(client)?;
+    let source_fields = form::get_form_fields(source_app).send(client)?;
+    let mut layout = form::get_form_layout(source_app).send(client)?.layout;
+
+    let app = add_app(new_name).send(client)?.app;
+
+    let mut properties = source_fields
+        .properties
+        .into_values()
+        .filter(|property| !property.field_type().is_builtin())
+        .peekable();
+    if properties.peek().is_some() {
+        properties
+            .fold(form::add_form_field(app), form::AddFormFieldRequest::field)
+            .send(client)?;
+    }
+
+    // The new app has one of each built-in field, under codes chosen by Kintone.
+    let new_fields = form::get_form_fields(app).preview(true).send(client)?;
+    let builtin_codes: HashMap<FieldType, String> = new_fields
+        .properties
+        .into_values()
+        .filter(|property| property.field_type().is_builtin())
+        .map(|property| (property.field_type(), property.field_code().to_owned()))
+        .collect();
+    rename_builtin_fields(&mut layout, &builtin_codes);
+    form::update_form_layout(app).layout(layout).send(client)?;
+
+    settings::update_app_settings(app)
+        .description(source_settings.description)
+        .theme(source_settings.theme)
+        .send(client)?;
+    Ok(app)
+}
+
+fn rename_builtin_fields(layout: &mut [LayoutRow], codes: &HashMap<FieldType, String>) {
+    let rename = |element: &mut LayoutElement| {
+        if let Some(code) = codes.get(&element.field_type) {
+            element.code = Some(code.clone());
+        }
+    };
+    for row in layout {
+        match row {
+            LayoutRow::Row { fields } | LayoutRow::Subtable { fields, .. } => {
+                fields.iter_mut().for_each(rename);
+            }
+            LayoutRow::Group { layout, .. } => rename_builtin_fields(layout, codes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Auth;
+    use crate::client::mock_client;
+    use crate::middleware::MockHandler;
+
+    #[test]
+    fn add_app_forbidden_hints_at_password_auth() {
+        let mock = MockHandler::new().with_response(
+            http::Method::POST,
+            "/k/v1/preview/app.json",
+            403,
+            r#"{"code":"GAIA_NO01","id":"abc","message":"Using this API token, you cannot run the specified API."}"#,
+        );
+        // Tokens supplied by a provider are only rejected by Kintone itself
+        let client = KintoneClient::builder(
+            "https://example.cybozu.com",
+            Auth::provider(|| Auth::api_token("t".to_owned())),
+        )
+        .with_handler(mock.clone())
+        .build();
+        let err = add_app("Sales").send(&client).unwrap_err();
+        match err {
+            ApiError::Forbidden {
+                required_permission: Some(permission),
+                ..
+            } => assert!(permission.contains("password authentication"), "{permission}"),
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        // The hint is kept when the error went through retries
+        let client = KintoneClient::builder(
+            "https://example.cybozu.com",
+            Auth::provider(|| Auth::api_token("t".to_owned())),
+        )
+        .layer(
+            crate::middleware::RetryLayer::new()
+                .with_max_attempts(2)
+                .with_initial_delay(std::time::Duration::ZERO)
+                .with_should_retry(Box::new(|_, _| true)),
+        )
+        .with_handler(mock)
+        .build();
+        let err = add_app("Sales").send(&client).unwrap_err();
+        assert!(matches!(err, ApiError::Retried { .. }), "{err:?}");
+        match err.final_error() {
+            ApiError::Forbidden {
+                required_permission: Some(permission),
+                ..
+            } => assert!(permission.contains("password authentication"), "{permission}"),
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn add_app_rejects_api_token_client() {
+        let mock = MockHandler::new();
+        let client = mock_client(&mock);
+        let err = add_app("Sales").send(&client).unwrap_err();
+        assert!(matches!(err, ApiError::AuthMethodNotSupported { .. }), "{err:?}");
+        assert!(mock.requests().is_empty());
+    }
+
+    #[test]
+    fn clone_app_recreates_form_and_settings() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/app/settings.json",
+                200,
+                r#"{"name":"Tasks","description":"<b>Team tasks</b>","theme":"RED","revision":"7"}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/app/form/fields.json",
+                200,
+                r#"{"properties":{
+                    "No":{"type":"RECORD_NUMBER","code":"No","label":"No.","noLabel":false},
+                    "title":{"type":"SINGLE_LINE_TEXT","code":"title","label":"Title","noLabel":false,
+                        "required":true,"unique":false,"maxLength":"64","minLength":"0",
+                        "defaultValue":"","expression":"","hideExpression":false}
+                },"revision":"7"}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/app/form/layout.json",
+                200,
+                r#"{"layout":[{"type":"ROW","fields":[
+                    {"type":"RECORD_NUMBER","code":"No"},
+                    {"type":"SINGLE_LINE_TEXT","code":"title","size":{"width":"300"}}
+                ]}],"revision":"7"}"#,
+            )
+            .with_response(
+                http::Method::POST,
+                "/k/v1/preview/app.json",
+                200,
+                r#"{"app":"42","revision":"1"}"#,
+            )
+            .with_response(
+                http::Method::POST,
+                "/k/v1/preview/app/form/fields.json",
+                200,
+                r#"{"revision":"2"}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/preview/app/form/fields.json",
+                200,
+                r#"{"properties":{
+                    "Record_number":{"type":"RECORD_NUMBER","code":"Record_number","label":"Record number","noLabel":false}
+                },"revision":"2"}"#,
+            )
+            .with_response(
+                http::Method::PUT,
+                "/k/v1/preview/app/form/layout.json",
+                200,
+                r#"{"revision":"3"}"#,
+            )
+            .with_response(
+                http::Method::PUT,
+                "/k/v1/preview/app/settings.json",
+                200,
+                r#"{"revision":"4"}"#,
+            );
+        let client = KintoneClient::builder(
+            "https://example.cybozu.com",
+            Auth::password("user".to_owned(), "pass".to_owned()),
+        )
+        .with_handler(mock.clone())
+        .build();
+
+        assert_eq!(clone_app(&client, 1, "Tasks (copy)").unwrap(), 42);
+
+        let requests = mock.requests();
+        let body = |method: http::Method, path: &str| {
+            requests
+                .iter()
+                .find(|r| r.method == method && r.uri.ends_with(path))
+                .unwrap_or_else(|| panic!("no {method} request to {path}"))
+                .body_json()
+        };
+        assert_eq!(body(http::Method::POST, "/v1/preview/app.json")["name"], "Tasks (copy)");
+        let added = body(http::Method::POST, "/v1/preview/app/form/fields.json");
+        assert_eq!(added["app"], "42");
+        assert_eq!(added["properties"].as_object().unwrap().len(), 1);
+        assert_eq!(added["properties"]["title"]["required"], true);
+        let layout = body(http::Method::PUT, "/v1/preview/app/form/layout.json");
+        assert_eq!(layout["layout"][0]["fields"][0]["code"], "Record_number");
+        assert_eq!(layout["layout"][0]["fields"][1]["code"], "title");
+        let settings = body(http::Method::PUT, "/v1/preview/app/settings.json");
+        assert_eq!(settings["app"], "42");
+        assert_eq!(settings["description"], "<b>Team tasks</b>");
+        assert_eq!(settings["theme"], "RED");
+    }
+
+    #[test]
+    fn deserialize_app_info_timestamps() {
+        let json = r#"{
+            "appId": "1",
+            "code": "",
+            "name": "Tasks",
+            "description": "",
+            "spaceId": null,
+            "threadId": null,
+            "createdAt": "2024-04-01T00:30:00.000Z",
+            "creator": {"code": "alice", "name": "Alice"},
+            "modifiedAt": "2024-04-01T09:30:00+09:00",
+            "modifier": {"code": "bob", "name": "Bob"}
+        }"#;
+        let app: AppInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(app.created_at, app.modified_at);
+        assert_eq!(app.created_at.to_rfc3339(), "2024-04-01T00:30:00+00:00");
+    }
+}
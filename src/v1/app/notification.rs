@@ -0,0 +1,568 @@
+//! # Kintone App Notification API
+//!
+//! This module provides functions for managing the notification settings of Kintone apps.
+//!
+//! ## Available Operations
+//!
+//! ### General Notifications
+//! - [`get_general_notifications`] - Retrieve the general notification settings
+//! - [`update_general_notifications`] - Update the general notification settings
+//!
+//! ### Per-Record Notifications
+//! - [`get_per_record_notifications`] - Retrieve the per-record notification settings
+//! - [`update_per_record_notifications`] - Update the per-record notification settings
+//!
+//! ### Reminder Notifications
+//! - [`get_reminder_notifications`] - Retrieve the reminder notification settings
+//! - [`update_reminder_notifications`] - Update the reminder notification settings
+//!
+//! ## Usage Pattern
+//!
+//! All functions in this module follow the builder pattern:
+//!
+//! ```no_run
+//! # use kintone::client::{Auth, KintoneClient};
+//! # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+//! let current = kintone::v1::app::notification::get_per_record_notifications(123)
+//!     .send(&client)?;
+//!
+//! let response = kintone::v1::app::notification::update_per_record_notifications(456)
+//!     .notifications(current.notifications)
+//!     .send(&client)?;
+//! println!("Updated app with revision: {}", response.revision);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! **Note**: The update functions modify the preview environment. Use
+//! [`crate::v1::app::settings::deploy_app`] to apply changes to production.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{KintoneClient, RequestBuilder};
+use crate::error::ApiError;
+use crate::internal::serde_helper::{option_stringified, stringified};
+use crate::model::app::notification::{
+    GeneralNotification, PerRecordNotification, ReminderNotification,
+};
+
+/// Retrieves the general notification settings of an app.
+///
+/// General notifications are sent to the specified recipients when records are added or
+/// edited, comments are posted, statuses change, or records are imported from a file.
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let response = kintone::v1::app::notification::get_general_notifications(123).send(&client)?;
+/// for notification in response.notifications {
+///     println!("{:?}: record_added={}", notification.entity, notification.record_added);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/notification/get-general-notification-settings/>
+pub fn get_general_notifications(app: u64) -> GetGeneralNotificationsRequest {
+    let builder = RequestBuilder::new(http::Method::GET, "/v1/app/notifications/general.json")
+        .query("app", app);
+    GetGeneralNotificationsRequest { builder }
+}
+
+#[must_use]
+pub struct GetGeneralNotificationsRequest {
+    builder: RequestBuilder,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetGeneralNotificationsResponse {
+    pub notifications: Vec<GeneralNotification>,
+    pub notify_to_commenter: bool,
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl GetGeneralNotificationsRequest {
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(self, client: &KintoneClient) -> Result<GetGeneralNotificationsResponse, ApiError> {
+        self.builder.call(client)
+    }
+}
+
+/// Updates the general notification settings of an app in the preview environment.
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::{Entity, EntityType};
+/// use kintone::model::app::notification::GeneralNotification;
+///
+/// let response = kintone::v1::app::notification::update_general_notifications(123)
+///     .notifications(vec![GeneralNotification {
+///         entity: Entity { entity_type: EntityType::GROUP, code: "sales".to_owned() },
+///         include_subs: false,
+///         record_added: true,
+///         record_edited: true,
+///         comment_added: false,
+///         status_changed: false,
+///         file_imported: false,
+///     }])
+///     .notify_to_commenter(true)
+///     .send(&client)?;
+/// println!("New revision: {}", response.revision);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/notification/update-general-notification-settings/>
+pub fn update_general_notifications(app: u64) -> UpdateGeneralNotificationsRequest {
+    let builder =
+        RequestBuilder::new(http::Method::PUT, "/v1/preview/app/notifications/general.json");
+    UpdateGeneralNotificationsRequest {
+        builder,
+        body: UpdateGeneralNotificationsRequestBody {
+            app,
+            notifications: None,
+            notify_to_commenter: None,
+            revision: None,
+        },
+    }
+}
+
+#[must_use]
+pub struct UpdateGeneralNotificationsRequest {
+    builder: RequestBuilder,
+    body: UpdateGeneralNotificationsRequestBody,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateGeneralNotificationsRequestBody {
+    #[serde(with = "stringified")]
+    app: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notifications: Option<Vec<GeneralNotification>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notify_to_commenter: Option<bool>,
+    #[serde(with = "option_stringified", skip_serializing_if = "Option::is_none")]
+    revision: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateGeneralNotificationsResponse {
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl UpdateGeneralNotificationsRequest {
+    /// Sets the recipients and triggers of the general notifications.
+    ///
+    /// This replaces all existing general notification settings.
+    pub fn notifications(mut self, notifications: Vec<GeneralNotification>) -> Self {
+        self.body.notifications = Some(notifications);
+        self
+    }
+
+    /// Sets whether to notify users who have commented on a record when a new comment is posted.
+    pub fn notify_to_commenter(mut self, notify_to_commenter: bool) -> Self {
+        self.body.notify_to_commenter = Some(notify_to_commenter);
+        self
+    }
+
+    /// Sets the expected revision number for validation.
+    ///
+    /// If provided and the actual revision doesn't match, the request will fail.
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.body.revision = Some(revision);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(
+        self,
+        client: &KintoneClient,
+    ) -> Result<UpdateGeneralNotificationsResponse, ApiError> {
+        self.builder.send(client, self.body)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieves the per-record notification settings of an app.
+///
+/// Per-record notifications are sent to the specified recipients when a record matches
+/// a condition.
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let response = kintone::v1::app::notification::get_per_record_notifications(123)
+///     .send(&client)?;
+/// for notification in response.notifications {
+///     println!("{}: {}", notification.title, notification.filter_cond);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/notification/get-per-record-notification-settings/>
+pub fn get_per_record_notifications(app: u64) -> GetPerRecordNotificationsRequest {
+    let builder = RequestBuilder::new(http::Method::GET, "/v1/app/notifications/perRecord.json")
+        .query("app", app);
+    GetPerRecordNotificationsRequest { builder }
+}
+
+#[must_use]
+pub struct GetPerRecordNotificationsRequest {
+    builder: RequestBuilder,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetPerRecordNotificationsResponse {
+    pub notifications: Vec<PerRecordNotification>,
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl GetPerRecordNotificationsRequest {
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(
+        self,
+        client: &KintoneClient,
+    ) -> Result<GetPerRecordNotificationsResponse, ApiError> {
+        self.builder.call(client)
+    }
+}
+
+/// Updates the per-record notification settings of an app in the preview environment.
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::{Entity, EntityType};
+/// use kintone::model::app::notification::{NotificationTarget, PerRecordNotification};
+///
+/// let response = kintone::v1::app::notification::update_per_record_notifications(123)
+///     .notifications(vec![PerRecordNotification {
+///         filter_cond: "priority in (\"High\")".to_owned(),
+///         title: "High priority record".to_owned(),
+///         targets: vec![NotificationTarget {
+///             entity: Entity { entity_type: EntityType::FIELD_ENTITY, code: "assignee".to_owned() },
+///             include_subs: false,
+///         }],
+///     }])
+///     .send(&client)?;
+/// println!("New revision: {}", response.revision);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/notification/update-per-record-notification-settings/>
+pub fn update_per_record_notifications(app: u64) -> UpdatePerRecordNotificationsRequest {
+    let builder =
+        RequestBuilder::new(http::Method::PUT, "/v1/preview/app/notifications/perRecord.json");
+    UpdatePerRecordNotificationsRequest {
+        builder,
+        body: UpdatePerRecordNotificationsRequestBody {
+            app,
+            notifications: Vec::new(),
+            revision: None,
+        },
+    }
+}
+
+#[must_use]
+pub struct UpdatePerRecordNotificationsRequest {
+    builder: RequestBuilder,
+    body: UpdatePerRecordNotificationsRequestBody,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdatePerRecordNotificationsRequestBody {
+    #[serde(with = "stringified")]
+    app: u64,
+    notifications: Vec<PerRecordNotification>,
+    #[serde(with = "option_stringified", skip_serializing_if = "Option::is_none")]
+    revision: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdatePerRecordNotificationsResponse {
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl UpdatePerRecordNotificationsRequest {
+    /// Sets the per-record notifications.
+    ///
+    /// This replaces all existing per-record notification settings. If this is not called,
+    /// all per-record notifications are removed.
+    pub fn notifications(mut self, notifications: Vec<PerRecordNotification>) -> Self {
+        self.body.notifications = notifications;
+        self
+    }
+
+    /// Sets the expected revision number for validation.
+    ///
+    /// If provided and the actual revision doesn't match, the request will fail.
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.body.revision = Some(revision);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(
+        self,
+        client: &KintoneClient,
+    ) -> Result<UpdatePerRecordNotificationsResponse, ApiError> {
+        self.builder.send(client, self.body)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieves the reminder notification settings of an app.
+///
+/// Reminder notifications are sent to the specified recipients at a time relative to the
+/// value of a date or datetime field.
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let response = kintone::v1::app::notification::get_reminder_notifications(123)
+///     .send(&client)?;
+/// println!("Timezone: {}", response.timezone);
+/// for notification in response.notifications {
+///     println!("{}: {} days after {}", notification.title,
+///         notification.timing.days_later, notification.timing.code);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/notification/get-reminder-notification-settings/>
+pub fn get_reminder_notifications(app: u64) -> GetReminderNotificationsRequest {
+    let builder = RequestBuilder::new(http::Method::GET, "/v1/app/notifications/reminder.json")
+        .query("app", app);
+    GetReminderNotificationsRequest { builder }
+}
+
+#[must_use]
+pub struct GetReminderNotificationsRequest {
+    builder: RequestBuilder,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetReminderNotificationsResponse {
+    pub notifications: Vec<ReminderNotification>,
+    pub timezone: String,
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl GetReminderNotificationsRequest {
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(
+        self,
+        client: &KintoneClient,
+    ) -> Result<GetReminderNotificationsResponse, ApiError> {
+        self.builder.call(client)
+    }
+}
+
+/// Updates the reminder notification settings of an app in the preview environment.
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::{Entity, EntityType};
+/// use kintone::model::app::notification::{
+///     NotificationTarget, ReminderNotification, ReminderTiming,
+/// };
+///
+/// let response = kintone::v1::app::notification::update_reminder_notifications(123)
+///     .notifications(vec![ReminderNotification {
+///         timing: ReminderTiming {
+///             code: "due_date".to_owned(),
+///             days_later: -1,
+///             hours_later: None,
+///             time: Some("09:00".to_owned()),
+///         },
+///         filter_cond: "".to_owned(),
+///         title: "Due tomorrow".to_owned(),
+///         targets: vec![NotificationTarget {
+///             entity: Entity { entity_type: EntityType::FIELD_ENTITY, code: "assignee".to_owned() },
+///             include_subs: false,
+///         }],
+///     }])
+///     .timezone("Asia/Tokyo")
+///     .send(&client)?;
+/// println!("New revision: {}", response.revision);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/notification/update-reminder-notification-settings/>
+pub fn update_reminder_notifications(app: u64) -> UpdateReminderNotificationsRequest {
+    let builder =
+        RequestBuilder::new(http::Method::PUT, "/v1/preview/app/notifications/reminder.json");
+    UpdateReminderNotificationsRequest {
+        builder,
+        body: UpdateReminderNotificationsRequestBody {
+            app,
+            notifications: None,
+            timezone: None,
+            revision: None,
+        },
+    }
+}
+
+#[must_use]
+pub struct UpdateReminderNotificationsRequest {
+    builder: RequestBuilder,
+    body: UpdateReminderNotificationsRequestBody,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateReminderNotificationsRequestBody {
+    #[serde(with = "stringified")]
+    app: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notifications: Option<Vec<ReminderNotification>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    #[serde(with = "option_stringified", skip_serializing_if = "Option::is_none")]
+    revision: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateReminderNotificationsResponse {
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl UpdateReminderNotificationsRequest {
+    /// Sets the reminder notifications.
+    ///
+    /// This replaces all existing reminder notification settings.
+    pub fn notifications(mut self, notifications: Vec<ReminderNotification>) -> Self {
+        self.body.notifications = Some(notifications);
+        self
+    }
+
+    /// Sets the timezone used to determine when reminders are sent (e.g. "Asia/Tokyo").
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.body.timezone = Some(timezone.into());
+        self
+    }
+
+    /// Sets the expected revision number for validation.
+    ///
+    /// If provided and the actual revision doesn't match, the request will fail.
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.body.revision = Some(revision);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(
+        self,
+        client: &KintoneClient,
+    ) -> Result<UpdateReminderNotificationsResponse, ApiError> {
+        self.builder.send(client, self.body)
+    }
+}
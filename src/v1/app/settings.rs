@@ -8,6 +8,16 @@
 //! ### Settings Deployment
 //! - [`deploy_app`] - Deploy app settings from preview to production environment
 //! - [`get_app_deploy_status`] - Check the deployment status of app settings
+//! - [`poll_deploy_status`] - Observe the deployment status until it finishes
+//! - [`wait_for_deploy`] - Wait for a deployment to finish and report which app failed
+//!
+//! ### General Settings
+//! - [`get_app_settings`] - Retrieve the name, description, and revision of an app
+//! - [`update_app_settings`] - Update the name, description, and theme of an app
+//!
+//! ### Customization
+//! - [`get_app_customize`] - Retrieve the JavaScript and CSS customization settings
+//! - [`update_app_customize`] - Update the JavaScript and CSS customization settings
 //!
 //! ## Usage Pattern
 //!
@@ -30,13 +40,24 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! ## Optimistic Locking
+//!
+//! Every update of the preview environment accepts the revision the change is based on, and
+//! every read returns the current revision. Reading the revision first (for example with
+//! [`get_app_settings`]) and passing it to the update makes the update fail with an error if
+//! another administrator changed the app in between, rather than overwriting their change.
+//!
 //! **Note**: App settings APIs require app management permissions.
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 use crate::client::{KintoneClient, RequestBuilder};
-use crate::error::ApiError;
+use crate::error::{ApiError, DeployError};
 use crate::internal::serde_helper::{option_stringified, stringified};
+use crate::internal::validation::check_len;
+use crate::model::app::customize::{CustomizeResources, CustomizeScope};
 
 /// Deploys app settings from the preview environment to the production environment.
 ///
@@ -139,6 +160,11 @@ impl DeployAppRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     /// Sends the request to deploy app settings.
     ///
     /// **Note**: This is an asynchronous operation. Use the [`get_app_deploy_status`] API to check
@@ -152,24 +178,35 @@ impl DeployAppRequest {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
 pub struct DeployAppResponse {}
 
+/// The maximum number of apps whose deployment status can be retrieved by
+/// [`get_app_deploy_status`] in a single request.
+pub const MAX_DEPLOY_STATUS_APPS: usize = 300;
+
 /// Checks the deployment status of app settings.
 ///
 /// This function creates a request to check the status of app deployments that were
 /// initiated with the deploy_app API. Since deployment is an asynchronous operation,
 /// this API allows you to monitor the progress and completion of the deployment process.
 ///
-/// - Can check the status of up to 300 apps in a single request
+/// - Can check the status of up to 300 apps ([`MAX_DEPLOY_STATUS_APPS`]) in a single request
 /// - Returns the current status for each app: PROCESSING, SUCCESS, FAIL, or CANCEL
 /// - Guest space apps can only be checked with other apps from the same guest space
 ///
+/// Kintone requires at least one app and has no way to list every deployment in progress.
+/// To watch all apps, e.g. for a dashboard of pending deployments, collect their IDs with
+/// [`get_apps`](crate::v1::app::get_apps), pass them with
+/// [`apps`](GetAppDeployStatusRequest::apps) 300 at a time, and look for [`DeployStatus::Processing`].
+///
 /// **Required Permissions:** App management permissions
 ///
 /// # Arguments
 ///
 /// Use the builder pattern to specify apps to check:
 /// - `app(app_id)` - Add an app ID to check deployment status
+/// - `apps(app_ids)` - Add several app IDs at once
 ///
 /// # Example
 /// ```no_run
@@ -218,12 +255,39 @@ struct GetAppDeployStatusRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetAppDeployStatusResponse {
     pub apps: Vec<AppDeployStatus>,
 }
 
+impl GetAppDeployStatusResponse {
+    /// Returns the deployment failure among these statuses, if any.
+    ///
+    /// An app with [`DeployStatus::Fail`] is reported in preference to one with
+    /// [`DeployStatus::Cancel`], since the latter are cancelled because of the former.
+    pub fn failure(&self) -> Option<DeployError> {
+        deploy_failure(&self.apps)
+    }
+}
+
+fn deploy_failure(apps: &[AppDeployStatus]) -> Option<DeployError> {
+    if let Some(failed) = apps.iter().find(|s| s.status == DeployStatus::Fail) {
+        return Some(DeployError::Failed {
+            app_id: failed.app,
+            reason: None,
+        });
+    }
+    apps.iter()
+        .find(|s| s.status == DeployStatus::Cancel)
+        .map(|cancelled| DeployError::Failed {
+            app_id: cancelled.app,
+            reason: Some("the deployment was cancelled".to_owned()),
+        })
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AppDeployStatus {
     #[serde(with = "stringified")]
     pub app: u64,
@@ -250,8 +314,732 @@ impl GetAppDeployStatusRequest {
         self
     }
 
+    /// Adds several app IDs to check deployment status, after any added before.
+    pub fn apps(mut self, app_ids: impl IntoIterator<Item = u64>) -> Self {
+        self.body.apps.extend(app_ids);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     /// Sends the request to check app deployment status.
+    ///
+    /// Returns [`ApiError::InvalidRequest`] without sending the request if no app was added
+    /// or more than [`MAX_DEPLOY_STATUS_APPS`] were.
     pub fn send(self, client: &KintoneClient) -> Result<GetAppDeployStatusResponse, ApiError> {
+        if self.body.apps.is_empty() {
+            return Err(ApiError::InvalidRequest(
+                "no app given: the deployment status can only be retrieved for specific apps"
+                    .to_owned(),
+            ));
+        }
+        check_len("apps", self.body.apps.len(), MAX_DEPLOY_STATUS_APPS)?;
+        self.builder.send(client, self.body)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Polls the deployment status of apps until every app has finished deploying.
+///
+/// The returned iterator sends [`get_app_deploy_status`] requests and yields each snapshot
+/// of the statuses. The first request is sent immediately and later ones after waiting for
+/// the polling interval (1 second by default). Iteration ends after yielding the first
+/// snapshot in which no app is [`DeployStatus::Processing`], or after yielding an error.
+///
+/// This is meant for tools that want to show progress while a deployment is running.
+/// With no apps there is nothing to wait for, so the iterator yields nothing and sends no
+/// request.
+///
+/// # Arguments
+/// * `client` - The client used to send the requests
+/// * `app_ids` - The IDs of the apps being deployed
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use std::time::Duration;
+/// use kintone::v1::app::settings::{DeployStatus, deploy_app, poll_deploy_status};
+///
+/// deploy_app().app(123, None).app(124, None).send(&client)?;
+///
+/// for snapshot in poll_deploy_status(&client, &[123, 124]).interval(Duration::from_millis(500)) {
+///     let snapshot = snapshot?;
+///     let done = snapshot.iter().filter(|s| s.status != DeployStatus::Processing).count();
+///     println!("{done}/{} apps deployed", snapshot.len());
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn poll_deploy_status<'a>(
+    client: &'a KintoneClient,
+    app_ids: &[u64],
+) -> DeployStatusPoller<'a> {
+    DeployStatusPoller {
+        client,
+        app_ids: app_ids.to_vec(),
+        interval: Duration::from_secs(1),
+        polled: false,
+        finished: false,
+    }
+}
+
+/// An iterator over deployment status snapshots, created by [`poll_deploy_status`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DeployStatusPoller<'a> {
+    client: &'a KintoneClient,
+    app_ids: Vec<u64>,
+    interval: Duration,
+    polled: bool,
+    finished: bool,
+}
+
+impl DeployStatusPoller<'_> {
+    /// Sets the time to wait between two status requests.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Polls until the deployment finishes and returns the final statuses.
+    ///
+    /// # Errors
+    /// Returns [`DeployError::Failed`] naming the failed app if the deployment did not succeed,
+    /// or [`DeployError::Api`] if a status request failed.
+    pub fn wait(self) -> Result<Vec<AppDeployStatus>, DeployError> {
+        let mut last = Vec::new();
+        for snapshot in self {
+            last = snapshot?;
+        }
+        match deploy_failure(&last) {
+            Some(err) => Err(err),
+            None => Ok(last),
+        }
+    }
+}
+
+/// Waits until the deployment of the given apps finishes, failing if any app did not deploy.
+///
+/// This is a shorthand for [`poll_deploy_status`] followed by [`DeployStatusPoller::wait`],
+/// polling once per second. It is meant for CI pipelines that deploy apps and need to stop
+/// with an actionable error when the deployment fails.
+///
+/// # Arguments
+/// * `client` - The client used to send the requests
+/// * `app_ids` - The IDs of the apps being deployed
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::error::DeployError;
+/// use kintone::v1::app::settings::{deploy_app, wait_for_deploy};
+///
+/// deploy_app().app(123, None).app(124, None).send(&client)?;
+/// match wait_for_deploy(&client, &[123, 124]) {
+///     Ok(()) => println!("deployed"),
+///     Err(DeployError::Failed { app_id, .. }) => eprintln!("app {app_id} failed to deploy"),
+///     Err(err) => return Err(err.into()),
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn wait_for_deploy(client: &KintoneClient, app_ids: &[u64]) -> Result<(), DeployError> {
+    poll_deploy_status(client, app_ids).wait().map(|_| ())
+}
+
+impl Iterator for DeployStatusPoller<'_> {
+    type Item = Result<Vec<AppDeployStatus>, ApiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if self.polled {
+            std::thread::sleep(self.interval);
+        }
+        if self.app_ids.is_empty() {
+            self.finished = true;
+            return None;
+        }
+        self.polled = true;
+
+        let req = get_app_deploy_status().apps(self.app_ids.iter().copied());
+        match req.send(self.client) {
+            Ok(resp) => {
+                self.finished = resp.apps.iter().all(|s| s.status != DeployStatus::Processing);
+                Some(Ok(resp.apps))
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieves the general settings of an app, such as its name, description, and revision.
+///
+/// The returned `revision` is the revision of the app's settings as a whole. Passing it to
+/// the `revision` setter of a preview update (for example
+/// [`UpdateAppCustomizeRequest::revision`]) makes the update fail if someone else changed the
+/// app in the meantime, instead of silently overwriting their changes. The preview
+/// environment has its own revision, which is read with
+/// [`preview(true)`](GetAppSettingsRequest::preview).
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+/// * `preview` (optional) - Read the settings of the preview environment (default: `false`)
+/// * `lang` (optional) - The language of the name and description: `"default"`, `"ja"`, `"en"`, `"zh"`, or `"user"`
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::v1::app::settings::{get_app_customize, get_app_settings, update_app_customize};
+///
+/// let revision = get_app_settings(123).preview(true).send(&client)?.revision;
+/// let current = get_app_customize(123).send(&client)?;
+/// update_app_customize(123)
+///     .desktop(current.desktop)
+///     .revision(revision)
+///     .send(&client)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/settings/get-general-settings/>
+pub fn get_app_settings(app: u64) -> GetAppSettingsRequest {
+    GetAppSettingsRequest {
+        app,
+        preview: false,
+        lang: None,
+    }
+}
+
+#[must_use]
+pub struct GetAppSettingsRequest {
+    app: u64,
+    preview: bool,
+    lang: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetAppSettingsResponse {
+    pub name: String,
+    pub description: String,
+    pub theme: String,
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl GetAppSettingsRequest {
+    /// Reads the settings of the preview environment instead of the production environment.
+    pub fn preview(mut self, preview: bool) -> Self {
+        self.preview = preview;
+        self
+    }
+
+    /// Sets the language of the name and description in the response.
+    pub fn lang(mut self, lang: &str) -> Self {
+        self.lang = Some(lang.to_owned());
+        self
+    }
+
+    fn builder(&self) -> RequestBuilder {
+        let path = if self.preview {
+            "/v1/preview/app/settings.json"
+        } else {
+            "/v1/app/settings.json"
+        };
+        let builder = RequestBuilder::new(http::Method::GET, path).query("app", self.app);
+        match &self.lang {
+            Some(lang) => builder.query("lang", lang),
+            None => builder,
+        }
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder().debug_url(client)
+    }
+
+    /// Sends the request to get the general settings.
+    pub fn send(self, client: &KintoneClient) -> Result<GetAppSettingsResponse, ApiError> {
+        self.builder().call(client)
+    }
+}
+
+/// Updates the general settings of an app in the preview environment.
+///
+/// This function creates a request to change the name, description, and color theme of an
+/// app. Settings that are not specified are left unchanged.
+///
+/// **Important**: The changes are made to the preview environment. To apply them to the
+/// production environment, use [`deploy_app`].
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let response = kintone::v1::app::settings::update_app_settings(123)
+///     .description("Tracks the projects of the Tokyo office.")
+///     .theme("BLUE")
+///     .send(&client)?;
+///
+/// kintone::v1::app::settings::deploy_app()
+///     .app(123, Some(response.revision))
+///     .send(&client)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/settings/update-general-settings/>
+pub fn update_app_settings(app: u64) -> UpdateAppSettingsRequest {
+    let builder = RequestBuilder::new(http::Method::PUT, "/v1/preview/app/settings.json");
+    UpdateAppSettingsRequest {
+        builder,
+        body: UpdateAppSettingsRequestBody {
+            app,
+            name: None,
+            description: None,
+            theme: None,
+            revision: None,
+        },
+    }
+}
+
+#[must_use]
+pub struct UpdateAppSettingsRequest {
+    builder: RequestBuilder,
+    body: UpdateAppSettingsRequestBody,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateAppSettingsRequestBody {
+    #[serde(with = "stringified")]
+    app: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme: Option<String>,
+    #[serde(with = "option_stringified", skip_serializing_if = "Option::is_none")]
+    revision: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateAppSettingsResponse {
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl UpdateAppSettingsRequest {
+    /// Sets the name of the app.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.body.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the app. HTML is allowed.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.body.description = Some(description.into());
+        self
+    }
+
+    /// Sets the color theme of the app, such as `"WHITE"` or `"BLUE"`.
+    pub fn theme(mut self, theme: impl Into<String>) -> Self {
+        self.body.theme = Some(theme.into());
+        self
+    }
+
+    /// Sets the expected revision number for validation.
+    ///
+    /// If provided and the actual revision doesn't match, the request will fail.
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.body.revision = Some(revision);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    /// Sends the request to update the general settings.
+    pub fn send(self, client: &KintoneClient) -> Result<UpdateAppSettingsResponse, ApiError> {
+        self.builder.send(client, self.body)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieves the JavaScript and CSS customization settings of an app.
+///
+/// This function creates a request to get the customization settings applied in the
+/// production environment, including the files used on desktop and mobile and the
+/// range of users the customization applies to.
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let response = kintone::v1::app::settings::get_app_customize(123).send(&client)?;
+/// println!("Scope: {:?}", response.scope);
+/// for resource in response.desktop.js {
+///     println!("Desktop JS: {resource:?}");
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/settings/get-customization/>
+pub fn get_app_customize(app: u64) -> GetAppCustomizeRequest {
+    let builder =
+        RequestBuilder::new(http::Method::GET, "/v1/app/customize.json").query("app", app);
+    GetAppCustomizeRequest { builder }
+}
+
+#[must_use]
+pub struct GetAppCustomizeRequest {
+    builder: RequestBuilder,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetAppCustomizeResponse {
+    pub scope: CustomizeScope,
+    pub desktop: CustomizeResources,
+    pub mobile: CustomizeResources,
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl GetAppCustomizeRequest {
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    /// Sends the request to get the customization settings.
+    pub fn send(self, client: &KintoneClient) -> Result<GetAppCustomizeResponse, ApiError> {
+        self.builder.call(client)
+    }
+}
+
+/// Updates the JavaScript and CSS customization settings of an app in the preview environment.
+///
+/// This function creates a request to replace the customization settings of an app.
+/// Settings that are not specified are left unchanged. To use files built locally, upload them
+/// with [`crate::v1::file::upload`] first and reference the returned file keys.
+///
+/// **Important**: The changes are made to the preview environment. To apply them to the
+/// production environment, use [`deploy_app`].
+///
+/// **Required Permissions:** App management permissions
+///
+/// # Arguments
+/// * `app` - The ID of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::app::customize::{CustomizeResource, CustomizeResources, CustomizeScope};
+///
+/// let bundle = std::fs::File::open("dist/bundle.js")?;
+/// let uploaded = kintone::v1::file::upload("bundle.js").send(&client, bundle)?;
+///
+/// let response = kintone::v1::app::settings::update_app_customize(123)
+///     .scope(CustomizeScope::All)
+///     .desktop(CustomizeResources {
+///         js: vec![
+///             CustomizeResource::url("https://cdn.example.com/lib.js"),
+///             CustomizeResource::file(uploaded.file_key),
+///         ],
+///         css: vec![],
+///     })
+///     .send(&client)?;
+///
+/// kintone::v1::app::settings::deploy_app()
+///     .app(123, Some(response.revision))
+///     .send(&client)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/apps/settings/update-customization/>
+pub fn update_app_customize(app: u64) -> UpdateAppCustomizeRequest {
+    let builder = RequestBuilder::new(http::Method::PUT, "/v1/preview/app/customize.json");
+    UpdateAppCustomizeRequest {
+        builder,
+        body: UpdateAppCustomizeRequestBody {
+            app,
+            scope: None,
+            desktop: None,
+            mobile: None,
+            revision: None,
+        },
+    }
+}
+
+#[must_use]
+pub struct UpdateAppCustomizeRequest {
+    builder: RequestBuilder,
+    body: UpdateAppCustomizeRequestBody,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateAppCustomizeRequestBody {
+    #[serde(with = "stringified")]
+    app: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<CustomizeScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    desktop: Option<CustomizeResources>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mobile: Option<CustomizeResources>,
+    #[serde(with = "option_stringified", skip_serializing_if = "Option::is_none")]
+    revision: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateAppCustomizeResponse {
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl UpdateAppCustomizeRequest {
+    /// Sets the range of users to which the customization is applied.
+    pub fn scope(mut self, scope: CustomizeScope) -> Self {
+        self.body.scope = Some(scope);
+        self
+    }
+
+    /// Sets the JavaScript and CSS files applied on desktop.
+    pub fn desktop(mut self, desktop: CustomizeResources) -> Self {
+        self.body.desktop = Some(desktop);
+        self
+    }
+
+    /// Sets the JavaScript and CSS files applied on mobile.
+    pub fn mobile(mut self, mobile: CustomizeResources) -> Self {
+        self.body.mobile = Some(mobile);
+        self
+    }
+
+    /// Sets the expected revision number for validation.
+    ///
+    /// If provided and the actual revision doesn't match, the request will fail.
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.body.revision = Some(revision);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    /// Sends the request to update the customization settings.
+    pub fn send(self, client: &KintoneClient) -> Result<UpdateAppCustomizeResponse, ApiError> {
         self.builder.send(client, self.body)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock_client;
+    use crate::middleware::MockHandler;
+
+    #[test]
+    fn deploy_status_requires_apps() {
+        let mock = MockHandler::new().with_response(
+            http::Method::GET,
+            "/k/v1/preview/app/deploy.json",
+            200,
+            r#"{"apps":[{"app":"1","status":"SUCCESS"},{"app":"2","status":"PROCESSING"}]}"#,
+        );
+        let client = mock_client(&mock);
+
+        let err = get_app_deploy_status().send(&client).unwrap_err();
+        assert!(matches!(err, ApiError::InvalidRequest(_)), "{err:?}");
+        let err = get_app_deploy_status().apps(0..301).send(&client).unwrap_err();
+        assert!(matches!(err, ApiError::InvalidRequest(_)), "{err:?}");
+        assert!(mock.requests().is_empty());
+
+        let resp = get_app_deploy_status().app(1).apps([2]).send(&client).unwrap();
+        assert_eq!(resp.apps.len(), 2);
+        assert_eq!(mock.requests()[0].body_json()["apps"], serde_json::json!([1, 2]));
+
+        assert!(poll_deploy_status(&client, &[]).wait().unwrap().is_empty());
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[test]
+    fn poll_deploy_status_stops_when_finished() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/preview/app/deploy.json",
+                200,
+                r#"{"apps":[{"app":"1","status":"PROCESSING"},{"app":"2","status":"PROCESSING"}]}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/preview/app/deploy.json",
+                200,
+                r#"{"apps":[{"app":"1","status":"SUCCESS"},{"app":"2","status":"PROCESSING"}]}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/preview/app/deploy.json",
+                200,
+                r#"{"apps":[{"app":"1","status":"SUCCESS"},{"app":"2","status":"SUCCESS"}]}"#,
+            );
+        let client = mock_client(&mock);
+
+        let snapshots: Vec<Vec<DeployStatus>> = poll_deploy_status(&client, &[1, 2])
+            .interval(Duration::ZERO)
+            .map(|s| s.unwrap().into_iter().map(|s| s.status).collect())
+            .collect();
+        assert_eq!(
+            snapshots,
+            vec![
+                vec![DeployStatus::Processing, DeployStatus::Processing],
+                vec![DeployStatus::Success, DeployStatus::Processing],
+                vec![DeployStatus::Success, DeployStatus::Success],
+            ]
+        );
+        assert_eq!(mock.requests().len(), 3);
+        assert_eq!(mock.requests()[0].body_json()["apps"], serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn wait_for_deploy_names_the_failed_app() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/preview/app/deploy.json",
+                200,
+                r#"{"apps":[{"app":"1","status":"PROCESSING"},{"app":"2","status":"PROCESSING"}]}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/preview/app/deploy.json",
+                200,
+                r#"{"apps":[{"app":"1","status":"CANCEL"},{"app":"2","status":"FAIL"}]}"#,
+            );
+        let client = mock_client(&mock);
+
+        let err = poll_deploy_status(&client, &[1, 2])
+            .interval(Duration::ZERO)
+            .wait()
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                DeployError::Failed {
+                    app_id: 2,
+                    reason: None
+                }
+            ),
+            "{err:?}"
+        );
+
+        let cancelled = GetAppDeployStatusResponse {
+            apps: vec![AppDeployStatus {
+                app: 3,
+                status: DeployStatus::Cancel,
+            }],
+        };
+        assert!(matches!(
+            cancelled.failure(),
+            Some(DeployError::Failed {
+                app_id: 3,
+                reason: Some(_)
+            })
+        ));
+    }
+
+    #[test]
+    fn update_app_settings_sends_only_given_settings() {
+        let mock = MockHandler::new().with_response(
+            http::Method::PUT,
+            "/k/v1/preview/app/settings.json",
+            200,
+            r#"{"revision":"25"}"#,
+        );
+        let client = mock_client(&mock);
+
+        let response = update_app_settings(5)
+            .description("<b>Projects</b>")
+            .revision(24)
+            .send(&client)
+            .unwrap();
+        assert_eq!(response.revision, 25);
+        assert_eq!(
+            mock.requests()[0].body_json(),
+            serde_json::json!({"app": "5", "description": "<b>Projects</b>", "revision": "24"})
+        );
+    }
+
+    #[test]
+    fn get_app_settings_reads_preview_revision() {
+        let mock = MockHandler::new().with_response(
+            http::Method::GET,
+            "/k/v1/preview/app/settings.json",
+            200,
+            r#"{"name":"Projects","description":"","icon":{"type":"PRESET","key":"APP72"},
+                "theme":"WHITE","revision":"24"}"#,
+        );
+        let client = mock_client(&mock);
+
+        let settings = get_app_settings(5).preview(true).lang("en").send(&client).unwrap();
+        assert_eq!(settings.revision, 24);
+        assert_eq!(settings.name, "Projects");
+        assert!(
+            mock.requests()[0]
+                .uri
+                .ends_with("/k/v1/preview/app/settings.json?app=5&lang=en")
+        );
+        assert_eq!(
+            get_app_settings(5).debug_url(&client),
+            "https://example.cybozu.com/k/v1/app/settings.json?app=5"
+        );
+    }
+}
@@ -5,7 +5,7 @@
 //! or as attachments in Kintone records.
 
 use serde::Deserialize;
-use std::io::Read;
+use std::io::{Read, Write};
 
 use crate::client::{DownloadRequest, KintoneClient, UploadRequest};
 use crate::error::ApiError;
@@ -51,6 +51,7 @@ pub struct UploadFileRequest {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct UploadFileResponse {
     pub file_key: String,
 }
@@ -63,6 +64,11 @@ impl UploadFileRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.upload_request.debug_url(client)
+    }
+
     /// Sends the upload request to the Kintone API with file content.
     ///
     /// # Arguments
@@ -119,13 +125,67 @@ pub struct DownloadFileRequest {
 }
 
 impl DownloadFileRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.download_request.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<DownloadFileResponse, ApiError> {
         let resp = self.download_request.send(client)?;
         Ok(DownloadFileResponse {
             mime_type: resp.mime_type,
+            name: None,
             content: resp.content,
         })
     }
+
+    /// Downloads the file and writes its content to `writer`.
+    ///
+    /// The content is streamed with [`std::io::copy`], so the file is never held in memory
+    /// as a whole. Errors that occur while reading the response or writing to `writer` are
+    /// returned as [`ApiError::Io`].
+    ///
+    /// # Arguments
+    /// * `client` - The KintoneClient to use for the API call
+    /// * `writer` - The destination of the file content
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// use std::fs::File;
+    ///
+    /// let mut output_file = File::create("./downloaded_file.pdf")?;
+    /// let response = kintone::v1::file::download("file_key_from_upload")
+    ///     .to_writer(&client, &mut output_file)?;
+    /// println!("Wrote {} bytes ({:?})", response.bytes_written, response.mime_type);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_writer<W: Write + ?Sized>(
+        self,
+        client: &KintoneClient,
+        writer: &mut W,
+    ) -> Result<DownloadToWriterResponse, ApiError> {
+        let mut resp = self.download_request.send(client)?;
+        let bytes_written = std::io::copy(&mut resp.content, writer)?;
+        writer.flush()?;
+        Ok(DownloadToWriterResponse {
+            mime_type: resp.mime_type,
+            bytes_written,
+        })
+    }
+}
+
+/// Response of [`DownloadFileRequest::to_writer`].
+///
+/// # Fields
+/// * `mime_type` - The MIME type of the downloaded file
+/// * `bytes_written` - The number of bytes written to the writer
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DownloadToWriterResponse {
+    pub mime_type: Option<mime::Mime>,
+    pub bytes_written: u64,
 }
 
 /// Response containing downloaded file data from Kintone.
@@ -135,9 +195,13 @@ impl DownloadFileRequest {
 ///
 /// # Fields
 /// * `mime_type` - The MIME type of the downloaded file (e.g., "application/pdf", "image/jpeg")
+/// * `name` - The original filename, when the download was started from a
+///   [`FileBody`](crate::model::FileBody) that carries one
 /// * `content` - A readable stream containing the file data
+#[non_exhaustive]
 pub struct DownloadFileResponse {
     pub mime_type: Option<mime::Mime>,
+    pub name: Option<String>,
     pub content: Box<dyn Read + Send + Sync + 'static>,
 }
 
@@ -145,6 +209,7 @@ impl std::fmt::Debug for DownloadFileResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DownloadFileResponse")
             .field("mime_type", &self.mime_type)
+            .field("name", &self.name)
             .finish()
     }
 }
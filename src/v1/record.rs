@@ -7,14 +7,29 @@
 //!
 //! ### Record Operations
 //! - [`get_record`] - Retrieve a single record by ID
-//! - [`get_records`] - Retrieve multiple records with filtering and pagination
+//! - [`try_get_record`] - Retrieve a single record by ID, or `None` if it does not exist
+//! - [`get_records`] - Retrieve multiple records with filtering and pagination, optionally
+//!   [streaming](GetRecordsRequest::stream) them one at a time
+//! - [`get_records_parallel`] - Retrieve all matching records using concurrent requests
+//! - [`count_records`] - Count the records matching a query
+//! - [`bind_query`] - Build a query string with safely escaped values
+//! - [`id_in`], [`record_number_in`] - Build a condition matching specific records
+//! - [`get_record_by_code`], [`get_records_by_code`] - Same as above, identifying the app by its app code
 //! - [`add_record`] - Create a new record
 //! - [`add_records`] - Create multiple records at once
+//! - [`parallel_add_records`] - Create any number of records using concurrent requests
 //! - [`update_record`] - Update an existing record
 //! - [`update_records`] - Update multiple records at once
+//! - [`upsert_record`] - Insert a record or update it by unique key
+//! - [`upsert_records`] - Insert or update multiple records by unique key at once
 //! - [`delete_records`] - Delete multiple records at once
+//! - [`delete_all_matching`] - Delete every record matching a query
+//! - [`BatchOutcome`] - Per-chunk results of the chunked helpers when carrying on past failures
 //! - [`bulk_request`] - Execute multiple API operations atomically
 //!
+//! ### Attachments
+//! - [`download_attachments`] - Download every file in a file field of a record
+//!
 //! ### Comment Operations
 //! - [`get_comments`] - Retrieve comments for a record
 //! - [`add_comment`] - Add a new comment to a record
@@ -23,22 +38,81 @@
 //! ### Workflow Operations
 //! - [`update_assignees`] - Update the assignees of a record
 //! - [`update_status`] - Update the workflow status of a record
+//! - [`update_statuses`] - Update the workflow statuses of multiple records at once
 //!
 //! ### Cursor-based Pagination
 //! - [`create_cursor`] - Create a cursor for efficient pagination through large datasets
 //! - [`get_records_by_cursor`] - Retrieve records using a cursor
 //! - [`delete_cursor`] - Delete a cursor to free up resources
+//! - [`get_all_records`] - Iterate over all matching records, fetching them page by page through a cursor
+//!
+//! ## Limits
+//!
+//! The API limits are exposed as constants: [`MAX_RECORDS_PER_REQUEST`],
+//! [`MAX_RECORDS_PER_PAGE`], and [`MAX_BULK_REQUESTS`]. Requests exceeding them are
+//! rejected with [`ApiError::InvalidRequest`] before being sent.
+//!
+//! ## Bulk Import and Export
+//!
+//! The CSV import and export available in the kintone UI has no REST counterpart, so there is
+//! no job-based endpoint for moving millions of rows at once. For large exports, use
+//! [`get_all_records`] or [`get_records_parallel`], which fetch [`MAX_RECORDS_PER_PAGE`]
+//! records per round-trip. For large imports, split the rows into chunks of
+//! [`MAX_RECORDS_PER_REQUEST`] and send them with [`add_records`], or group up to
+//! [`MAX_BULK_REQUESTS`] such chunks into one [`bulk_request`].
+//!
+//! ## Read-after-write Consistency
+//!
+//! The record APIs have no cache-control or consistency parameters, so the read builders
+//! have no such setting: Kintone answers reads from the same data the writes go to, and a
+//! record is visible to [`get_record`] and [`get_records`] as soon as the write that created
+//! or changed it has returned. The client does not cache responses either, unless a
+//! [`CacheLayer`](crate::middleware::CacheLayer) is installed, and that layer revalidates every
+//! cached response with the server before using it. To confirm that a read reflects a given
+//! write, compare the record's `$revision` with the revision returned by the write.
+
+use std::io::Read;
 
 use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 
 use crate::client::{KintoneClient, RequestBuilder};
-use crate::error::ApiError;
+use crate::error::{ApiError, KintoneErrorCode};
+use crate::internal::json_stream::JsonArrayStream;
 use crate::internal::serde_helper::{option_stringified, stringified};
+use crate::internal::validation::check_len;
 use crate::model::{
-    Order,
-    record::{PostedRecordComment, Record, RecordComment},
+    FileBody, Order,
+    record::{FieldAccessError, FieldType, FieldValue, PostedRecordComment, Record, RecordComment},
 };
+use crate::v1::file::DownloadFileResponse;
+
+/// The maximum number of records that can be added, updated, or deleted in a single request.
+///
+/// Applies to [`add_records`], [`update_records`], [`delete_records`], and [`update_statuses`].
+/// Requests exceeding this limit are rejected without being sent to the server.
+///
+/// # Example
+/// ```
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::api_token("token".to_owned()));
+/// use kintone::error::ApiError;
+/// use kintone::model::record::Record;
+/// use kintone::v1::record::{MAX_RECORDS_PER_REQUEST, add_records};
+///
+/// let records = vec![Record::new(); MAX_RECORDS_PER_REQUEST + 1];
+/// let result = add_records(123, records).send(&client);
+/// assert!(matches!(result, Err(ApiError::InvalidRequest(_))));
+/// ```
+pub const MAX_RECORDS_PER_REQUEST: usize = 100;
+
+/// The maximum number of records that can be retrieved in a single request.
+///
+/// Applies to the `limit` clause of a [`get_records`] query and the page size of [`create_cursor`].
+pub const MAX_RECORDS_PER_PAGE: usize = 500;
+
+/// The maximum number of requests that can be executed in a single [`bulk_request`].
+pub const MAX_BULK_REQUESTS: usize = 20;
 
 /// Retrieves a single record from a Kintone app by its ID.
 ///
@@ -48,12 +122,19 @@ use crate::model::{
 /// # Arguments
 /// * `app` - The ID of the Kintone app
 /// * `id` - The ID of the record to retrieve
+/// * `fields` (optional) - The field codes to keep in the returned record
+///
+/// The endpoint has no server-side field selection, so `fields` is applied after the
+/// response is parsed. It keeps the returned [`Record`] small but does not reduce the
+/// amount of data transferred; use [`get_records`] with an `$id` query when that matters.
 ///
 /// # Example
 /// ```no_run
 /// # use kintone::client::{Auth, KintoneClient};
 /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
-/// let response = kintone::v1::record::get_record(123, 456).send(&client)?;
+/// let response = kintone::v1::record::get_record(123, 456)
+///     .fields(&["name", "email"])
+///     .send(&client)?;
 /// println!("Record: {:?}", response.record);
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
@@ -64,28 +145,139 @@ pub fn get_record(app: u64, id: u64) -> GetRecordRequest {
     let builder = RequestBuilder::new(http::Method::GET, "/v1/record.json")
         .query("app", app)
         .query("id", id);
-    GetRecordRequest { builder }
+    GetRecordRequest {
+        builder,
+        fields: None,
+    }
 }
 
 #[must_use]
 pub struct GetRecordRequest {
     builder: RequestBuilder,
+    fields: Option<Vec<String>>,
 }
 
 impl GetRecordRequest {
+    /// Keeps only the given fields in the returned record.
+    ///
+    /// The filtering happens on the client; the full record is still downloaded.
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.fields = Some(fields.into_iter().map(|f| f.as_ref().to_owned()).collect());
+        self
+    }
+
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<GetRecordResponse, ApiError> {
-        self.builder.call(client)
+        let mut resp: GetRecordResponse = self.builder.call(client)?;
+        if let Some(fields) = self.fields {
+            let unwanted: Vec<String> = resp
+                .record
+                .fields()
+                .map(|(code, _)| code)
+                .filter(|code| !fields.iter().any(|f| f == code))
+                .map(str::to_owned)
+                .collect();
+            for code in unwanted {
+                resp.record.remove_field(&code);
+            }
+        }
+        Ok(resp)
     }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetRecordResponse {
     pub record: Record,
 }
 
 //-----------------------------------------------------------------------------
 
+/// Retrieves a single record, returning `None` if it does not exist.
+///
+/// This behaves like [`get_record`], except that the "record not found" error (Kintone error
+/// code `GAIA_RE01`) is returned as `Ok(None)`. Other errors, including a missing app, are
+/// returned as they are.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app
+/// * `id` - The ID of the record to retrieve
+/// * `fields` (optional) - The field codes to keep in the returned record
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// match kintone::v1::record::try_get_record(123, 456).send(&client)? {
+///     Some(response) => println!("Record: {:?}", response.record),
+///     None => println!("Record 456 does not exist"),
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/records/get-record/>
+pub fn try_get_record(app: u64, id: u64) -> TryGetRecordRequest {
+    TryGetRecordRequest {
+        inner: get_record(app, id),
+    }
+}
+
+#[must_use]
+pub struct TryGetRecordRequest {
+    inner: GetRecordRequest,
+}
+
+impl TryGetRecordRequest {
+    /// Keeps only the given fields in the returned record.
+    ///
+    /// The filtering happens on the client; the full record is still downloaded.
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.inner.debug_url(client)
+    }
+
+    pub fn send(self, client: &KintoneClient) -> Result<Option<GetRecordResponse>, ApiError> {
+        match self.inner.send(client) {
+            Ok(resp) => Ok(Some(resp)),
+            Err(err)
+                if matches!(err.final_error(), ApiError::Kintone(e)
+                    if e.error_code() == KintoneErrorCode::RecordNotFound) =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
 /// Retrieves multiple records from a Kintone app with optional filtering and pagination.
 ///
 /// This function creates a request to get records from the specified app. The request
@@ -113,16 +305,21 @@ pub struct GetRecordResponse {
 /// <https://cybozu.dev/ja/kintone/docs/rest-api/records/get-records/>
 pub fn get_records(app: u64) -> GetRecordsRequest {
     let builder = RequestBuilder::new(http::Method::GET, "/v1/records.json").query("app", app);
-    GetRecordsRequest { builder }
+    GetRecordsRequest {
+        builder,
+        error: None,
+    }
 }
 
 #[must_use]
 pub struct GetRecordsRequest {
     builder: RequestBuilder,
+    error: Option<ApiError>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetRecordsResponse {
     pub records: Vec<Record>,
 
@@ -130,9 +327,44 @@ pub struct GetRecordsResponse {
     pub total_count: Option<usize>,
 }
 
+impl GetRecordsResponse {
+    /// Returns the total number of records matching the query.
+    ///
+    /// Kintone only counts the records when the request asked for it with
+    /// [`GetRecordsRequest::total_count`]; otherwise `total_count` is `None`.
+    ///
+    /// # Errors
+    /// Returns [`ApiError::InvalidRequest`] if the total count was not requested.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// let response = kintone::v1::record::get_records(123)
+    ///     .query("limit 100")
+    ///     .total_count(true)
+    ///     .send(&client)?;
+    /// let pages = response.require_total_count()?.div_ceil(100);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn require_total_count(&self) -> Result<usize, ApiError> {
+        self.total_count.ok_or_else(|| {
+            ApiError::InvalidRequest(
+                "the total count was not requested; call `total_count(true)` on the request"
+                    .to_owned(),
+            )
+        })
+    }
+}
+
 impl GetRecordsRequest {
-    pub fn fields(mut self, fields: &[&str]) -> Self {
-        self.builder = self.builder.query_array("fields", fields);
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fields: Vec<String> = fields.into_iter().map(|f| f.as_ref().to_owned()).collect();
+        self.builder = self.builder.query_array("fields", &fields);
         self
     }
 
@@ -141,14 +373,600 @@ impl GetRecordsRequest {
         self
     }
 
+    /// Sets a query in which each `?` placeholder is replaced with a bound value.
+    ///
+    /// Every value is inserted as a quoted string literal, with quotes and backslashes escaped,
+    /// so user input cannot change the structure of the query. See [`bind_query`] for details.
+    /// If the number of values does not match the number of placeholders, `send` returns
+    /// [`ApiError::InvalidRequest`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// let response = kintone::v1::record::get_records(123)
+    ///     .query_params("name = ? and status = ?", ["O'Brien \"Bob\"", "Active"])
+    ///     .send(&client)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_params<I>(mut self, template: &str, params: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        match bind_query(template, params) {
+            Ok(query) => self.query(&query),
+            Err(err) => {
+                self.error = Some(err);
+                self
+            }
+        }
+    }
+
     pub fn total_count(mut self, total_count: bool) -> Self {
         self.builder = self.builder.query("totalCount", total_count);
         self
     }
 
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<GetRecordsResponse, ApiError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
         self.builder.call(client)
     }
+
+    /// Sends the request and returns an iterator that parses the records one at a time while
+    /// the response body is being read.
+    ///
+    /// Unlike [`send`](Self::send), the records are never collected into a `Vec`, so memory
+    /// use stays at about one record regardless of how many are returned. The total count
+    /// is not available this way. The client's maximum response size does not apply, as
+    /// the body is never held in memory as a whole.
+    ///
+    /// Errors while sending the request are returned immediately. Errors while reading or
+    /// parsing the body are yielded by the iterator, which then ends.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// let records = kintone::v1::record::get_records(123)
+    ///     .query("order by $id asc limit 500")
+    ///     .stream(&client)?;
+    /// for record in records {
+    ///     println!("{:?}", record?.id());
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn stream(self, client: &KintoneClient) -> Result<RecordStream, ApiError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let reader = self.builder.call_for_body(client)?.into_json_reader()?;
+        Ok(RecordStream {
+            inner: JsonArrayStream::new(Box::new(reader), "records"),
+        })
+    }
+}
+
+/// An iterator over the records of a response, returned by [`GetRecordsRequest::stream`].
+pub struct RecordStream {
+    inner: JsonArrayStream<Box<dyn Read + Send>, Record>,
+}
+
+impl Iterator for RecordStream {
+    type Item = Result<Record, ApiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// Builds a query string by replacing each `?` in `template` with a quoted, escaped value.
+///
+/// Values are written as Kintone string literals: wrapped in double quotes, with `\` and `"`
+/// escaped by a backslash. Kintone compares string literals against numeric and date fields as
+/// well, so this form works for every field type. A `?` inside a string literal of the
+/// template itself is left untouched.
+///
+/// Use this to build queries for requests that take a plain query string, such as
+/// [`create_cursor`] or [`get_all_records`].
+///
+/// # Errors
+/// Returns [`ApiError::InvalidRequest`] if the number of values does not match the number of
+/// placeholders.
+///
+/// # Example
+/// ```
+/// use kintone::v1::record::bind_query;
+///
+/// let query = bind_query("name = ? and age > ?", ["say \"hi\"", "20"])?;
+/// assert_eq!(query, r#"name = "say \"hi\"" and age > "20""#);
+/// # Ok::<(), kintone::error::ApiError>(())
+/// ```
+pub fn bind_query<I>(template: &str, params: I) -> Result<String, ApiError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut params = params.into_iter();
+    let mut query = String::with_capacity(template.len());
+    let mut placeholders = 0;
+    let mut bound = 0;
+    let mut in_literal = false;
+    let mut escaped = false;
+    for c in template.chars() {
+        if in_literal {
+            in_literal = escaped || c != '"';
+            escaped = !escaped && c == '\\';
+        } else if c == '"' {
+            in_literal = true;
+        } else if c == '?' {
+            placeholders += 1;
+            if let Some(value) = params.next() {
+                bound += 1;
+                push_query_literal(&mut query, value.as_ref());
+                continue;
+            }
+        }
+        query.push(c);
+    }
+    let given = bound + params.count();
+    if given != placeholders {
+        return Err(ApiError::InvalidRequest(format!(
+            "query has {placeholders} placeholders, but {given} values were given"
+        )));
+    }
+    Ok(query)
+}
+
+/// Builds a `$id in (...)` condition matching the records with the given IDs.
+///
+/// The result is a query condition, not a whole query; combine it with other conditions,
+/// `order by`, or `limit` as needed. At most [`MAX_RECORDS_PER_PAGE`] records are returned
+/// by a single [`get_records`] call, so split larger ID lists accordingly.
+///
+/// # Errors
+/// Returns [`ApiError::InvalidRequest`] if `ids` is empty, since `in ()` is not a valid query.
+///
+/// # Example
+/// ```
+/// use kintone::v1::record::id_in;
+///
+/// let query = format!("{} order by $id asc", id_in([3, 1, 2])?);
+/// assert_eq!(query, r#"$id in ("3", "1", "2") order by $id asc"#);
+/// # Ok::<(), kintone::error::ApiError>(())
+/// ```
+pub fn id_in(ids: impl IntoIterator<Item = u64>) -> Result<String, ApiError> {
+    in_condition("$id", ids.into_iter().map(|id| id.to_string()))
+}
+
+/// Builds a `<field_code> in (...)` condition matching the records with the given record
+/// numbers.
+///
+/// Unlike `$id`, the record number field has an app-specific field code, which has to be
+/// given. Record numbers are passed as they are shown, including the app code prefix if the
+/// app has one.
+///
+/// # Errors
+/// Returns [`ApiError::InvalidRequest`] if `numbers` is empty.
+///
+/// # Example
+/// ```
+/// use kintone::v1::record::record_number_in;
+///
+/// let query = record_number_in("number", ["PRJ-1", "PRJ-7"])?;
+/// assert_eq!(query, r#"number in ("PRJ-1", "PRJ-7")"#);
+/// # Ok::<(), kintone::error::ApiError>(())
+/// ```
+pub fn record_number_in<I>(field_code: &str, numbers: I) -> Result<String, ApiError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    in_condition(field_code, numbers)
+}
+
+fn in_condition<I>(field_code: &str, values: I) -> Result<String, ApiError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut query = format!("{field_code} in (");
+    let mut empty = true;
+    for value in values {
+        if !empty {
+            query.push_str(", ");
+        }
+        push_query_literal(&mut query, value.as_ref());
+        empty = false;
+    }
+    if empty {
+        return Err(ApiError::InvalidRequest(format!(
+            "no values given for the `in` condition on {field_code}"
+        )));
+    }
+    query.push(')');
+    Ok(query)
+}
+
+/// Appends `value` to `query` as a double-quoted Kintone string literal.
+fn push_query_literal(query: &mut String, value: &str) {
+    query.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            query.push('\\');
+        }
+        query.push(c);
+    }
+    query.push('"');
+}
+
+//-----------------------------------------------------------------------------
+
+/// Same as [`get_record`], but identifies the app by its app code instead of its ID.
+///
+/// The app code is resolved to an ID with [`get_apps`](crate::v1::app::get_apps) the first
+/// time it is used. The client remembers the result, so later calls with the same code do
+/// not send the extra request.
+///
+/// # Errors
+/// Returns [`ApiError::AppCodeNotFound`] if no app has the given code.
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let response = kintone::v1::record::get_record_by_code(&client, "PROJECT", 456)?.send(&client)?;
+/// println!("Record: {:?}", response.record);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn get_record_by_code(
+    client: &KintoneClient,
+    app_code: &str,
+    id: u64,
+) -> Result<GetRecordRequest, ApiError> {
+    Ok(get_record(client.resolve_app_code(app_code)?, id))
+}
+
+/// Same as [`get_records`], but identifies the app by its app code instead of its ID.
+///
+/// See [`get_record_by_code`] for how the app code is resolved.
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let response = kintone::v1::record::get_records_by_code(&client, "PROJECT")?
+///     .query("status = \"Active\"")
+///     .send(&client)?;
+/// println!("Found {} records", response.records.len());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn get_records_by_code(
+    client: &KintoneClient,
+    app_code: &str,
+) -> Result<GetRecordsRequest, ApiError> {
+    Ok(get_records(client.resolve_app_code(app_code)?))
+}
+
+//-----------------------------------------------------------------------------
+
+/// Downloads the files attached to a file field of a record, one after another.
+///
+/// The returned iterator downloads each file lazily when it is advanced, in the order the
+/// files appear in the field, and yields the file's metadata together with its content.
+/// If the record has no field with the given code, or the field is empty, the iterator is
+/// empty. If the field is not a file field, the iterator yields a single error.
+///
+/// # Arguments
+/// * `client` - The client used to download the files
+/// * `record` - The record that holds the file field
+/// * `field_code` - The code of the file field
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use std::fs::File;
+/// use kintone::v1::record::{download_attachments, get_record};
+///
+/// let record = get_record(123, 456).send(&client)?.record;
+/// for attachment in download_attachments(&client, &record, "attachments") {
+///     let (file, mut download) = attachment?;
+///     let name = file.name.unwrap_or(file.file_key);
+///     std::io::copy(&mut download.content, &mut File::create(name)?)?;
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn download_attachments<'a>(
+    client: &'a KintoneClient,
+    record: &Record,
+    field_code: &str,
+) -> AttachmentDownloads<'a> {
+    let (files, error) = match record.get(field_code) {
+        None => (Vec::new(), None),
+        Some(FieldValue::File(files)) => (files.clone(), None),
+        Some(other) => {
+            let err = FieldAccessError::type_mismatch(field_code, FieldType::File, other);
+            (Vec::new(), Some(ApiError::InvalidRequest(err.to_string())))
+        }
+    };
+    AttachmentDownloads {
+        client,
+        files: files.into_iter(),
+        error,
+    }
+}
+
+/// An iterator over the downloads of a file field, created by [`download_attachments`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AttachmentDownloads<'a> {
+    client: &'a KintoneClient,
+    files: std::vec::IntoIter<FileBody>,
+    error: Option<ApiError>,
+}
+
+impl Iterator for AttachmentDownloads<'_> {
+    type Item = Result<(FileBody, DownloadFileResponse), ApiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            return Some(Err(err));
+        }
+        let file = self.files.next()?;
+        let download = crate::v1::file::download(file.file_key.clone()).send(self.client);
+        Some(download.map(|download| (file, download)))
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Counts the records in a Kintone app that match a query.
+///
+/// This sends a [`get_records`] request that asks for the total count while fetching at most
+/// one record with only its `$id`, so record bodies are not transferred.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app
+/// * `query` (optional) - A query condition. It must not contain `limit` or `offset`
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let count = kintone::v1::record::count_records(123)
+///     .query("status = \"Active\"")
+///     .send(&client)?;
+/// println!("{count} active records");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/records/get-records/>
+pub fn count_records(app: u64) -> CountRecordsRequest {
+    CountRecordsRequest {
+        app,
+        query: String::new(),
+    }
+}
+
+#[must_use]
+pub struct CountRecordsRequest {
+    app: u64,
+    query: String,
+}
+
+impl CountRecordsRequest {
+    pub fn query(mut self, query: &str) -> Self {
+        self.query = query.to_owned();
+        self
+    }
+
+    fn to_get_records(&self) -> GetRecordsRequest {
+        let query = if self.query.trim().is_empty() {
+            "limit 1".to_owned()
+        } else {
+            format!("{} limit 1", self.query)
+        };
+        get_records(self.app).fields(["$id"]).query(&query).total_count(true)
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.to_get_records().debug_url(client)
+    }
+
+    pub fn send(self, client: &KintoneClient) -> Result<usize, ApiError> {
+        let resp = self.to_get_records().send(client)?;
+        resp.total_count.ok_or_else(|| {
+            ApiError::Json(serde::de::Error::custom("totalCount is missing from the response"))
+        })
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieves all records matching a query, fetching ranges of record IDs concurrently.
+///
+/// This is intended for bulk exports of large apps. A cursor can only be read sequentially, so
+/// instead the range of record IDs (`$id`) that match the query is split into partitions, and
+/// each partition is paged through by a separate thread using [`get_records`]. The results are
+/// merged into a single `Vec` ordered by record ID.
+///
+/// Because every partition sends its own requests, this uses more API requests than a cursor
+/// when there are few records. Keep the number of partitions small to stay within Kintone's
+/// limit on concurrent requests.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app to retrieve records from
+/// * `query` (optional) - A query condition. It must not contain `order by`, `limit`, or `offset`
+/// * `fields` (optional) - The field codes to include in the response. `$id` is always included
+/// * `partitions` (optional) - The number of concurrent partitions (default: 4)
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let records = kintone::v1::record::get_records_parallel(123)
+///     .query("status = \"Active\"")
+///     .fields(&["name", "email"])
+///     .partitions(8)
+///     .send(&client)?;
+/// println!("Exported {} records", records.len());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn get_records_parallel(app: u64) -> GetRecordsParallelRequest {
+    GetRecordsParallelRequest {
+        app,
+        query: String::new(),
+        fields: Vec::new(),
+        partitions: GetRecordsParallelRequest::DEFAULT_PARTITIONS,
+    }
+}
+
+#[must_use]
+pub struct GetRecordsParallelRequest {
+    app: u64,
+    query: String,
+    fields: Vec<String>,
+    partitions: usize,
+}
+
+impl GetRecordsParallelRequest {
+    const DEFAULT_PARTITIONS: usize = 4;
+
+    pub fn query(mut self, query: &str) -> Self {
+        self.query = query.to_owned();
+        self
+    }
+
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.fields = fields.into_iter().map(|f| f.as_ref().to_owned()).collect();
+        self
+    }
+
+    /// Sets the number of partitions fetched concurrently. Values below 1 are treated as 1.
+    pub fn partitions(mut self, partitions: usize) -> Self {
+        self.partitions = partitions.max(1);
+        self
+    }
+
+    pub fn send(self, client: &KintoneClient) -> Result<Vec<Record>, ApiError> {
+        let Some(min_id) = self.find_edge_id(client, Order::Asc)? else {
+            return Ok(Vec::new());
+        };
+        let Some(max_id) = self.find_edge_id(client, Order::Desc)? else {
+            return Ok(Vec::new());
+        };
+        let ranges = split_id_range(min_id, max_id, self.partitions);
+
+        let this = &self;
+        let results: Vec<Result<Vec<Record>, ApiError>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = ranges
+                .into_iter()
+                .map(|(first, last)| scope.spawn(move || this.fetch_range(client, first, last)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .collect()
+        });
+
+        let mut records = Vec::new();
+        for result in results {
+            records.extend(result?);
+        }
+        Ok(records)
+    }
+
+    fn condition(&self, range: &str) -> String {
+        match (self.query.trim(), range) {
+            ("", range) => range.to_owned(),
+            (query, "") => format!("({query})"),
+            (query, range) => format!("({query}) and {range}"),
+        }
+    }
+
+    fn fields_with_id(&self) -> Vec<&str> {
+        let mut fields: Vec<&str> = self.fields.iter().map(String::as_str).collect();
+        if !fields.is_empty() && !fields.contains(&"$id") {
+            fields.push("$id");
+        }
+        fields
+    }
+
+    fn find_edge_id(&self, client: &KintoneClient, order: Order) -> Result<Option<u64>, ApiError> {
+        let query = format!("{} order by $id {order} limit 1", self.condition(""));
+        let resp = get_records(self.app)
+            .fields(["$id"])
+            .query(query.trim_start())
+            .total_count(false)
+            .send(client)?;
+        Ok(resp.records.first().and_then(Record::id))
+    }
+
+    fn fetch_range(
+        &self,
+        client: &KintoneClient,
+        first: u64,
+        last: u64,
+    ) -> Result<Vec<Record>, ApiError> {
+        let fields = self.fields_with_id();
+        let mut records = Vec::new();
+        let mut after = first.saturating_sub(1);
+        loop {
+            let range = format!("$id > {after} and $id <= {last}");
+            let query =
+                format!("{} order by $id asc limit {MAX_RECORDS_PER_PAGE}", self.condition(&range));
+            let resp = get_records(self.app)
+                .fields(&fields)
+                .query(&query)
+                .total_count(false)
+                .send(client)?;
+            let count = resp.records.len();
+            let Some(last_id) = resp.records.last().and_then(Record::id) else {
+                return Ok(records);
+            };
+            records.extend(resp.records);
+            if count < MAX_RECORDS_PER_PAGE || last_id >= last {
+                return Ok(records);
+            }
+            after = last_id;
+        }
+    }
+}
+
+/// Splits the inclusive range `first..=last` into at most `n` contiguous, non-empty ranges.
+fn split_id_range(first: u64, last: u64, n: usize) -> Vec<(u64, u64)> {
+    let total = last - first + 1;
+    let n = (n as u64).min(total);
+    let size = total / n;
+    let remainder = total % n;
+    let mut ranges = Vec::new();
+    let mut start = first;
+    for i in 0..n {
+        let len = size + if i < remainder { 1 } else { 0 };
+        ranges.push((start, start + len - 1));
+        start += len;
+    }
+    ranges
 }
 
 //-----------------------------------------------------------------------------
@@ -206,6 +1024,7 @@ pub struct AddRecordRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AddRecordResponse {
     #[serde(with = "stringified")]
     pub id: u64,
@@ -219,6 +1038,11 @@ impl AddRecordRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<AddRecordResponse, ApiError> {
         self.builder.send(client, self.body)
     }
@@ -236,7 +1060,7 @@ impl AddRecordRequest {
 /// * `records` - A vector of Records containing the field data for the new records
 ///
 /// # Limits
-/// - Maximum 100 records can be added in a single request
+/// - Maximum 100 records ([`MAX_RECORDS_PER_REQUEST`]) can be added in a single request
 /// - If any record fails, all records in the request are rolled back
 ///
 /// # Example
@@ -287,24 +1111,191 @@ pub struct AddRecordsRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AddRecordsResponse {
     pub ids: Vec<String>,
     pub revisions: Vec<String>,
 }
 
 impl AddRecordsRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<AddRecordsResponse, ApiError> {
+        check_len("records", self.body.records.len(), MAX_RECORDS_PER_REQUEST)?;
         self.builder.send(client, self.body)
     }
 }
 
 //-----------------------------------------------------------------------------
 
+/// The outcome of an operation that sends records in chunks and carries on when a chunk fails.
+///
+/// Returned by the `send_continue_on_error` methods of the chunked helpers, such as
+/// [`ParallelAddRecordsRequest::send_continue_on_error`] and
+/// [`DeleteAllMatchingRequest::send_continue_on_error`]. Each entry is keyed by the index of
+/// its chunk, counting from 0 in the order the chunks were formed. For helpers that chunk their
+/// input, chunk `i` holds the records from `i * MAX_RECORDS_PER_REQUEST` up to the next chunk.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct BatchOutcome<T> {
+    /// The chunks that were processed, with their results.
+    pub succeeded: Vec<(usize, T)>,
+    /// The chunks that failed, with their errors.
+    pub failed: Vec<(usize, ApiError)>,
+}
+
+impl<T> BatchOutcome<T> {
+    /// Returns `true` if no chunk failed.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl<T> Default for BatchOutcome<T> {
+    fn default() -> Self {
+        BatchOutcome {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+}
+
+/// Collects per-chunk results, given in chunk order.
+impl<T> FromIterator<Result<T, ApiError>> for BatchOutcome<T> {
+    fn from_iter<I: IntoIterator<Item = Result<T, ApiError>>>(iter: I) -> Self {
+        let mut outcome = BatchOutcome::default();
+        for (index, result) in iter.into_iter().enumerate() {
+            match result {
+                Ok(value) => outcome.succeeded.push((index, value)),
+                Err(err) => outcome.failed.push((index, err)),
+            }
+        }
+        outcome
+    }
+}
+
+/// Adds any number of records, sending chunks of them concurrently.
+///
+/// The records are split into chunks of [`MAX_RECORDS_PER_REQUEST`], and up to `concurrency`
+/// worker threads send the chunks with [`add_records`]. All workers share the given client, so
+/// any layers configured on it (such as retries or rate limiting) apply to every request.
+///
+/// Each chunk is atomic on its own, but the operation as a whole is not: if one chunk fails,
+/// the others are still added. The result therefore has one entry per chunk, in the order of
+/// the input, so callers can tell exactly which records were added.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app
+/// * `records` - The records to add
+/// * `concurrency` (optional) - The maximum number of requests in flight (default: 4)
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::record::{Record, FieldValue};
+///
+/// let records: Vec<Record> = (0..10_000)
+///     .map(|i| Record::from([("name", FieldValue::text(format!("row {i}")))]))
+///     .collect();
+/// let results = kintone::v1::record::parallel_add_records(123, records)
+///     .concurrency(8)
+///     .send(&client);
+/// for (chunk, result) in results.iter().enumerate() {
+///     if let Err(err) = result {
+///         eprintln!("chunk {chunk} was not added: {err}");
+///     }
+/// }
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/records/add-records/>
+pub fn parallel_add_records(app: u64, records: Vec<Record>) -> ParallelAddRecordsRequest {
+    ParallelAddRecordsRequest {
+        app,
+        records,
+        concurrency: ParallelAddRecordsRequest::DEFAULT_CONCURRENCY,
+    }
+}
+
+#[must_use]
+pub struct ParallelAddRecordsRequest {
+    app: u64,
+    records: Vec<Record>,
+    concurrency: usize,
+}
+
+impl ParallelAddRecordsRequest {
+    const DEFAULT_CONCURRENCY: usize = 4;
+
+    /// Sets the maximum number of requests in flight. Values below 1 are treated as 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sends the chunks and collects their results into a [`BatchOutcome`].
+    ///
+    /// This sends the same requests as [`send`](Self::send); the outcome separates the chunks
+    /// that were added from those that failed, keyed by chunk index.
+    pub fn send_continue_on_error(
+        self,
+        client: &KintoneClient,
+    ) -> BatchOutcome<AddRecordsResponse> {
+        self.send(client).into_iter().collect()
+    }
+
+    /// Sends the chunks and returns the result of each, in input order.
+    pub fn send(self, client: &KintoneClient) -> Vec<Result<AddRecordsResponse, ApiError>> {
+        let mut chunks = Vec::new();
+        let mut records = self.records.into_iter().peekable();
+        while records.peek().is_some() {
+            chunks.push(records.by_ref().take(MAX_RECORDS_PER_REQUEST).collect::<Vec<_>>());
+        }
+        let chunk_count = chunks.len();
+        let queue = std::sync::Mutex::new(chunks.into_iter().enumerate());
+        let app = self.app;
+
+        let mut results: Vec<(usize, Result<AddRecordsResponse, ApiError>)> =
+            std::thread::scope(|scope| {
+                let queue = &queue;
+                let handles: Vec<_> = (0..self.concurrency.min(chunk_count))
+                    .map(|_| {
+                        scope.spawn(move || {
+                            let mut results = Vec::new();
+                            loop {
+                                let next = queue.lock().unwrap().next();
+                                let Some((index, chunk)) = next else {
+                                    return results;
+                                };
+                                results.push((index, add_records(app, chunk).send(client)));
+                            }
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                    .collect()
+            });
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+//-----------------------------------------------------------------------------
+
 /// Updates an existing record in a Kintone app.
 ///
 /// This function creates a request to update a record in the specified app.
 /// The record can be identified either by its ID or by a unique key field.
-/// Only the fields specified in the record data will be updated.
+/// Only the fields specified in the record data will be updated. To clear a field,
+/// include it with an empty value (see [`FieldValue::empty_text`]).
+///
+/// [`FieldValue::empty_text`]: crate::model::record::FieldValue::empty_text
 ///
 /// # Arguments
 /// * `app` - The ID of the Kintone app containing the record to update
@@ -313,6 +1304,17 @@ impl AddRecordsRequest {
 /// * `record` (optional) - A Record containing the field data to update (only specified fields will be updated)
 /// * `revision` (optional) - The expected revision number of the record to prevent conflicts
 ///
+/// `revision` can be combined with either `id` or `update_key`. If the record has been
+/// updated since that revision, the request fails with [`ApiError::RevisionConflict`],
+/// which lets callers reload the record and try again.
+///
+/// If `record` contains the built-in `$id` or `$revision` fields (for example because it was
+/// fetched with [`get_record`], or set with [`Record::set_id`] / [`Record::set_revision`]),
+/// they are removed from the submitted fields and used as the `id` and `revision` of the
+/// request. Values set with [`id`](UpdateRecordRequest::id),
+/// [`update_key`](UpdateRecordRequest::update_key), or
+/// [`revision`](UpdateRecordRequest::revision) take precedence over the embedded ones.
+///
 /// # Example
 /// ```no_run
 /// # use kintone::client::{Auth, KintoneClient};
@@ -419,7 +1421,9 @@ pub struct UpdateRecordRequest {
 #[serde(rename_all = "camelCase")]
 pub struct UpdateRecordRequestBody {
     app: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     update_key: Option<UpdateKey>,
     record: Option<Record>,
     revision: Option<u64>,
@@ -427,6 +1431,7 @@ pub struct UpdateRecordRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct UpdateRecordResponse {
     #[serde(with = "stringified")]
     pub revision: u64,
@@ -456,8 +1461,33 @@ impl UpdateRecordRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<UpdateRecordResponse, ApiError> {
-        self.builder.send(client, self.body)
+        self.builder.send(client, self.body.with_embedded_id_and_revision())
+    }
+}
+
+impl UpdateRecordRequestBody {
+    /// Moves the `$id` and `$revision` fields of the record to the request, unless the request
+    /// already identifies the record or specifies a revision.
+    fn with_embedded_id_and_revision(mut self) -> Self {
+        if let Some(record) = &mut self.record {
+            if let Some(id) = record.id() {
+                record.remove_field("$id");
+                if self.id.is_none() && self.update_key.is_none() {
+                    self.id = Some(id);
+                }
+            }
+            if let Some(revision) = record.revision() {
+                record.remove_field("$revision");
+                self.revision.get_or_insert(revision);
+            }
+        }
+        self
     }
 }
 
@@ -474,7 +1504,7 @@ impl UpdateRecordRequest {
 /// * `records` - A vector of UpdateRecordData containing the record update information
 ///
 /// # Limits
-/// - Maximum 100 records can be updated in a single request
+/// - Maximum 100 records ([`MAX_RECORDS_PER_REQUEST`]) can be updated in a single request
 /// - If any record update fails, all updates in the request are rolled back
 /// - UPSERT mode can insert new records if they don't exist
 ///
@@ -585,43 +1615,264 @@ impl Default for UpdateRecordData {
 }
 
 #[must_use]
-pub struct UpdateRecordsRequest {
+pub struct UpdateRecordsRequest {
+    builder: RequestBuilder,
+    pub(crate) body: UpdateRecordsRequestBody,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRecordsRequestBody {
+    app: u64,
+    records: Vec<UpdateRecordData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upsert: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateRecordsResponse {
+    pub records: Vec<UpdatedRecordInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdatedRecordInfo {
+    pub id: String,
+    pub revision: String,
+    pub operation: String,
+}
+
+impl UpdateRecordsRequest {
+    /// Enables UPSERT mode. When enabled, records that don't exist will be created.
+    pub fn upsert(mut self, upsert: bool) -> Self {
+        self.body.upsert = Some(upsert);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(self, client: &KintoneClient) -> Result<UpdateRecordsResponse, ApiError> {
+        check_len("records", self.body.records.len(), MAX_RECORDS_PER_REQUEST)?;
+        self.builder.send(client, self.body)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Inserts a record, or updates it if a record with the same unique key already exists.
+///
+/// This function creates a request that looks up a record by a unique key field. If a matching
+/// record exists it is updated with the given field values; otherwise a new record is created
+/// with the key field populated. The lookup and the write are performed atomically by Kintone
+/// (using the UPSERT mode of the update records API), so concurrent callers cannot create
+/// duplicates.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app
+/// * `update_key` - The unique key field and value identifying the record (required)
+/// * `record` - The field values to write
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::record::{Record, FieldValue};
+/// use kintone::v1::record::Upserted;
+///
+/// let outcome = kintone::v1::record::upsert_record(123)
+///     .update_key("employee_id".to_owned(), "E-0042")
+///     .record(Record::from([
+///         ("name", FieldValue::SingleLineText("John Doe".to_owned())),
+///     ]))
+///     .send(&client)?;
+/// match outcome {
+///     Upserted::Created(id) => println!("Created record {id}"),
+///     Upserted::Updated(revision) => println!("Updated record, new revision: {revision}"),
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/records/update-records/>
+pub fn upsert_record(app: u64) -> UpsertRecordRequest {
+    let builder = RequestBuilder::new(http::Method::PUT, "/v1/records.json");
+    UpsertRecordRequest {
+        builder,
+        app,
+        data: UpdateRecordData::new(),
+    }
+}
+
+/// The outcome of [`upsert_record`], or of one entry of [`upsert_records`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Upserted {
+    /// A new record was created with the given record ID.
+    Created(u64),
+    /// An existing record was updated; holds its new revision number.
+    Updated(u64),
+}
+
+#[must_use]
+pub struct UpsertRecordRequest {
+    builder: RequestBuilder,
+    app: u64,
+    data: UpdateRecordData,
+}
+
+#[derive(Deserialize)]
+struct UpsertRecordResponse {
+    records: Vec<UpsertedRecordInfo>,
+}
+
+#[derive(Deserialize)]
+struct UpsertedRecordInfo {
+    #[serde(with = "stringified")]
+    id: u64,
+    #[serde(with = "stringified")]
+    revision: u64,
+    operation: String,
+}
+
+impl UpsertRecordRequest {
+    /// Sets the unique key to identify the record to update.
+    pub fn update_key(mut self, field: String, value: impl Into<UpdateKeyValue>) -> Self {
+        self.data = self.data.update_key(field, value);
+        self
+    }
+
+    /// Sets the record data to write.
+    pub fn record(mut self, record: Record) -> Self {
+        self.data = self.data.record(record);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(self, client: &KintoneClient) -> Result<Upserted, ApiError> {
+        if self.data.update_key.is_none() {
+            return Err(ApiError::InvalidRequest(
+                "update_key is required for upsert_record".to_owned(),
+            ));
+        }
+        let body = UpdateRecordsRequestBody {
+            app: self.app,
+            records: vec![self.data],
+            upsert: Some(true),
+        };
+        let response: UpsertRecordResponse = self.builder.send(client, body)?;
+        let Some(info) = response.records.into_iter().next() else {
+            return Err(ApiError::Json(serde::de::Error::custom(
+                "upsert_record response contains no records",
+            )));
+        };
+        Ok(info.into())
+    }
+}
+
+impl From<UpsertedRecordInfo> for Upserted {
+    fn from(info: UpsertedRecordInfo) -> Self {
+        if info.operation == "INSERT" {
+            Upserted::Created(info.id)
+        } else {
+            Upserted::Updated(info.revision)
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Inserts or updates multiple records, matching each one by a unique key.
+///
+/// This is the batch version of [`upsert_record`]. Every entry must identify its record with an
+/// update key; entries whose key matches an existing record update it, and the others are
+/// created. Kintone decides between insert and update atomically on the server, so there is no
+/// window in which another client could create a duplicate between a lookup and a write.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app
+/// * `entries` - The records to write, each with an update key
+///
+/// # Limits
+/// - Maximum 100 entries ([`MAX_RECORDS_PER_REQUEST`]) per request
+/// - If any entry fails, none of the entries in the request are written
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::model::record::{Record, FieldValue};
+/// use kintone::v1::record::{UpdateRecordData, Upserted};
+///
+/// let entries = vec![
+///     UpdateRecordData::new()
+///         .update_key("employee_id".to_owned(), "E-0042")
+///         .record(Record::from([("name", FieldValue::text("John Doe"))])),
+///     UpdateRecordData::new()
+///         .update_key("employee_id".to_owned(), "E-0043")
+///         .record(Record::from([("name", FieldValue::text("Jane Roe"))])),
+/// ];
+/// let outcomes = kintone::v1::record::upsert_records(123, entries).send(&client)?;
+/// for outcome in outcomes {
+///     match outcome {
+///         Upserted::Created(id) => println!("Created record {id}"),
+///         Upserted::Updated(revision) => println!("Updated record, new revision: {revision}"),
+///     }
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/records/update-records/>
+pub fn upsert_records(app: u64, entries: Vec<UpdateRecordData>) -> UpsertRecordsRequest {
+    let builder = RequestBuilder::new(http::Method::PUT, "/v1/records.json");
+    UpsertRecordsRequest {
+        builder,
+        body: UpdateRecordsRequestBody {
+            app,
+            records: entries,
+            upsert: Some(true),
+        },
+    }
+}
+
+#[must_use]
+pub struct UpsertRecordsRequest {
     builder: RequestBuilder,
-    pub(crate) body: UpdateRecordsRequestBody,
-}
-
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct UpdateRecordsRequestBody {
-    app: u64,
-    records: Vec<UpdateRecordData>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    upsert: Option<bool>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct UpdateRecordsResponse {
-    pub records: Vec<UpdatedRecordInfo>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct UpdatedRecordInfo {
-    pub id: String,
-    pub revision: String,
-    pub operation: String,
+    body: UpdateRecordsRequestBody,
 }
 
-impl UpdateRecordsRequest {
-    /// Enables UPSERT mode. When enabled, records that don't exist will be created.
-    pub fn upsert(mut self, upsert: bool) -> Self {
-        self.body.upsert = Some(upsert);
-        self
+impl UpsertRecordsRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
     }
 
-    pub fn send(self, client: &KintoneClient) -> Result<UpdateRecordsResponse, ApiError> {
-        self.builder.send(client, self.body)
+    /// Sends the request and returns the outcome of each entry, in the order they were given.
+    pub fn send(self, client: &KintoneClient) -> Result<Vec<Upserted>, ApiError> {
+        check_len("records", self.body.records.len(), MAX_RECORDS_PER_REQUEST)?;
+        if let Some(index) = self.body.records.iter().position(|r| r.update_key.is_none()) {
+            return Err(ApiError::InvalidRequest(format!(
+                "update_key is required for upsert_records (missing in entry {index})"
+            )));
+        }
+        let expected = self.body.records.len();
+        let response: UpsertRecordResponse = self.builder.send(client, self.body)?;
+        if response.records.len() != expected {
+            return Err(ApiError::Json(serde::de::Error::custom(format!(
+                "upsert_records response contains {} records, expected {expected}",
+                response.records.len()
+            ))));
+        }
+        Ok(response.records.into_iter().map(Upserted::from).collect())
     }
 }
 
@@ -637,7 +1888,7 @@ impl UpdateRecordsRequest {
 /// * `ids` - A vector of record IDs to delete
 ///
 /// # Limits
-/// - Maximum 100 records can be deleted in a single request
+/// - Maximum 100 records ([`MAX_RECORDS_PER_REQUEST`]) can be deleted in a single request
 /// - If any record deletion fails, all deletions in the request are rolled back
 /// - Optional revision numbers can be provided for optimistic locking
 ///
@@ -695,6 +1946,7 @@ pub struct DeleteRecordsRequestBody {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
 pub struct DeleteRecordsResponse {
     // Empty response body
 }
@@ -709,13 +1961,167 @@ impl DeleteRecordsRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<DeleteRecordsResponse, ApiError> {
+        check_len("records", self.body.ids.len(), MAX_RECORDS_PER_REQUEST)?;
         self.builder.send(client, self.body)
     }
 }
 
 //-----------------------------------------------------------------------------
 
+/// Deletes every record that matches a query, in chunks of at most 100 records.
+///
+/// The matching records are read with [`get_records`] a page at a time (only `$id` and
+/// `$revision`), in ascending `$id` order, and each page is removed with one
+/// [`delete_records`] request. This repeats until no matching record is left.
+///
+/// By default the revision read with each record is sent along with the deletion, so a record
+/// that someone modifies in the meantime is not deleted: the chunk containing it fails with
+/// [`ApiError::RevisionConflict`] and the operation stops. Chunks deleted before the error stay
+/// deleted. Call [`check_revisions(false)`](DeleteAllMatchingRequest::check_revisions) to
+/// delete the records regardless of concurrent changes, or finish with
+/// [`send_continue_on_error`](DeleteAllMatchingRequest::send_continue_on_error) to skip the
+/// failing chunk and delete the rest.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app
+/// * `query` - A query condition. It must not contain `order by`, `limit`, or `offset`.
+///   An empty query matches every record of the app
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let deleted = kintone::v1::record::delete_all_matching(123, "status = \"Archived\"")
+///     .send(&client)?;
+/// println!("Deleted {deleted} records");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/records/delete-records/>
+pub fn delete_all_matching(app: u64, query: &str) -> DeleteAllMatchingRequest {
+    DeleteAllMatchingRequest {
+        app,
+        query: query.to_owned(),
+        check_revisions: true,
+    }
+}
+
+#[must_use]
+pub struct DeleteAllMatchingRequest {
+    app: u64,
+    query: String,
+    check_revisions: bool,
+}
+
+impl DeleteAllMatchingRequest {
+    /// Sets whether to send the revisions read with the records, so that records modified
+    /// in the meantime are not deleted (default: `true`).
+    pub fn check_revisions(mut self, check_revisions: bool) -> Self {
+        self.check_revisions = check_revisions;
+        self
+    }
+
+    /// Deletes the matching records and returns how many were deleted.
+    ///
+    /// The operation stops at the first chunk that fails.
+    pub fn send(self, client: &KintoneClient) -> Result<usize, ApiError> {
+        let mut deleted = 0;
+        let mut after = 0;
+        while let Some(ids) = self.delete_next_chunk(client, &mut after)? {
+            deleted += ids?.len();
+        }
+        Ok(deleted)
+    }
+
+    /// Deletes the matching records, carrying on past chunks that fail.
+    ///
+    /// Each successful entry of the outcome holds the IDs deleted by one chunk. A chunk that
+    /// fails to be deleted is reported in [`BatchOutcome::failed`] and its records are
+    /// skipped, so they still match the query afterwards. If the matching records can no
+    /// longer be read, that error is reported as a failure and the operation stops.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use kintone::client::{Auth, KintoneClient};
+    /// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+    /// let outcome = kintone::v1::record::delete_all_matching(123, "status = \"Archived\"")
+    ///     .send_continue_on_error(&client);
+    /// let deleted: usize = outcome.succeeded.iter().map(|(_, ids)| ids.len()).sum();
+    /// println!("Deleted {deleted} records");
+    /// for (chunk, err) in &outcome.failed {
+    ///     eprintln!("chunk {chunk} was not deleted: {err}");
+    /// }
+    /// ```
+    pub fn send_continue_on_error(self, client: &KintoneClient) -> BatchOutcome<Vec<u64>> {
+        let mut outcome = BatchOutcome::default();
+        let mut after = 0;
+        for index in 0.. {
+            match self.delete_next_chunk(client, &mut after) {
+                Ok(Some(Ok(ids))) => outcome.succeeded.push((index, ids)),
+                Ok(Some(Err(err))) => outcome.failed.push((index, err)),
+                Ok(None) => break,
+                Err(err) => {
+                    outcome.failed.push((index, err));
+                    break;
+                }
+            }
+        }
+        outcome
+    }
+
+    /// Reads the next chunk of matching records after the ID `after` and deletes it.
+    ///
+    /// The outer error is a failure to read the chunk, the inner one a failure to delete it.
+    /// Returns `None` when no matching record is left.
+    fn delete_next_chunk(
+        &self,
+        client: &KintoneClient,
+        after: &mut u64,
+    ) -> Result<Option<Result<Vec<u64>, ApiError>>, ApiError> {
+        let range = format!("$id > {after}");
+        let condition = match self.query.trim() {
+            "" => range,
+            query => format!("({query}) and {range}"),
+        };
+        let query = format!("{condition} order by $id asc limit {MAX_RECORDS_PER_REQUEST}");
+        let resp = get_records(self.app)
+            .fields(["$id", "$revision"])
+            .query(&query)
+            .total_count(false)
+            .send(client)?;
+        if resp.records.is_empty() {
+            return Ok(None);
+        }
+
+        let mut ids = Vec::with_capacity(resp.records.len());
+        let mut revisions = Vec::with_capacity(resp.records.len());
+        for record in &resp.records {
+            let (Some(id), Some(revision)) = (record.id(), record.revision()) else {
+                return Err(ApiError::Json(serde::de::Error::custom(
+                    "get_records response contains a record without $id or $revision",
+                )));
+            };
+            ids.push(id);
+            revisions.push(revision);
+        }
+        *after = ids.iter().copied().max().unwrap_or(*after);
+        let mut request = delete_records(self.app, ids.clone());
+        if self.check_revisions {
+            request = request.revisions(revisions);
+        }
+        Ok(Some(request.send(client).map(|_| ids)))
+    }
+}
+
+//-----------------------------------------------------------------------------
+
 /// Retrieves comments for a specific record in a Kintone app.
 ///
 /// This function creates a request to get all comments associated with a specific record.
@@ -758,6 +2164,7 @@ pub struct GetCommentsRequest {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetCommentsResponse {
     pub comments: Vec<PostedRecordComment>,
     pub older: bool,
@@ -780,6 +2187,18 @@ impl GetCommentsRequest {
         self
     }
 
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<GetCommentsResponse, ApiError> {
         self.builder.call(client)
     }
@@ -839,12 +2258,18 @@ pub struct AddCommentRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AddCommentResponse {
     #[serde(with = "stringified")]
     pub id: u64,
 }
 
 impl AddCommentRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<AddCommentResponse, ApiError> {
         self.builder.send(client, self.body)
     }
@@ -900,11 +2325,17 @@ pub struct DeleteCommentRequestBody {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
 pub struct DeleteCommentResponse {
     // Empty response body
 }
 
 impl DeleteCommentRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<DeleteCommentResponse, ApiError> {
         self.builder.send(client, self.body)
     }
@@ -967,6 +2398,7 @@ pub struct UpdateAssigneesRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct UpdateAssigneesResponse {
     #[serde(with = "stringified")]
     pub revision: u64,
@@ -978,6 +2410,11 @@ impl UpdateAssigneesRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<UpdateAssigneesResponse, ApiError> {
         self.builder.send(client, self.body)
     }
@@ -1044,6 +2481,7 @@ pub struct UpdateStatusRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct UpdateStatusResponse {
     #[serde(with = "stringified")]
     pub revision: u64,
@@ -1060,6 +2498,11 @@ impl UpdateStatusRequest {
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<UpdateStatusResponse, ApiError> {
         self.builder.send(client, self.body)
     }
@@ -1067,6 +2510,128 @@ impl UpdateStatusRequest {
 
 //-----------------------------------------------------------------------------
 
+/// Updates the workflow statuses of multiple records in a Kintone app.
+///
+/// This function creates a request to execute workflow actions on multiple records at once.
+/// This is more efficient than calling [`update_status`] for each record when many records
+/// advance through the workflow together, such as in batch approvals.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app containing the records
+/// * `records` - A vector of UpdateStatusData containing the status update information
+///
+/// # Limits
+/// - Maximum 100 records ([`MAX_RECORDS_PER_REQUEST`]) can be updated in a single request
+/// - If any status update fails, all updates in the request are rolled back
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// use kintone::v1::record::UpdateStatusData;
+///
+/// let updates = vec![
+///     UpdateStatusData::new(456, "Approve".to_owned()).revision(5),
+///     UpdateStatusData::new(457, "Approve".to_owned()).assignee("manager1".to_owned()),
+/// ];
+///
+/// let response = kintone::v1::record::update_statuses(123, updates).send(&client)?;
+/// for record in response.records {
+///     println!("Record {} is now at revision {}", record.id, record.revision);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/records/update-statuses/>
+pub fn update_statuses(app: u64, records: Vec<UpdateStatusData>) -> UpdateStatusesRequest {
+    let builder = RequestBuilder::new(http::Method::PUT, "/v1/records/status.json");
+    UpdateStatusesRequest {
+        builder,
+        body: UpdateStatusesRequestBody { app, records },
+    }
+}
+
+/// Data for updating the status of a single record in a bulk status update operation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateStatusData {
+    pub id: u64,
+    pub action: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+}
+
+impl UpdateStatusData {
+    /// Creates a new UpdateStatusData instance for the given record and workflow action.
+    pub fn new(id: u64, action: String) -> Self {
+        Self {
+            id,
+            action,
+            assignee: None,
+            revision: None,
+        }
+    }
+
+    /// Sets the login name or code of the user to assign the record to.
+    pub fn assignee(mut self, assignee: String) -> Self {
+        self.assignee = Some(assignee);
+        self
+    }
+
+    /// Sets the expected revision number for optimistic locking.
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.revision = Some(revision);
+        self
+    }
+}
+
+#[must_use]
+pub struct UpdateStatusesRequest {
+    builder: RequestBuilder,
+    pub(crate) body: UpdateStatusesRequestBody,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateStatusesRequestBody {
+    app: u64,
+    records: Vec<UpdateStatusData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateStatusesResponse {
+    pub records: Vec<UpdatedStatusInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdatedStatusInfo {
+    #[serde(with = "stringified")]
+    pub id: u64,
+    #[serde(with = "stringified")]
+    pub revision: u64,
+}
+
+impl UpdateStatusesRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
+    pub fn send(self, client: &KintoneClient) -> Result<UpdateStatusesResponse, ApiError> {
+        check_len("records", self.body.records.len(), MAX_RECORDS_PER_REQUEST)?;
+        self.builder.send(client, self.body)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
 /// Creates a cursor for paginating through large result sets efficiently.
 ///
 /// This function creates a request to generate a cursor that can be used to retrieve
@@ -1078,7 +2643,7 @@ impl UpdateStatusRequest {
 /// * `app` - The ID of the Kintone app to create a cursor for
 /// * `fields` (optional) - An array of field codes to include in the response
 /// * `query` (optional) - A query string following Kintone's query syntax
-/// * `size` (optional) - The number of records to retrieve per page (default: 100, max: 500)
+/// * `size` (optional) - The number of records to retrieve per page (default: 100, max: [`MAX_RECORDS_PER_PAGE`])
 ///
 /// # Example
 /// ```no_run
@@ -1128,6 +2693,7 @@ pub struct CreateCursorRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct CreateCursorResponse {
     pub id: String,
     #[serde(with = "stringified")]
@@ -1138,9 +2704,13 @@ impl CreateCursorRequest {
     /// Specifies which fields to include in the response.
     ///
     /// # Arguments
-    /// * `fields` - An array of field codes to retrieve
-    pub fn fields(mut self, fields: &[&str]) -> Self {
-        self.body.fields = Some(fields.iter().map(|s| s.to_string()).collect());
+    /// * `fields` - The field codes to retrieve, such as `&["name", "email"]` or a `Vec<String>`
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body.fields = Some(fields.into_iter().map(|f| f.as_ref().to_owned()).collect());
         self
     }
 
@@ -1156,13 +2726,21 @@ impl CreateCursorRequest {
     /// Sets the number of records to retrieve per page.
     ///
     /// # Arguments
-    /// * `size` - The page size (default: 100, max: 500)
+    /// * `size` - The page size (default: 100, max: [`MAX_RECORDS_PER_PAGE`])
     pub fn size(mut self, size: u64) -> Self {
         self.body.size = Some(size);
         self
     }
 
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<CreateCursorResponse, ApiError> {
+        if let Some(size) = self.body.size {
+            check_len("records per page", size as usize, MAX_RECORDS_PER_PAGE)?;
+        }
         self.builder.send(client, self.body)
     }
 }
@@ -1209,12 +2787,25 @@ pub struct GetRecordsByCursorRequest {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetRecordsByCursorResponse {
     pub records: Vec<Record>,
     pub next: bool,
 }
 
 impl GetRecordsByCursorRequest {
+    /// Sets a query parameter this request has no setter for, replacing any value already set
+    /// for `key`.
+    pub fn raw_query(mut self, key: &str, value: &str) -> Self {
+        self.builder = self.builder.query(key, value);
+        self
+    }
+
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<GetRecordsByCursorResponse, ApiError> {
         self.builder.call(client)
     }
@@ -1264,11 +2855,17 @@ pub struct DeleteCursorRequestBody {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
 pub struct DeleteCursorResponse {
     // Empty response body
 }
 
 impl DeleteCursorRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<DeleteCursorResponse, ApiError> {
         self.builder.send(client, self.body)
     }
@@ -1276,6 +2873,163 @@ impl DeleteCursorRequest {
 
 //-----------------------------------------------------------------------------
 
+/// Iterates over every record matching a query using a cursor.
+///
+/// `send` creates a cursor and returns an [`AllRecords`] iterator that fetches one page at a
+/// time, so the result set does not have to fit in memory at once. The total number of
+/// matching records is known as soon as the cursor is created, which makes it easy to report
+/// progress as `fetched / total`. If the iterator is dropped before it is exhausted, the cursor
+/// is deleted on a best-effort basis.
+///
+/// # Arguments
+/// * `app` - The ID of the Kintone app
+/// * `fields` (optional) - An array of field codes to include in the response
+/// * `query` (optional) - A query condition. It must not contain `limit` or `offset`
+/// * `page_size` (optional) - The number of records fetched per request (default and max: [`MAX_RECORDS_PER_PAGE`])
+///
+/// # Example
+/// ```no_run
+/// # use kintone::client::{Auth, KintoneClient};
+/// # let client = KintoneClient::new("https://example.cybozu.com", Auth::password("user".to_owned(), "pass".to_owned()));
+/// let mut records = kintone::v1::record::get_all_records(123)
+///     .query("status = \"Active\"")
+///     .send(&client)?;
+/// while let Some(record) = records.next() {
+///     let record = record?;
+///     println!("{}/{}: {:?}", records.fetched(), records.total_count(), record.id());
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Reference
+/// <https://cybozu.dev/ja/kintone/docs/rest-api/records/create-cursor/>
+pub fn get_all_records(app: u64) -> GetAllRecordsRequest {
+    GetAllRecordsRequest {
+        cursor: create_cursor(app),
+        page_size: MAX_RECORDS_PER_PAGE as u64,
+    }
+}
+
+#[must_use]
+pub struct GetAllRecordsRequest {
+    cursor: CreateCursorRequest,
+    page_size: u64,
+}
+
+impl GetAllRecordsRequest {
+    /// Specifies which fields to include in the records.
+    ///
+    /// # Arguments
+    /// * `fields` - The field codes to retrieve, such as `&["name", "email"]` or a `Vec<String>`
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.cursor = self.cursor.fields(fields);
+        self
+    }
+
+    /// Sets a query to filter the records.
+    ///
+    /// # Arguments
+    /// * `query` - A query string following Kintone's query syntax
+    pub fn query(mut self, query: &str) -> Self {
+        self.cursor = self.cursor.query(query);
+        self
+    }
+
+    /// Sets the number of records fetched per request.
+    ///
+    /// # Arguments
+    /// * `page_size` - The page size (default and max: [`MAX_RECORDS_PER_PAGE`])
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Creates the cursor and returns an iterator over the matching records.
+    pub fn send(self, client: &KintoneClient) -> Result<AllRecords<'_>, ApiError> {
+        if self.page_size == 0 {
+            return Err(ApiError::InvalidRequest("page size must be at least 1".to_owned()));
+        }
+        let cursor = self.cursor.size(self.page_size).send(client)?;
+        Ok(AllRecords {
+            client,
+            cursor_id: cursor.id,
+            total_count: cursor.total_count,
+            fetched: 0,
+            page: Vec::new().into_iter(),
+            has_next: true,
+        })
+    }
+}
+
+/// An iterator over the records of a cursor, returned by [`get_all_records`].
+///
+/// Kintone limits the number of cursors open at once in a domain. The cursor is deleted when
+/// the iterator is dropped before the last page has been read, or when fetching a page fails.
+#[must_use = "the cursor stays open until the iterator is consumed or dropped"]
+pub struct AllRecords<'a> {
+    client: &'a KintoneClient,
+    cursor_id: String,
+    total_count: u64,
+    fetched: u64,
+    page: std::vec::IntoIter<Record>,
+    has_next: bool,
+}
+
+impl AllRecords<'_> {
+    /// Returns the number of records that matched the query when the cursor was created.
+    pub fn total_count(&self) -> u64 {
+        self.total_count
+    }
+
+    /// Returns the number of records yielded so far.
+    pub fn fetched(&self) -> u64 {
+        self.fetched
+    }
+}
+
+impl Iterator for AllRecords<'_> {
+    type Item = Result<Record, ApiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.page.next() {
+                self.fetched += 1;
+                return Some(Ok(record));
+            }
+            if !self.has_next {
+                return None;
+            }
+            match get_records_by_cursor(&self.cursor_id).send(self.client) {
+                Ok(response) => {
+                    self.has_next = response.next;
+                    self.page = response.records.into_iter();
+                }
+                Err(err) => {
+                    // The cursor may still be open on the server; do not leave it to expire.
+                    let _ = delete_cursor(&self.cursor_id).send(self.client);
+                    self.has_next = false;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for AllRecords<'_> {
+    fn drop(&mut self) {
+        // Kintone deletes a cursor by itself once its last page has been read.
+        if self.has_next {
+            let _ = delete_cursor(&self.cursor_id).send(self.client);
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
 /// Executes multiple API requests in a single bulk operation.
 ///
 /// This function creates a request to execute multiple API operations atomically.
@@ -1286,7 +3040,7 @@ impl DeleteCursorRequest {
 /// * `requests` - A vector of BulkRequestItem containing the operations to execute
 ///
 /// # Limits
-/// - Maximum 20 requests can be executed in a single bulk request
+/// - Maximum 20 requests ([`MAX_BULK_REQUESTS`]) can be executed in a single bulk request
 /// - All operations are executed atomically (all succeed or all fail)
 /// - Supports record operations, status updates, and assignee updates
 ///
@@ -1374,7 +3128,7 @@ impl TryFrom<UpdateRecordRequest> for BulkRequestItem {
         Ok(Self {
             method: http::Method::PUT,
             api: "/k/v1/record.json".to_string(),
-            payload: serde_json::to_value(request.body)?,
+            payload: serde_json::to_value(request.body.with_embedded_id_and_revision())?,
         })
     }
 }
@@ -1427,6 +3181,18 @@ impl TryFrom<UpdateStatusRequest> for BulkRequestItem {
     }
 }
 
+impl TryFrom<UpdateStatusesRequest> for BulkRequestItem {
+    type Error = serde_json::Error;
+
+    fn try_from(request: UpdateStatusesRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            method: http::Method::PUT,
+            api: "/k/v1/records/status.json".to_string(),
+            payload: serde_json::to_value(request.body)?,
+        })
+    }
+}
+
 #[must_use]
 pub struct BulkRequestRequest {
     builder: RequestBuilder,
@@ -1439,14 +3205,762 @@ struct BulkRequestRequestBody {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
 pub struct BulkRequestResponse {
     pub results: Vec<serde_json::Value>,
 }
 
 impl BulkRequestRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<BulkRequestResponse, ApiError> {
+        check_len("requests", self.body.requests.len(), MAX_BULK_REQUESTS)?;
         self.builder.send(client, self.body)
     }
 }
 
 //-----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{mock_client, test_client_builder};
+    use crate::middleware::MockHandler;
+    use crate::model::record::{FieldValue, Record};
+
+    #[test]
+    fn split_id_range_covers_all_ids() {
+        assert_eq!(split_id_range(1, 10, 3), vec![(1, 4), (5, 7), (8, 10)]);
+        assert_eq!(split_id_range(5, 6, 4), vec![(5, 5), (6, 6)]);
+        assert_eq!(split_id_range(7, 7, 1), vec![(7, 7)]);
+    }
+
+    #[test]
+    fn get_records_parallel_pages_through_ranges() {
+        let page = |ids: &[u64]| {
+            let records: Vec<String> = ids
+                .iter()
+                .map(|id| format!(r#"{{"$id":{{"type":"__ID__","value":"{id}"}}}}"#))
+                .collect();
+            format!(r#"{{"records":[{}],"totalCount":null}}"#, records.join(","))
+        };
+        let mock = MockHandler::new()
+            .with_response(http::Method::GET, "/k/v1/records.json", 200, page(&[3]))
+            .with_response(http::Method::GET, "/k/v1/records.json", 200, page(&[9]))
+            .with_response(http::Method::GET, "/k/v1/records.json", 200, page(&[3, 5, 9]));
+        let records = get_records_parallel(1)
+            .query("status = \"Active\"")
+            .partitions(1)
+            .send(&mock_client(&mock))
+            .unwrap();
+        let ids: Vec<u64> = records.iter().filter_map(Record::id).collect();
+        assert_eq!(ids, vec![3, 5, 9]);
+
+        let queries: Vec<String> = mock
+            .requests()
+            .iter()
+            .map(|r| {
+                let url = url::Url::parse(&r.uri).unwrap();
+                url.query_pairs().find(|(k, _)| k == "query").unwrap().1.into_owned()
+            })
+            .collect();
+        assert_eq!(
+            queries,
+            vec![
+                "(status = \"Active\") order by $id asc limit 1",
+                "(status = \"Active\") order by $id desc limit 1",
+                "(status = \"Active\") and $id > 2 and $id <= 9 order by $id asc limit 500",
+            ]
+        );
+    }
+
+    #[test]
+    fn get_record_keeps_only_selected_fields() {
+        let mock = MockHandler::new().with_response(
+            http::Method::GET,
+            "/k/v1/record.json",
+            200,
+            r#"{"record":{
+                "$id":{"type":"__ID__","value":"7"},
+                "name":{"type":"SINGLE_LINE_TEXT","value":"Alice"},
+                "email":{"type":"SINGLE_LINE_TEXT","value":"alice@example.com"},
+                "memo":{"type":"MULTI_LINE_TEXT","value":"long text"}
+            }}"#,
+        );
+        let client = mock_client(&mock);
+
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        let record = get_record(1, 7).fields(&["name", "email"]).send(&client).unwrap().record;
+        let mut codes: Vec<&str> = record.fields().map(|(code, _)| code).collect();
+        codes.sort();
+        assert_eq!(codes, vec!["email", "name"]);
+
+        let record = get_record(1, 7).send(&client).unwrap().record;
+        assert_eq!(record.fields().len(), 4);
+        assert!(!mock.requests()[0].uri.contains("fields"));
+    }
+
+    #[test]
+    fn update_record_by_key_with_revision() {
+        let mock = MockHandler::new().with_response(
+            http::Method::PUT,
+            "/k/v1/record.json",
+            409,
+            r#"{"code":"GAIA_CO02","id":"xyz","message":"revision mismatch"}"#,
+        );
+        let record = Record::from([("status", FieldValue::text("Done"))]);
+        let err = update_record(1)
+            .update_key("code".to_owned(), "A-001")
+            .record(record)
+            .revision(4)
+            .send(&mock_client(&mock))
+            .unwrap_err();
+        assert!(matches!(&err, ApiError::RevisionConflict(e) if e.status == 409), "{err:?}");
+
+        let body = mock.requests()[0].body_json();
+        assert_eq!(body["updateKey"], serde_json::json!({"field": "code", "value": "A-001"}));
+        assert_eq!(body["revision"], 4);
+        assert!(body.get("id").is_none());
+    }
+
+    #[test]
+    fn count_records_requests_only_the_count() {
+        let mock = MockHandler::new().with_response(
+            http::Method::GET,
+            "/k/v1/records.json",
+            200,
+            r#"{"records":[{"$id":{"type":"__ID__","value":"1"}}],"totalCount":"1234"}"#,
+        );
+        let count = count_records(7).query("status = \"Open\"").send(&mock_client(&mock)).unwrap();
+        assert_eq!(count, 1234);
+
+        let url = url::Url::parse(&mock.requests()[0].uri).unwrap();
+        let params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert!(params.contains(&("query".to_owned(), "status = \"Open\" limit 1".to_owned())));
+        assert!(params.contains(&("totalCount".to_owned(), "true".to_owned())));
+        assert!(params.contains(&("fields[0]".to_owned(), "$id".to_owned())));
+    }
+
+    #[test]
+    fn get_records_by_code_resolves_the_app_once() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/apps.json",
+                200,
+                r#"{"apps":[{"appId":"42","code":"PROJECT","name":"Projects","description":"",
+                    "spaceId":null,"threadId":null,"createdAt":"2024-01-01T00:00:00Z",
+                    "creator":{"code":"alice","name":"Alice"},"modifiedAt":"2024-01-01T00:00:00Z",
+                    "modifier":{"code":"alice","name":"Alice"}}]}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records.json",
+                200,
+                r#"{"records":[],"totalCount":null}"#,
+            );
+        let client = mock_client(&mock);
+
+        get_records_by_code(&client, "PROJECT").unwrap().send(&client).unwrap();
+        get_records_by_code(&client, "PROJECT").unwrap().send(&client).unwrap();
+
+        let uris: Vec<String> = mock.requests().into_iter().map(|r| r.uri).collect();
+        assert_eq!(uris.len(), 3);
+        assert!(uris[0].contains("/k/v1/apps.json"));
+        assert!(uris[1].contains("app=42") && uris[2].contains("app=42"), "{uris:?}");
+    }
+
+    #[test]
+    fn download_attachments_of_file_field() {
+        let mock = MockHandler::new()
+            .with_response(http::Method::GET, "/k/v1/file.json", 200, "first")
+            .with_response(http::Method::GET, "/k/v1/file.json", 200, "second");
+        let client = mock_client(&mock);
+        let record = Record::from([
+            (
+                "files",
+                FieldValue::File(vec![
+                    crate::model::file_body("key1").name("a.txt").build(),
+                    crate::model::file_body("key2").name("b.txt").build(),
+                ]),
+            ),
+            ("empty", FieldValue::File(vec![])),
+            ("title", FieldValue::text("x")),
+        ]);
+
+        let downloads: Vec<(String, String)> = download_attachments(&client, &record, "files")
+            .map(|r| {
+                let (file, mut download) = r.unwrap();
+                let mut content = String::new();
+                std::io::Read::read_to_string(&mut download.content, &mut content).unwrap();
+                (file.name.unwrap(), content)
+            })
+            .collect();
+        assert_eq!(
+            downloads,
+            vec![
+                ("a.txt".to_owned(), "first".to_owned()),
+                ("b.txt".to_owned(), "second".to_owned())
+            ]
+        );
+        let uris: Vec<String> = mock.requests().into_iter().map(|r| r.uri).collect();
+        assert!(uris[0].ends_with("fileKey=key1") && uris[1].ends_with("fileKey=key2"), "{uris:?}");
+
+        assert_eq!(download_attachments(&client, &record, "empty").count(), 0);
+        assert_eq!(download_attachments(&client, &record, "missing").count(), 0);
+        let results: Vec<_> = download_attachments(&client, &record, "title").collect();
+        assert!(matches!(results[..], [Err(ApiError::InvalidRequest(_))]));
+    }
+
+    #[test]
+    fn raw_query_replaces_typed_parameters() {
+        let client = test_client_builder().build();
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        let url = get_records(1)
+            .query("a = 1")
+            .fields(&["x", "y"])
+            .raw_query("query", "b = 2")
+            .raw_query("newParam", "on")
+            .fields(&["z"])
+            .debug_url(&client);
+        assert_eq!(
+            url,
+            "https://example.cybozu.com/k/v1/records.json?app=1&query=b+%3D+2&newParam=on&fields%5B0%5D=z"
+        );
+    }
+
+    #[test]
+    fn fields_accept_owned_strings() {
+        let client = test_client_builder().build();
+        let codes: Vec<String> = ["name", "email"].map(str::to_owned).to_vec();
+        assert_eq!(
+            get_records(1).fields(&codes).debug_url(&client),
+            get_records(1).fields(["name", "email"]).debug_url(&client)
+        );
+
+        let mock = MockHandler::new().with_response(
+            http::Method::POST,
+            "/k/v1/records/cursor.json",
+            200,
+            r#"{"id":"c","totalCount":"0"}"#,
+        );
+        let client = mock_client(&mock);
+        create_cursor(1).fields(codes).send(&client).unwrap();
+        assert_eq!(mock.requests()[0].body_json()["fields"], serde_json::json!(["name", "email"]));
+    }
+
+    #[test]
+    fn require_total_count() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records.json",
+                200,
+                r#"{"records":[],"totalCount":"42"}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records.json",
+                200,
+                r#"{"records":[],"totalCount":null}"#,
+            );
+        let client = mock_client(&mock);
+
+        let response = get_records(1).total_count(true).send(&client).unwrap();
+        assert_eq!(response.require_total_count().unwrap(), 42);
+        let response = get_records(1).send(&client).unwrap();
+        let err = response.require_total_count().unwrap_err();
+        assert!(matches!(err, ApiError::InvalidRequest(msg) if msg.contains("total_count(true)")));
+    }
+
+    #[test]
+    fn in_conditions() {
+        assert_eq!(id_in([7]).unwrap(), r#"$id in ("7")"#);
+        assert_eq!(record_number_in("no", [r#"A"1"#]).unwrap(), r#"no in ("A\"1")"#);
+        assert!(matches!(id_in([]), Err(ApiError::InvalidRequest(_))));
+        assert!(matches!(record_number_in("no", [""; 0]), Err(ApiError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn bind_query_escapes_values() {
+        assert_eq!(
+            bind_query("name = ? and code in (?, ?)", ["O'Brien", r#"a" or "1" = "1"#, r"c:\"])
+                .unwrap(),
+            r#"name = "O'Brien" and code in ("a\" or \"1\" = \"1", "c:\\")"#
+        );
+        assert_eq!(
+            bind_query(r#"title like "why?" and note = "\"?" and id = ?"#, ["5"]).unwrap(),
+            r#"title like "why?" and note = "\"?" and id = "5""#
+        );
+        assert!(matches!(bind_query("a = ? and b = ?", ["x"]), Err(ApiError::InvalidRequest(_))));
+        assert!(matches!(bind_query("a = ?", ["x", "y"]), Err(ApiError::InvalidRequest(_))));
+
+        let mock = MockHandler::new().with_response(
+            http::Method::GET,
+            "/k/v1/records.json",
+            200,
+            r#"{"records":[],"totalCount":null}"#,
+        );
+        let client = mock_client(&mock);
+        get_records(1).query_params("name = ?", ["x\"y"]).send(&client).unwrap();
+        assert!(
+            mock.requests()[0].uri.contains("query=name+%3D+%22x%5C%22y%22"),
+            "{:?}",
+            mock.requests()[0].uri
+        );
+        let err = get_records(1).query_params("name = ?", Vec::<String>::new()).send(&client);
+        assert!(matches!(err, Err(ApiError::InvalidRequest(_))));
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[test]
+    fn try_get_record_maps_missing_record_to_none() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/record.json",
+                404,
+                r#"{"code":"GAIA_RE01","id":"a","message":"The specified record (ID: 9) is not found."}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/record.json",
+                404,
+                r#"{"code":"GAIA_AP01","id":"b","message":"The app (ID: 1) not found."}"#,
+            );
+        let client = mock_client(&mock);
+
+        assert!(try_get_record(1, 9).send(&client).unwrap().is_none());
+        let err = try_get_record(1, 9).send(&client).unwrap_err();
+        assert!(matches!(&err, ApiError::Kintone(e) if e.code == "GAIA_AP01"), "{err:?}");
+    }
+
+    #[test]
+    fn try_get_record_sees_through_retries() {
+        let mock = MockHandler::new().with_response(
+            http::Method::GET,
+            "/k/v1/record.json",
+            404,
+            r#"{"code":"GAIA_RE01","id":"a","message":"The specified record (ID: 9) is not found."}"#,
+        );
+        let client = test_client_builder()
+            .layer(
+                crate::middleware::RetryLayer::new()
+                    .with_max_attempts(2)
+                    .with_initial_delay(std::time::Duration::ZERO)
+                    .with_should_retry(Box::new(|_, _| true)),
+            )
+            .with_handler(mock.clone())
+            .build();
+
+        assert!(try_get_record(1, 9).send(&client).unwrap().is_none());
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[test]
+    fn delete_all_matching_deletes_page_by_page() {
+        let page = |ids: std::ops::RangeInclusive<u64>| {
+            let records: Vec<Record> = ids
+                .map(|id| {
+                    let mut record = Record::new();
+                    record.set_id(id);
+                    record.set_revision(id + 1000);
+                    record
+                })
+                .collect();
+            serde_json::json!({ "records": records, "totalCount": null }).to_string()
+        };
+        let mock = MockHandler::new()
+            .with_response(http::Method::GET, "/k/v1/records.json", 200, page(1..=100))
+            .with_response(http::Method::GET, "/k/v1/records.json", 200, page(101..=130))
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records.json",
+                200,
+                r#"{"records":[],"totalCount":null}"#,
+            )
+            .with_response(http::Method::DELETE, "/k/v1/records.json", 200, "{}");
+        let client = mock_client(&mock);
+
+        let deleted = delete_all_matching(1, "status = \"Done\"").send(&client).unwrap();
+        assert_eq!(deleted, 130);
+
+        let requests = mock.requests();
+        let deletes: Vec<_> =
+            requests.iter().filter(|r| r.method == http::Method::DELETE).collect();
+        assert_eq!(deletes.len(), 2);
+        let body = deletes[1].body_json();
+        assert_eq!(body["ids"].as_array().unwrap().len(), 30);
+        assert_eq!(body["revisions"][0], 1101);
+        let last_get = requests.iter().rfind(|r| r.method == http::Method::GET).unwrap();
+        assert!(last_get.uri.contains("%24id+%3E+130"), "{}", last_get.uri);
+    }
+
+    #[test]
+    fn delete_all_matching_continues_past_failed_chunk() {
+        let page = |ids: std::ops::RangeInclusive<u64>| {
+            let records: Vec<Record> = ids
+                .map(|id| {
+                    let mut record = Record::new();
+                    record.set_id(id);
+                    record.set_revision(1);
+                    record
+                })
+                .collect();
+            serde_json::json!({ "records": records, "totalCount": null }).to_string()
+        };
+        let conflict =
+            r#"{"code":"GAIA_CO02","id":"x","message":"The revision is not the latest."}"#;
+        let mock = MockHandler::new()
+            .with_response(http::Method::GET, "/k/v1/records.json", 200, page(1..=100))
+            .with_response(http::Method::GET, "/k/v1/records.json", 200, page(101..=120))
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records.json",
+                200,
+                r#"{"records":[],"totalCount":null}"#,
+            )
+            .with_response(http::Method::DELETE, "/k/v1/records.json", 409, conflict)
+            .with_response(http::Method::DELETE, "/k/v1/records.json", 200, "{}");
+        let client = mock_client(&mock);
+
+        let outcome = delete_all_matching(1, "").send_continue_on_error(&client);
+        assert!(!outcome.is_success());
+        assert_eq!(outcome.failed.len(), 1);
+        assert_eq!(outcome.failed[0].0, 0);
+        assert!(
+            matches!(outcome.failed[0].1, ApiError::RevisionConflict(_)),
+            "{:?}",
+            outcome.failed
+        );
+        assert_eq!(outcome.succeeded.len(), 1);
+        assert_eq!(outcome.succeeded[0].0, 1);
+        assert_eq!(outcome.succeeded[0].1, (101..=120).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn parallel_add_records_keeps_chunk_order() {
+        use crate::middleware::{Handler, RequestBody, ResponseBody};
+
+        // Answers each chunk with its first record's value as the id and its size as the
+        // revision, and holds the first chunk back so that it finishes last.
+        struct Echo;
+        impl Handler for Echo {
+            fn handle(
+                &self,
+                req: http::Request<RequestBody>,
+            ) -> Result<http::Response<ResponseBody>, ApiError> {
+                let body: serde_json::Value =
+                    serde_json::from_reader(req.into_body().into_reader()).unwrap();
+                let records = body["records"].as_array().unwrap();
+                let first = records[0]["n"]["value"].as_str().unwrap();
+                if first == "0" {
+                    std::thread::sleep(std::time::Duration::from_millis(50));
+                }
+                let resp =
+                    serde_json::json!({"ids": [first], "revisions": [records.len().to_string()]});
+                Ok(http::Response::new(ResponseBody::from_reader(
+                    std::io::Cursor::new(resp.to_string().into_bytes()),
+                    Some("application/json"),
+                )))
+            }
+        }
+        let client = test_client_builder().with_handler(Echo).build();
+        let records: Vec<Record> = (0..250)
+            .map(|i| Record::from([("n", FieldValue::text(i.to_string()))]))
+            .collect();
+
+        let results = parallel_add_records(1, records).concurrency(2).send(&client);
+        let chunks: Vec<(String, String)> = results
+            .into_iter()
+            .map(|r| {
+                let resp = r.unwrap();
+                (resp.ids[0].clone(), resp.revisions[0].clone())
+            })
+            .collect();
+        assert_eq!(
+            chunks,
+            [
+                ("0".to_owned(), "100".to_owned()),
+                ("100".to_owned(), "100".to_owned()),
+                ("200".to_owned(), "50".to_owned())
+            ]
+        );
+
+        assert!(parallel_add_records(1, Vec::new()).send(&client).is_empty());
+    }
+
+    #[test]
+    fn batch_outcome_collects_results_by_index() {
+        let outcome: BatchOutcome<u32> = [
+            Ok(1),
+            Err(ApiError::InvalidRequest("bad".to_owned())),
+            Ok(3),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(outcome.succeeded, [(0, 1), (2, 3)]);
+        assert_eq!(outcome.failed[0].0, 1);
+    }
+
+    #[test]
+    fn upsert_records_reports_each_entry() {
+        let mock = MockHandler::new().with_response(
+            http::Method::PUT,
+            "/k/v1/records.json",
+            200,
+            r#"{"records":[{"id":"5","revision":"3","operation":"UPDATE"},
+                {"id":"9","revision":"1","operation":"INSERT"}]}"#,
+        );
+        let client = mock_client(&mock);
+        let entry = |key: &str| {
+            UpdateRecordData::new()
+                .update_key("code".to_owned(), key)
+                .record(Record::from([("name", FieldValue::text(key))]))
+        };
+
+        let outcomes = upsert_records(1, vec![entry("A"), entry("B")]).send(&client).unwrap();
+        assert_eq!(outcomes, vec![Upserted::Updated(3), Upserted::Created(9)]);
+        let body = mock.requests()[0].body_json();
+        assert_eq!(body["upsert"], true);
+        assert_eq!(body["records"][1]["updateKey"]["value"], "B");
+
+        let missing_key = upsert_records(1, vec![entry("A"), UpdateRecordData::new().id(3)]);
+        assert!(matches!(missing_key.send(&client), Err(ApiError::InvalidRequest(_))));
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[test]
+    fn get_all_records_pages_through_cursor() {
+        let record = |id: u64| {
+            let mut record = Record::new();
+            record.set_id(id);
+            record
+        };
+        let page = |ids: &[u64], next: bool| {
+            serde_json::json!({
+                "records": ids.iter().map(|&id| record(id)).collect::<Vec<_>>(),
+                "next": next,
+            })
+            .to_string()
+        };
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::POST,
+                "/k/v1/records/cursor.json",
+                200,
+                r#"{"id":"c1","totalCount":"3"}"#,
+            )
+            .with_response(http::Method::GET, "/k/v1/records/cursor.json", 200, page(&[1, 2], true))
+            .with_response(http::Method::GET, "/k/v1/records/cursor.json", 200, page(&[3], false));
+        let client = mock_client(&mock);
+
+        let mut records = get_all_records(7).query("x = 1").send(&client).unwrap();
+        assert_eq!(records.total_count(), 3);
+        let mut progress = Vec::new();
+        while let Some(r) = records.next() {
+            progress.push((r.unwrap().id(), records.fetched()));
+        }
+        drop(records);
+        assert_eq!(progress, vec![(Some(1), 1), (Some(2), 2), (Some(3), 3)]);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3, "the exhausted cursor must not be deleted");
+        let body = requests[0].body_json();
+        assert_eq!(body["size"], 500);
+        assert_eq!(body["query"], "x = 1");
+
+        assert!(matches!(
+            get_all_records(7).page_size(501).send(&client),
+            Err(ApiError::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn get_all_records_deletes_abandoned_cursor() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::POST,
+                "/k/v1/records/cursor.json",
+                200,
+                r#"{"id":"c1","totalCount":"1000"}"#,
+            )
+            .with_response(http::Method::DELETE, "/k/v1/records/cursor.json", 200, "{}");
+        let client = mock_client(&mock);
+
+        let records = get_all_records(7).page_size(100).send(&client).unwrap();
+        drop(records);
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].body_json()["size"], 100);
+        assert_eq!(requests[1].method, http::Method::DELETE);
+        assert_eq!(requests[1].body_json()["id"], "c1");
+    }
+
+    #[test]
+    fn get_all_records_deletes_cursor_after_error() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::POST,
+                "/k/v1/records/cursor.json",
+                200,
+                r#"{"id":"c1","totalCount":"1000"}"#,
+            )
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records/cursor.json",
+                500,
+                r#"{"code":"CB_IJ01","id":"abc","message":"internal error"}"#,
+            )
+            .with_response(http::Method::DELETE, "/k/v1/records/cursor.json", 200, "{}");
+        let client = mock_client(&mock);
+
+        let mut records = get_all_records(7).send(&client).unwrap();
+        assert!(records.next().unwrap().is_err());
+        assert!(records.next().is_none());
+        drop(records);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3, "the cursor must be deleted exactly once");
+        assert_eq!(requests[2].method, http::Method::DELETE);
+        assert_eq!(requests[2].body_json()["id"], "c1");
+    }
+
+    #[test]
+    fn update_record_uses_embedded_id_and_revision() {
+        let mock = MockHandler::new().with_response(
+            http::Method::PUT,
+            "/k/v1/record.json",
+            200,
+            r#"{"revision":"8"}"#,
+        );
+        let client = mock_client(&mock);
+        let mut record = Record::from([("title", FieldValue::text("Updated"))]);
+        record.set_id(5);
+        record.set_revision(7);
+
+        update_record(1).record(record.clone()).send(&client).unwrap();
+        update_record(1).record(record).id(6).revision(3).send(&client).unwrap();
+
+        let requests = mock.requests();
+        let body = requests[0].body_json();
+        assert_eq!(body["id"], 5);
+        assert_eq!(body["revision"], 7);
+        assert_eq!(
+            body["record"],
+            serde_json::json!({"title": {"type": "SINGLE_LINE_TEXT", "value": "Updated"}})
+        );
+        let body = requests[1].body_json();
+        assert_eq!(body["id"], 6);
+        assert_eq!(body["revision"], 3);
+    }
+
+    #[test]
+    fn add_records_reports_field_errors() {
+        let mock = MockHandler::new().with_response(
+            http::Method::POST,
+            "/k/v1/records.json",
+            400,
+            r#"{"code":"CB_VA01","id":"abc","message":"Missing or invalid input.","errors":{
+                "records[1].price.value":{"messages":["Only numbers are allowed."]},
+                "records[3].name.value":{"messages":["Required.","Too long."]}
+            }}"#,
+        );
+        let records = vec![Record::new(); 4];
+        let err = add_records(1, records).send(&mock_client(&mock)).unwrap_err();
+        let ApiError::Kintone(err) = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        let summary: Vec<(Option<usize>, String, String)> = err
+            .field_errors()
+            .into_iter()
+            .map(|e| (e.record_index, e.field_code, e.message))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Some(1), "price".to_owned(), "Only numbers are allowed.".to_owned()),
+                (Some(3), "name".to_owned(), "Required.".to_owned()),
+                (Some(3), "name".to_owned(), "Too long.".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn update_record_with_empty_values_clears_fields() {
+        let mock = MockHandler::new().with_response(
+            http::Method::PUT,
+            "/k/v1/record.json",
+            200,
+            r#"{"revision":"3"}"#,
+        );
+        let record = Record::from([
+            ("memo", FieldValue::empty_text()),
+            ("amount", FieldValue::empty_number()),
+            ("tags", FieldValue::CheckBox(vec![])),
+            ("due", FieldValue::empty_date()),
+            ("start", FieldValue::empty_time()),
+            ("deadline", FieldValue::empty_datetime()),
+        ]);
+        update_record(1).id(2).record(record).send(&mock_client(&mock)).unwrap();
+
+        let body = mock.requests()[0].body_json();
+        assert_eq!(
+            body["record"],
+            serde_json::json!({
+                "memo": {"type": "SINGLE_LINE_TEXT", "value": ""},
+                "amount": {"type": "NUMBER", "value": ""},
+                "tags": {"type": "CHECK_BOX", "value": []},
+                "due": {"type": "DATE", "value": ""},
+                "start": {"type": "TIME", "value": ""},
+                "deadline": {"type": "DATETIME", "value": ""},
+            })
+        );
+    }
+
+    #[test]
+    fn stream_records_one_at_a_time() {
+        let mock = MockHandler::new()
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records.json",
+                200,
+                r#"{ "totalCount": null, "note": {"a": [1, "]}"]},
+                    "records" : [
+                        {"$id": {"type": "__ID__", "value": "1"},
+                         "title": {"type": "SINGLE_LINE_TEXT", "value": "say \"hi\" [x]"}},
+                        {"$id": {"type": "__ID__", "value": "2"},
+                         "title": {"type": "SINGLE_LINE_TEXT", "value": "{"}}
+                    ]
+                }"#,
+            )
+            .with_response(http::Method::GET, "/k/v1/records.json", 200, r#"{"records": []}"#)
+            .with_response(
+                http::Method::GET,
+                "/k/v1/records.json",
+                200,
+                r#"{"records": [{"$id": {"type": "__ID__", "value": "1"}}, {"$id""#,
+            );
+        let client = mock_client(&mock);
+
+        let records: Vec<Record> =
+            get_records(1).stream(&client).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id(), Some(1));
+        assert_eq!(
+            records[0].get("title"),
+            Some(&FieldValue::SingleLineText(r#"say "hi" [x]"#.to_owned()))
+        );
+        assert_eq!(records[1].get("title"), Some(&FieldValue::SingleLineText("{".to_owned())));
+
+        assert_eq!(get_records(1).stream(&client).unwrap().count(), 0);
+
+        let mut truncated = get_records(1).stream(&client).unwrap();
+        assert_eq!(truncated.next().unwrap().unwrap().id(), Some(1));
+        assert!(matches!(truncated.next(), Some(Err(ApiError::Json(_)))));
+        assert!(truncated.next().is_none());
+    }
+}
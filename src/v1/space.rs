@@ -98,12 +98,18 @@ pub struct AddSpaceRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AddSpaceResponse {
     #[serde(with = "stringified")]
     pub id: u64,
 }
 
 impl AddSpaceRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     /// Sends the request to create the space.
     ///
     /// # Returns
@@ -162,11 +168,17 @@ pub struct DeleteSpaceRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct DeleteSpaceResponse {
     // Empty response body
 }
 
 impl DeleteSpaceRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     /// Sends the request to delete the space.
     ///
     /// # Returns
@@ -237,12 +249,18 @@ pub struct AddThreadRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AddThreadResponse {
     #[serde(with = "stringified")]
     pub id: u64,
 }
 
 impl AddThreadRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     /// Sends the request to create the thread.
     ///
     /// # Returns
@@ -322,13 +340,45 @@ pub struct AddThreadCommentRequestBody {
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AddThreadCommentResponse {
     #[serde(with = "stringified")]
     pub id: u64,
 }
 
 impl AddThreadCommentRequest {
+    /// Returns the URL this request would be sent to, without sending it.
+    pub fn debug_url(&self, client: &KintoneClient) -> String {
+        self.builder.debug_url(client)
+    }
+
     pub fn send(self, client: &KintoneClient) -> Result<AddThreadCommentResponse, ApiError> {
         self.builder.send(client, self.body)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADD_SPACE_RESPONSE_JSON: &str = include_str!("../testdata/add_space_response.json");
+    const ADD_THREAD_RESPONSE_JSON: &str = include_str!("../testdata/add_thread_response.json");
+
+    #[test]
+    fn deserialize_add_space_response() {
+        let response: AddSpaceResponse = serde_json::from_str(ADD_SPACE_RESPONSE_JSON).unwrap();
+        assert_eq!(response.id, 1);
+    }
+
+    #[test]
+    fn deserialize_add_thread_response() {
+        let response: AddThreadResponse = serde_json::from_str(ADD_THREAD_RESPONSE_JSON).unwrap();
+        assert_eq!(response.id, 2);
+    }
+
+    #[test]
+    fn reject_non_numeric_id() {
+        let result = serde_json::from_str::<AddSpaceResponse>(r#"{"id": "abc"}"#);
+        assert!(result.is_err());
+    }
+}
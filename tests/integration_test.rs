@@ -237,6 +237,7 @@ fn integration_test_full_workflow() {
 
     // 6. Retrieve records with filter conditions and verify results
     // Test filter: age >= 30
+    #[allow(clippy::needless_borrows_for_generic_args)]
     let filter_response = record::get_records(app_id)
         .query("age >= 30")
         .fields(&["name", "age"])
@@ -0,0 +1,71 @@
+//! Tests of the records [`LoggingLayer`] emits.
+//!
+//! These live in their own test binary because they install a global logger and raise the
+//! maximum log level, which would affect every other test running in the same process.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use kintone::client::{Auth, KintoneClient};
+use kintone::middleware::{LoggingLayer, MockHandler};
+
+type Captured = (log::Level, String, HashMap<String, String>);
+
+struct Capture(Mutex<Vec<Captured>>);
+
+impl log::Log for Capture {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "kintone::test_log"
+    }
+
+    fn log(&self, record: &log::Record) {
+        struct Fields(HashMap<String, String>);
+        impl<'kvs> log::kv::VisitSource<'kvs> for Fields {
+            fn visit_pair(
+                &mut self,
+                key: log::kv::Key<'kvs>,
+                value: log::kv::Value<'kvs>,
+            ) -> Result<(), log::kv::Error> {
+                self.0.insert(key.to_string(), value.to_string());
+                Ok(())
+            }
+        }
+        if self.enabled(record.metadata()) {
+            let mut fields = Fields(HashMap::new());
+            record.key_values().visit(&mut fields).unwrap();
+            let entry = (record.level(), record.args().to_string(), fields.0);
+            self.0.lock().unwrap().push(entry);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+
+#[test]
+fn logging_layer_emits_structured_fields() {
+    log::set_logger(&CAPTURE).unwrap();
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let mock = MockHandler::new().with_response(http::Method::GET, "/k/v1/app.json", 200, "{}");
+    let client =
+        KintoneClient::builder("https://example.cybozu.com", Auth::api_token("t".to_owned()))
+            .layer(LoggingLayer::new().with_log_target("kintone::test_log"))
+            .with_handler(mock)
+            .build();
+    let _: serde_json::Value =
+        client.request(http::Method::GET, "/v1/app.json").query("id", 1).send().unwrap();
+
+    let captured = CAPTURE.0.lock().unwrap();
+    assert_eq!(captured.len(), 2, "{captured:?}");
+    let (level, _, fields) = &captured[0];
+    assert_eq!(*level, log::Level::Debug);
+    assert_eq!(fields["method"], "GET");
+    let (level, message, fields) = &captured[1];
+    assert_eq!(*level, log::Level::Info);
+    assert!(message.contains("status=200"), "{message}");
+    assert_eq!(fields["url"], "https://example.cybozu.com/k/v1/app.json?id=1");
+    assert_eq!(fields["status"], "200");
+    assert!(fields.contains_key("duration_ms"), "{fields:?}");
+}